@@ -0,0 +1,279 @@
+//! Astronomical computations on fractional Julian Dates
+//!
+//! Whereas a Julian day number identifies a whole day, astronomers identify
+//! instants by a *Julian Date*: the number of days (including a fractional
+//! part) since noon UT on JDN 0.  A Julian Date is represented here as an
+//! `f64`; the civil day with Julian day number `n` thus begins at Julian Date
+//! `n - 0.5`.
+//!
+//! This module provides conversions between Julian Dates and [`Date`]s plus
+//! times of day, and between universal time and [local solar time][SolarTime]
+//! — the mean or apparent solar time at a given longitude, which was the basis
+//! of civil timekeeping before the adoption of standard time zones.
+//!
+//! The [`timescale`] submodule provides conversions between the time scales
+//! in which Julian Dates may be expressed, such as UTC, TAI, TT, and UT1.  The
+//! [`sidereal`] submodule computes sidereal time, the [`seasons`] submodule
+//! computes the instants of the equinoxes and solstices, the [`moon`]
+//! submodule computes the phases of the Moon, and the [`sun`] submodule
+//! computes the times of sunrise, sunset, and twilight.
+//!
+//! Longitudes are given in degrees east of Greenwich, with western longitudes
+//! negative.
+//!
+//! # Example
+//!
+//! An event recorded as occurring at noon local mean time in Boston on
+//! 1700-03-10 (when the colonies still used the Julian calendar) occurred at
+//! about 16:44 UT:
+//!
+//! ```
+//! use julian::{Calendar, Month, ncal, astro::{SolarTime, jd2date}};
+//!
+//! let cal = Calendar::reforming(ncal::UNITED_STATES).unwrap();
+//! let date = cal.at_ymd(1700, Month::March, 10).unwrap();
+//! let jd = SolarTime::Mean.to_ut(date, 43200.0, -71.0589);
+//! let (ut_date, seconds) = jd2date(jd, cal).unwrap();
+//! assert_eq!(ut_date, date);
+//! assert_eq!(seconds.round(), 60254.0);
+//! ```
+// Polynomials in this module and its submodules are written out term by term
+// rather than with fused multiply-adds so that they read the same as the
+// formulae printed in Meeus's *Astronomical Algorithms*:
+#![allow(clippy::suboptimal_flops)]
+
+use crate::errors::ArithmeticError;
+use crate::{Calendar, Date, Jdnum};
+
+pub mod moon;
+pub mod seasons;
+pub mod sidereal;
+pub mod sun;
+pub mod timescale;
+
+// 86400 is exactly representable as an `f64`:
+#[allow(clippy::cast_precision_loss)]
+const SECONDS_IN_DAY: f64 = crate::SECONDS_IN_DAY as f64;
+
+/// The number of seconds of local mean time by which a place is ahead of
+/// universal time per degree of longitude east
+const SECONDS_PER_DEGREE: f64 = 240.0;
+
+/// The Julian Date of the standard epoch J2000.0 (2000-01-01 12:00 TT)
+pub const J2000: f64 = 2451545.0;
+
+/// The number of days in a Julian century
+const DAYS_IN_CENTURY: f64 = 36525.0;
+
+/// The number of days in a Julian millennium
+const DAYS_IN_MILLENNIUM: f64 = 365250.0;
+
+/// Converts a Julian Date to the Julian day number of the civil day on which
+/// it falls, along with the number of seconds since midnight
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::jd2jdn;
+///
+/// let (jdn, seconds) = jd2jdn(2460066.25).unwrap();
+/// assert_eq!(jdn, 2460066);
+/// assert_eq!(seconds, 64800.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if the Julian Date is NaN or infinite or if the
+/// Julian day number is outside the range of [`Jdnum`].
+#[allow(clippy::cast_possible_truncation)]
+pub fn jd2jdn(jd: f64) -> Result<(Jdnum, f64), ArithmeticError> {
+    let day = libm::floor(jd + 0.5);
+    if !(f64::from(Jdnum::MIN) <= day && day <= f64::from(Jdnum::MAX)) {
+        return Err(ArithmeticError);
+    }
+    // `day` is an integer in range for `Jdnum`, so this cast is exact:
+    let mut jdn = day as Jdnum;
+    let mut seconds = (jd + 0.5 - day) * SECONDS_IN_DAY;
+    if seconds >= SECONDS_IN_DAY {
+        // Rounding error can carry the seconds over into the next day.
+        jdn = jdn.checked_add(1).ok_or(ArithmeticError)?;
+        seconds -= SECONDS_IN_DAY;
+    }
+    Ok((jdn, seconds))
+}
+
+/// Converts a Julian day number and a number of seconds since midnight to a
+/// Julian Date
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::jdn2jd;
+///
+/// assert_eq!(jdn2jd(2460066, 64800.0), 2460066.25);
+/// ```
+pub fn jdn2jd(jdn: Jdnum, seconds: f64) -> f64 {
+    f64::from(jdn) - 0.5 + seconds / SECONDS_IN_DAY
+}
+
+/// Converts a Julian Date to a [`Date`] in the given calendar, along with the
+/// number of seconds since midnight
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if the Julian Date is NaN or infinite or if the
+/// Julian day number is outside the range of [`Jdnum`].
+pub fn jd2date(jd: f64, calendar: Calendar) -> Result<(Date, f64), ArithmeticError> {
+    let (jdn, seconds) = jd2jdn(jd)?;
+    Ok((calendar.at_jdn(jdn), seconds))
+}
+
+/// Converts a [`Date`] and a number of seconds since midnight to a Julian Date
+pub fn date2jd(date: Date, seconds: f64) -> f64 {
+    jdn2jd(date.julian_day_number(), seconds)
+}
+
+/// Returns the number of Julian centuries of 36525 days since [`J2000`].
+/// This is the time argument used by most expressions in Meeus's
+/// *Astronomical Algorithms* and by the IAU precession & nutation models.
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::julian_centuries;
+///
+/// // 1987-04-10 00:00
+/// let t = julian_centuries(2446895.5);
+/// assert!((t - -0.127296372348).abs() < 1e-12);
+/// ```
+pub fn julian_centuries(jd: f64) -> f64 {
+    (jd - J2000) / DAYS_IN_CENTURY
+}
+
+/// Returns the number of Julian millennia of 365250 days since [`J2000`].
+/// This is the time argument used by the VSOP87 planetary theory.
+pub fn julian_millennia(jd: f64) -> f64 {
+    (jd - J2000) / DAYS_IN_MILLENNIUM
+}
+
+/// [Private] Returns the mean obliquity of the ecliptic in degrees for the
+/// given number of Julian centuries since J2000.0 (Meeus, equation 22.2)
+fn mean_obliquity(t: f64) -> f64 {
+    let arcseconds = 21.448 - t * (46.8150 + t * (0.00059 - t * 0.001813));
+    23.0 + (26.0 + arcseconds / 60.0) / 60.0
+}
+
+/// [Private] Reduce an angle in degrees to the range `[0, 360)`
+fn normalize_degrees(degrees: f64) -> f64 {
+    let r = libm::fmod(degrees, 360.0);
+    if r < 0.0 { r + 360.0 } else { r }
+}
+
+/// Returns the [equation of time][] at the given Julian Date — the number of
+/// seconds by which apparent solar time is ahead of mean solar time.
+///
+/// This uses the approximation given by Meeus, *Astronomical Algorithms*,
+/// equation 28.3, which is accurate to within a few seconds for dates within
+/// several centuries of the present.  The difference between universal time
+/// and dynamical time is negligible here, and so `jd` may be in either.
+///
+/// [equation of time]: https://en.wikipedia.org/wiki/Equation_of_time
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::equation_of_time;
+///
+/// // 1992-10-13 00:00
+/// let eot = equation_of_time(2448908.5);
+/// assert_eq!(eot.round(), 823.0);
+/// ```
+pub fn equation_of_time(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    // Geometric mean longitude of the Sun:
+    let l0 = (280.46646 + 36000.76983 * t + 0.0003032 * t * t).to_radians();
+    // Mean anomaly of the Sun:
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    // Eccentricity of Earth's orbit:
+    let e = 0.016708634 - 0.000042037 * t - 0.0000001267 * t * t;
+    let epsilon = mean_obliquity(t).to_radians();
+    let y = libm::tan(epsilon / 2.0);
+    let y = y * y;
+    let radians = y * libm::sin(2.0 * l0) - 2.0 * e * libm::sin(m)
+        + 4.0 * e * y * libm::sin(m) * libm::cos(2.0 * l0)
+        - 0.5 * y * y * libm::sin(4.0 * l0)
+        - 1.25 * e * e * libm::sin(2.0 * m);
+    radians.to_degrees() * SECONDS_PER_DEGREE
+}
+
+/// An enumeration of the kinds of local solar time
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SolarTime {
+    /// Local mean time, in which the mean Sun crosses the meridian at noon
+    /// every day.  Local mean time differs from universal time by four minutes
+    /// per degree of longitude.
+    Mean,
+
+    /// Local apparent time, as shown by a sundial, in which the true Sun
+    /// crosses the meridian at noon every day.  Local apparent time differs
+    /// from local mean time by the [equation of time][equation_of_time].
+    Apparent,
+}
+
+impl SolarTime {
+    /// Returns the number of seconds by which this kind of local solar time at
+    /// the given longitude is ahead of universal time at the given Julian Date
+    /// (UT)
+    pub fn offset(&self, jd: f64, longitude: f64) -> f64 {
+        let offset = longitude * SECONDS_PER_DEGREE;
+        match self {
+            SolarTime::Mean => offset,
+            SolarTime::Apparent => offset + equation_of_time(jd),
+        }
+    }
+
+    /// Converts a Julian Date (UT) to a [`Date`] in the given calendar plus a
+    /// number of seconds since midnight in this kind of local solar time at
+    /// the given longitude
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, astro::SolarTime};
+    ///
+    /// // 2023-05-01 00:00 UT in Beijing (116.4° E):
+    /// let (date, seconds) = SolarTime::Mean
+    ///     .from_ut(2460065.5, 116.4, Calendar::GREGORIAN)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "2023-05-01");
+    /// assert_eq!(seconds.round(), 27936.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the Julian Date is NaN or infinite or if
+    /// the Julian day number is outside the range of [`Jdnum`].
+    pub fn from_ut(
+        &self,
+        jd: f64,
+        longitude: f64,
+        calendar: Calendar,
+    ) -> Result<(Date, f64), ArithmeticError> {
+        jd2date(jd + self.offset(jd, longitude) / SECONDS_IN_DAY, calendar)
+    }
+
+    /// Converts a [`Date`] plus a number of seconds since midnight in this
+    /// kind of local solar time at the given longitude to a Julian Date (UT)
+    pub fn to_ut(&self, date: Date, seconds: f64, longitude: f64) -> f64 {
+        let local = date2jd(date, seconds);
+        let mut jd = local - longitude * SECONDS_PER_DEGREE / SECONDS_IN_DAY;
+        if matches!(self, SolarTime::Apparent) {
+            // The equation of time changes by less than a minute per day, so
+            // two refinements are more than enough.
+            for _ in 0..2 {
+                jd = local - self.offset(jd, longitude) / SECONDS_IN_DAY;
+            }
+        }
+        jd
+    }
+}
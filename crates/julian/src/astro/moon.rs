@@ -0,0 +1,474 @@
+//! Phases of the Moon
+//!
+//! This module computes the instants of the principal phases of the Moon —
+//! new moon, first quarter, full moon, and last quarter — using the algorithm
+//! of Meeus, *Astronomical Algorithms*, chapter 49.  Over the years 1980–2020,
+//! the results differ from those of the full ELP-2000/82 lunar theory by at
+//! most about 17 seconds and by about 4 seconds on average.  The error grows
+//! for dates far from the present, primarily because of the uncertainty in
+//! ΔT when converting to universal time.
+//!
+//! Each occurrence of a phase is identified by a [`LunarPhase`], consisting of
+//! the phase and a *lunation number* counting synodic months from the new moon
+//! of 2000-01-06, which is lunation 0.  (This is Meeus's `k`; the Brown
+//! lunation number is 953 more.)  The [mean][LunarPhase::mean_jde] and
+//! [true][LunarPhase::jde] instants of a phase can be computed as Julian Dates,
+//! and [`LunarPhase::date()`] gives the date of a phase in any [`Calendar`].
+//!
+//! The functions [`elongation()`], [`phase_angle()`], and
+//! [`illuminated_fraction()`] describe the phase of the Moon at an arbitrary
+//! instant.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, astro::moon::{new_moons, Phase}};
+//!
+//! let cal = Calendar::GREGORIAN;
+//! let start = cal.at_ymd(2023, Month::January, 1).unwrap();
+//! let end = cal.at_ymd(2023, Month::March, 31).unwrap();
+//! let dates = new_moons(start, end)
+//!     .map(|lp| lp.date(cal).unwrap().0.to_string())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(dates, ["2023-01-21", "2023-02-20", "2023-03-21"]);
+//! ```
+use super::timescale::{tt2ut, ut2tt};
+use super::{jd2date, jd2jdn, julian_centuries, normalize_degrees};
+use crate::errors::ArithmeticError;
+use crate::{Calendar, Date, Jdnum};
+use core::iter::FusedIterator;
+
+/// The mean length of a synodic month in days
+pub const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// The Julian Ephemeris Date of the mean new moon of lunation 0
+const LUNATION_EPOCH: f64 = 2451550.09766;
+
+/// The number of lunations per Julian century
+const LUNATIONS_PER_CENTURY: f64 = 1236.85;
+
+/// An enumeration of the principal phases of the Moon
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    /// New moon, when the Moon and Sun have the same apparent geocentric
+    /// longitude
+    NewMoon,
+
+    /// First quarter, when the Moon is 90° east of the Sun
+    FirstQuarter,
+
+    /// Full moon, when the Moon is opposite the Sun
+    FullMoon,
+
+    /// Last quarter, when the Moon is 90° west of the Sun
+    LastQuarter,
+}
+
+impl Phase {
+    /// Returns an iterator over the phases in the order in which they occur
+    /// within a lunation
+    pub fn iter() -> impl Iterator<Item = Phase> {
+        [
+            Phase::NewMoon,
+            Phase::FirstQuarter,
+            Phase::FullMoon,
+            Phase::LastQuarter,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the elongation of the Moon from the Sun at the phase, in
+    /// degrees: 0 for new moon, 90 for first quarter, 180 for full moon, and
+    /// 270 for last quarter
+    pub const fn elongation(&self) -> f64 {
+        match self {
+            Phase::NewMoon => 0.0,
+            Phase::FirstQuarter => 90.0,
+            Phase::FullMoon => 180.0,
+            Phase::LastQuarter => 270.0,
+        }
+    }
+
+    /// [Private] Returns the fraction of a lunation by which the phase follows
+    /// the new moon
+    const fn fraction(self) -> f64 {
+        match self {
+            Phase::NewMoon => 0.0,
+            Phase::FirstQuarter => 0.25,
+            Phase::FullMoon => 0.5,
+            Phase::LastQuarter => 0.75,
+        }
+    }
+}
+
+/// A particular occurrence of a [`Phase`] of the Moon, identified by its
+/// lunation number
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LunarPhase {
+    lunation: i32,
+    phase: Phase,
+}
+
+impl LunarPhase {
+    /// Construct a `LunarPhase` for the given phase in the given lunation,
+    /// where lunation 0 begins with the new moon of 2000-01-06
+    pub const fn new(lunation: i32, phase: Phase) -> LunarPhase {
+        LunarPhase { lunation, phase }
+    }
+
+    /// Returns the first occurrence of the given phase at or after the given
+    /// Julian Date (UT)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::moon::{LunarPhase, Phase};
+    ///
+    /// // The first full moon of 2023:
+    /// let lp = LunarPhase::following(2459945.5, Phase::FullMoon);
+    /// assert_eq!(lp.lunation(), 284);
+    /// assert_eq!(lp.julian_day_number(), Ok(2459951));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn following(jd: f64, phase: Phase) -> LunarPhase {
+        let estimate = libm::floor((ut2tt(jd) - LUNATION_EPOCH) / SYNODIC_MONTH - phase.fraction());
+        // Saturate rather than overflow for absurdly distant dates:
+        let base = estimate.clamp(f64::from(i32::MIN), f64::from(i32::MAX)) as i32;
+        // The true phase differs from the mean phase by less than a day, but
+        // far from the present, the uncertainty in ΔT can make the estimate
+        // off by more than one lunation, so search outwards from it.
+        let mut lp = LunarPhase::new(base, phase);
+        while let Some(prev) = lp.pred()
+            && prev.jd() >= jd
+        {
+            lp = prev;
+        }
+        while lp.jd() < jd
+            && let Some(next) = lp.succ()
+        {
+            lp = next;
+        }
+        lp
+    }
+
+    /// Returns the lunation number
+    pub const fn lunation(&self) -> i32 {
+        self.lunation
+    }
+
+    /// Returns the phase
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns the next occurrence of the same phase, or `None` if the
+    /// lunation number would overflow
+    pub const fn succ(&self) -> Option<LunarPhase> {
+        match self.lunation.checked_add(1) {
+            Some(lunation) => Some(LunarPhase::new(lunation, self.phase)),
+            None => None,
+        }
+    }
+
+    /// Returns the previous occurrence of the same phase, or `None` if the
+    /// lunation number would underflow
+    pub const fn pred(&self) -> Option<LunarPhase> {
+        match self.lunation.checked_sub(1) {
+            Some(lunation) => Some(LunarPhase::new(lunation, self.phase)),
+            None => None,
+        }
+    }
+
+    /// [Private] Returns Meeus's `k`
+    fn k(&self) -> f64 {
+        f64::from(self.lunation) + self.phase.fraction()
+    }
+
+    /// Returns the instant of the mean phase as a Julian Ephemeris Date (TT),
+    /// i.e., the instant at which the phase would occur if the Moon & Sun
+    /// moved uniformly
+    pub fn mean_jde(&self) -> f64 {
+        let k = self.k();
+        let t = k / LUNATIONS_PER_CENTURY;
+        LUNATION_EPOCH
+            + SYNODIC_MONTH * k
+            + t * t * (0.00015437 + t * (-0.000000150 + t * 0.00000000073))
+    }
+
+    /// Returns the instant of the true phase as a Julian Ephemeris Date (TT)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::moon::{LunarPhase, Phase};
+    ///
+    /// // Meeus, example 49.a: 1977-02-18 03:37:42 TT
+    /// let lp = LunarPhase::new(-283, Phase::NewMoon);
+    /// assert!((lp.jde() - 2443192.65118).abs() < 1e-5);
+    /// ```
+    pub fn jde(&self) -> f64 {
+        let k = self.k();
+        let t = k / LUNATIONS_PER_CENTURY;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        // Eccentricity of Earth's orbit:
+        let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+        // Sun's mean anomaly:
+        let m = (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3).to_radians();
+        // Moon's mean anomaly:
+        let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3
+            - 0.000000058 * t4)
+            .to_radians();
+        // Moon's argument of latitude:
+        let f = (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4)
+            .to_radians();
+        // Longitude of the ascending node of the lunar orbit:
+        let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3).to_radians();
+        let sin = libm::sin;
+        let correction = match self.phase {
+            Phase::NewMoon => {
+                -0.40720 * sin(mp)
+                    + 0.17241 * e * sin(m)
+                    + 0.01608 * sin(2.0 * mp)
+                    + 0.01039 * sin(2.0 * f)
+                    + 0.00739 * e * sin(mp - m)
+                    - 0.00514 * e * sin(mp + m)
+                    + 0.00208 * e * e * sin(2.0 * m)
+                    - 0.00111 * sin(mp - 2.0 * f)
+                    - 0.00057 * sin(mp + 2.0 * f)
+                    + 0.00056 * e * sin(2.0 * mp + m)
+                    - 0.00042 * sin(3.0 * mp)
+                    + 0.00042 * e * sin(m + 2.0 * f)
+                    + 0.00038 * e * sin(m - 2.0 * f)
+                    - 0.00024 * e * sin(2.0 * mp - m)
+                    - 0.00017 * sin(omega)
+                    - 0.00007 * sin(mp + 2.0 * m)
+                    + 0.00004 * sin(2.0 * mp - 2.0 * f)
+                    + 0.00004 * sin(3.0 * m)
+                    + 0.00003 * sin(mp + m - 2.0 * f)
+                    + 0.00003 * sin(2.0 * mp + 2.0 * f)
+                    - 0.00003 * sin(mp + m + 2.0 * f)
+                    + 0.00003 * sin(mp - m + 2.0 * f)
+                    - 0.00002 * sin(mp - m - 2.0 * f)
+                    - 0.00002 * sin(3.0 * mp + m)
+                    + 0.00002 * sin(4.0 * mp)
+            }
+            Phase::FullMoon => {
+                -0.40614 * sin(mp)
+                    + 0.17302 * e * sin(m)
+                    + 0.01614 * sin(2.0 * mp)
+                    + 0.01043 * sin(2.0 * f)
+                    + 0.00734 * e * sin(mp - m)
+                    - 0.00515 * e * sin(mp + m)
+                    + 0.00209 * e * e * sin(2.0 * m)
+                    - 0.00111 * sin(mp - 2.0 * f)
+                    - 0.00057 * sin(mp + 2.0 * f)
+                    + 0.00056 * e * sin(2.0 * mp + m)
+                    - 0.00042 * sin(3.0 * mp)
+                    + 0.00042 * e * sin(m + 2.0 * f)
+                    + 0.00038 * e * sin(m - 2.0 * f)
+                    - 0.00024 * e * sin(2.0 * mp - m)
+                    - 0.00017 * sin(omega)
+                    - 0.00007 * sin(mp + 2.0 * m)
+                    + 0.00004 * sin(2.0 * mp - 2.0 * f)
+                    + 0.00004 * sin(3.0 * m)
+                    + 0.00003 * sin(mp + m - 2.0 * f)
+                    + 0.00003 * sin(2.0 * mp + 2.0 * f)
+                    - 0.00003 * sin(mp + m + 2.0 * f)
+                    + 0.00003 * sin(mp - m + 2.0 * f)
+                    - 0.00002 * sin(mp - m - 2.0 * f)
+                    - 0.00002 * sin(3.0 * mp + m)
+                    + 0.00002 * sin(4.0 * mp)
+            }
+            Phase::FirstQuarter | Phase::LastQuarter => {
+                let quarter = -0.62801 * sin(mp) + 0.17172 * e * sin(m) - 0.01183 * e * sin(mp + m)
+                    + 0.00862 * sin(2.0 * mp)
+                    + 0.00804 * sin(2.0 * f)
+                    + 0.00454 * e * sin(mp - m)
+                    + 0.00204 * e * e * sin(2.0 * m)
+                    - 0.00180 * sin(mp - 2.0 * f)
+                    - 0.00070 * sin(mp + 2.0 * f)
+                    - 0.00040 * sin(3.0 * mp)
+                    - 0.00034 * e * sin(2.0 * mp - m)
+                    + 0.00032 * e * sin(m + 2.0 * f)
+                    + 0.00032 * e * sin(m - 2.0 * f)
+                    - 0.00028 * e * e * sin(mp + 2.0 * m)
+                    + 0.00027 * e * sin(2.0 * mp + m)
+                    - 0.00017 * sin(omega)
+                    - 0.00005 * sin(mp - m - 2.0 * f)
+                    + 0.00004 * sin(2.0 * mp + 2.0 * f)
+                    - 0.00004 * sin(mp + m + 2.0 * f)
+                    + 0.00004 * sin(mp - 2.0 * m)
+                    + 0.00003 * sin(mp + m - 2.0 * f)
+                    + 0.00003 * sin(3.0 * m)
+                    + 0.00002 * sin(2.0 * mp - 2.0 * f)
+                    + 0.00002 * sin(mp - m + 2.0 * f)
+                    - 0.00002 * sin(3.0 * mp + m);
+                let cos = libm::cos;
+                let w = 0.00306 - 0.00038 * e * cos(m) + 0.00026 * cos(mp) - 0.00002 * cos(mp - m)
+                    + 0.00002 * cos(mp + m)
+                    + 0.00002 * cos(2.0 * f);
+                if self.phase == Phase::FirstQuarter {
+                    quarter + w
+                } else {
+                    quarter - w
+                }
+            }
+        };
+        self.mean_jde() + correction + planetary_correction(k, t2)
+    }
+
+    /// Returns the instant of the true phase as a Julian Date in universal
+    /// time, using the ΔT approximation of
+    /// [`delta_t()`][super::timescale::delta_t]
+    pub fn jd(&self) -> f64 {
+        tt2ut(self.jde())
+    }
+
+    /// Returns the Julian day number of the day (UT) on which the phase
+    /// occurs
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the Julian day number is outside the
+    /// range of [`Jdnum`].
+    pub fn julian_day_number(&self) -> Result<Jdnum, ArithmeticError> {
+        jd2jdn(self.jd()).map(|(jdn, _)| jdn)
+    }
+
+    /// Returns the date in the given calendar and the number of seconds since
+    /// midnight (UT) at which the phase occurs
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the Julian day number is outside the
+    /// range of [`Jdnum`].
+    pub fn date(&self, calendar: Calendar) -> Result<(Date, f64), ArithmeticError> {
+        jd2date(self.jd(), calendar)
+    }
+}
+
+/// [Private] Returns the additional corrections for all phases due to the
+/// planets (Meeus, chapter 49)
+fn planetary_correction(k: f64, t2: f64) -> f64 {
+    let terms = [
+        (325.0, 299.77 + 0.107408 * k - 0.009173 * t2),
+        (165.0, 251.88 + 0.016321 * k),
+        (164.0, 251.83 + 26.651886 * k),
+        (126.0, 349.42 + 36.412478 * k),
+        (110.0, 84.66 + 18.206239 * k),
+        (62.0, 141.74 + 53.303771 * k),
+        (60.0, 207.14 + 2.453732 * k),
+        (56.0, 154.84 + 7.306860 * k),
+        (47.0, 34.52 + 27.261239 * k),
+        (42.0, 207.19 + 0.121824 * k),
+        (40.0, 291.34 + 1.844379 * k),
+        (37.0, 161.72 + 24.198154 * k),
+        (35.0, 239.56 + 25.513099 * k),
+        (23.0, 331.55 + 3.592518 * k),
+    ];
+    terms
+        .iter()
+        .map(|&(a, arg)| a * libm::sin(arg.to_radians()))
+        .sum::<f64>()
+        * 0.000001
+}
+
+/// Returns the elongation of the Moon from the Sun in apparent geocentric
+/// longitude at the given Julian Date, in degrees in the range `[0, 360)`.
+/// This is 0 at new moon, 90 at first quarter, 180 at full moon, and 270 at
+/// last quarter.
+///
+/// This uses the principal periodic terms of the Moon's & Sun's longitudes
+/// (Meeus, chapters 47 & 48) and is accurate to within about a degree.
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::moon::{LunarPhase, Phase, elongation};
+///
+/// let full = LunarPhase::new(284, Phase::FullMoon);
+/// assert!((elongation(full.jde()) - 180.0).abs() < 1.0);
+/// ```
+pub fn elongation(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    // Mean elongation of the Moon:
+    let d = (297.8501921 + 445267.1114034 * t - 0.0018819 * t * t).to_radians();
+    // Sun's mean anomaly:
+    let m = (357.5291092 + 35999.0502909 * t - 0.0001536 * t * t).to_radians();
+    // Moon's mean anomaly:
+    let mp = (134.9633964 + 477198.8675055 * t + 0.0087414 * t * t).to_radians();
+    let sin = libm::sin;
+    normalize_degrees(
+        d.to_degrees() + 6.289 * sin(mp) - 2.100 * sin(m)
+            + 1.274 * sin(2.0 * d - mp)
+            + 0.658 * sin(2.0 * d)
+            + 0.214 * sin(2.0 * mp)
+            + 0.110 * sin(d),
+    )
+}
+
+/// Returns the phase angle of the Moon — the angle between the Sun and the
+/// Earth as seen from the Moon — at the given Julian Date, in degrees in the
+/// range `[0, 180]`.  This is 180 at new moon and 0 at full moon.
+pub fn phase_angle(jd: f64) -> f64 {
+    (180.0 - elongation(jd)).abs()
+}
+
+/// Returns the fraction of the Moon's disk that is illuminated at the given
+/// Julian Date, from 0 at new moon to 1 at full moon
+pub fn illuminated_fraction(jd: f64) -> f64 {
+    f64::midpoint(1.0, libm::cos(phase_angle(jd).to_radians()))
+}
+
+/// An iterator over successive occurrences of a phase of the Moon before a
+/// given instant
+///
+/// A `Phases` iterator is returned by [`phases_between()`], [`new_moons()`],
+/// and [`full_moons()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phases {
+    next: Option<LunarPhase>,
+    end: f64,
+}
+
+impl Iterator for Phases {
+    type Item = LunarPhase;
+
+    fn next(&mut self) -> Option<LunarPhase> {
+        let lp = self.next?;
+        if lp.jd() < self.end {
+            self.next = lp.succ();
+            Some(lp)
+        } else {
+            self.next = None;
+            None
+        }
+    }
+}
+
+impl FusedIterator for Phases {}
+
+/// Returns an iterator over the occurrences of the given phase that fall (in
+/// UT) on any day from `start` through `end`, inclusive
+pub fn phases_between(phase: Phase, start: Date, end: Date) -> Phases {
+    let start_jd = f64::from(start.julian_day_number()) - 0.5;
+    let end_jd = f64::from(end.julian_day_number()) + 0.5;
+    Phases {
+        next: Some(LunarPhase::following(start_jd, phase)),
+        end: end_jd,
+    }
+}
+
+/// Returns an iterator over the new moons that fall (in UT) on any day from
+/// `start` through `end`, inclusive
+pub fn new_moons(start: Date, end: Date) -> Phases {
+    phases_between(Phase::NewMoon, start, end)
+}
+
+/// Returns an iterator over the full moons that fall (in UT) on any day from
+/// `start` through `end`, inclusive
+pub fn full_moons(start: Date, end: Date) -> Phases {
+    phases_between(Phase::FullMoon, start, end)
+}
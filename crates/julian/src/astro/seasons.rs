@@ -0,0 +1,178 @@
+//! Equinoxes & solstices
+//!
+//! This module computes the instants of the equinoxes and solstices using the
+//! algorithm of Meeus, *Astronomical Algorithms*, chapter 27.  Over the years
+//! 1951–2050, the results differ from those of the full VSOP87 theory by at
+//! most about a minute; the algorithm is intended for years −1000 through
+//! 3000, and its accuracy degrades gradually outside that range.
+//!
+//! Years are numbered astronomically, so that 1 BC is year 0.  As the
+//! equinoxes and solstices fall in March, June, September, and December, the
+//! year is the same in the Julian and Gregorian calendars for all years within
+//! the algorithm's range.
+//!
+//! # Example
+//!
+//! The Gregorian reform returned the vernal equinox to around March 21, close
+//! to the date it fell on at the time of the Council of Nicaea in 325.  By
+//! 1582, the equinox had drifted to the end of March 10 (UT) in the Julian
+//! calendar:
+//!
+//! ```
+//! use julian::{Calendar, Month, astro::seasons::Season};
+//!
+//! let (date, _) = Season::MarchEquinox.date(325, Calendar::JULIAN).unwrap();
+//! assert_eq!(date.month(), Month::March);
+//! assert_eq!(date.day(), 20);
+//!
+//! let (date, _) = Season::MarchEquinox.date(1582, Calendar::JULIAN).unwrap();
+//! assert_eq!(date.month(), Month::March);
+//! assert_eq!(date.day(), 10);
+//!
+//! let (date, _) = Season::MarchEquinox.date(1583, Calendar::GREGORIAN).unwrap();
+//! assert_eq!(date.month(), Month::March);
+//! assert_eq!(date.day(), 21);
+//! ```
+use super::timescale::tt2ut;
+use super::{jd2date, julian_centuries};
+use crate::errors::ArithmeticError;
+use crate::{Calendar, Date};
+
+/// The periodic terms of Meeus, table 27.C, as `(A, B, C)` triples, where `B`
+/// and `C` are in degrees
+static PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.232),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// An enumeration of the equinoxes and solstices
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Season {
+    /// The March (northward, or vernal) equinox, when the Sun's apparent
+    /// geocentric longitude is 0°
+    MarchEquinox,
+
+    /// The June (northern summer) solstice, when the Sun's apparent
+    /// geocentric longitude is 90°
+    JuneSolstice,
+
+    /// The September (southward, or autumnal) equinox, when the Sun's
+    /// apparent geocentric longitude is 180°
+    SeptemberEquinox,
+
+    /// The December (northern winter) solstice, when the Sun's apparent
+    /// geocentric longitude is 270°
+    DecemberSolstice,
+}
+
+impl Season {
+    /// Returns an iterator over the equinoxes and solstices in the order in
+    /// which they occur within a year
+    pub fn iter() -> impl Iterator<Item = Season> {
+        [
+            Season::MarchEquinox,
+            Season::JuneSolstice,
+            Season::SeptemberEquinox,
+            Season::DecemberSolstice,
+        ]
+        .into_iter()
+    }
+
+    /// [Private] Returns the coefficients of the polynomial giving the
+    /// instant of the mean equinox or solstice (Meeus, tables 27.A and 27.B),
+    /// along with the value of `Y` at which to evaluate it
+    fn mean_polynomial(self, year: i32) -> ([f64; 5], f64) {
+        if year < 1000 {
+            let y = f64::from(year) / 1000.0;
+            let coefficients = match self {
+                Season::MarchEquinox => [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+                Season::JuneSolstice => [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+                Season::SeptemberEquinox => {
+                    [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074]
+                }
+                Season::DecemberSolstice => {
+                    [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006]
+                }
+            };
+            (coefficients, y)
+        } else {
+            let y = f64::from(year - 2000) / 1000.0;
+            let coefficients = match self {
+                Season::MarchEquinox => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+                Season::JuneSolstice => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+                Season::SeptemberEquinox => {
+                    [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078]
+                }
+                Season::DecemberSolstice => {
+                    [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032]
+                }
+            };
+            (coefficients, y)
+        }
+    }
+
+    /// Returns the instant of the equinox or solstice in the given year as a
+    /// Julian Ephemeris Date, i.e., a Julian Date in Terrestrial Time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::seasons::Season;
+    ///
+    /// // Meeus, example 27.a: 1962-06-21 21:25:08 TT
+    /// let jde = Season::JuneSolstice.jde(1962);
+    /// assert!((jde - 2437837.39245).abs() < 1e-5);
+    /// ```
+    pub fn jde(&self, year: i32) -> f64 {
+        let (coefficients, y) = self.mean_polynomial(year);
+        let jde0 = coefficients.iter().rev().fold(0.0, |acc, &c| acc * y + c);
+        let t = julian_centuries(jde0);
+        let w = (35999.373 * t - 2.47).to_radians();
+        let delta_lambda = 1.0 + 0.0334 * libm::cos(w) + 0.0007 * libm::cos(2.0 * w);
+        let s: f64 = PERIODIC_TERMS
+            .iter()
+            .map(|&(a, b, c)| a * libm::cos((b + c * t).to_radians()))
+            .sum();
+        jde0 + 0.00001 * s / delta_lambda
+    }
+
+    /// Returns the instant of the equinox or solstice in the given year as a
+    /// Julian Date in universal time, using the ΔT approximation of
+    /// [`delta_t()`][super::timescale::delta_t]
+    pub fn jd(&self, year: i32) -> f64 {
+        tt2ut(self.jde(year))
+    }
+
+    /// Returns the date in the given calendar and the number of seconds since
+    /// midnight (UT) of the equinox or solstice in the given year
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the Julian day number of the result is
+    /// outside the range of [`Jdnum`][crate::Jdnum].
+    pub fn date(&self, year: i32, calendar: Calendar) -> Result<(Date, f64), ArithmeticError> {
+        jd2date(self.jd(year), calendar)
+    }
+}
@@ -0,0 +1,158 @@
+//! Sidereal time
+//!
+//! Sidereal time measures the rotation of the Earth relative to the vernal
+//! equinox rather than to the Sun.  This module computes Greenwich mean
+//! sidereal time (GMST), Greenwich apparent sidereal time (GAST), which
+//! additionally accounts for the nutation of the equinox, and the
+//! corresponding local sidereal times at a given longitude.
+//!
+//! All functions take a Julian Date in UT1 and return sidereal times in hours
+//! in the range `[0, 24)`.  Two models of GMST are supported, selected with
+//! [`SiderealModel`]; they agree to within a few milliseconds of time over the
+//! present era.  GAST is computed using the low-accuracy nutation series of
+//! Meeus, *Astronomical Algorithms*, chapter 22, which is accurate to about
+//! 0.5″ in longitude, or about 0.03 seconds of time.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::astro::sidereal::SiderealModel;
+//!
+//! // 1987-04-10 00:00 UT (Meeus, example 12.a):
+//! let gmst = SiderealModel::Iau1982.gmst(2446895.5);
+//! // 13h 10m 46.3668s
+//! assert!((gmst * 3600.0 - 47446.3668).abs() < 1e-3);
+//! ```
+use super::timescale::ut2tt;
+use super::{J2000, julian_centuries, mean_obliquity, normalize_degrees};
+
+/// The number of degrees of rotation per hour of sidereal time
+const DEGREES_PER_HOUR: f64 = 15.0;
+
+/// An enumeration of models of Greenwich mean sidereal time
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SiderealModel {
+    /// The IAU 1982 expression for GMST as a polynomial in UT1 (Meeus,
+    /// equation 12.4)
+    Iau1982,
+
+    /// The IAU 2006 expression for GMST in terms of the Earth rotation angle
+    /// and a polynomial in TT (Capitaine et al., 2003).  TT is approximated
+    /// from UT1 using [`delta_t()`][super::timescale::delta_t], the error in
+    /// which has a negligible effect on the result.
+    #[default]
+    Iau2006,
+}
+
+impl SiderealModel {
+    /// Returns the Greenwich mean sidereal time, in hours, at the given Julian
+    /// Date (UT1)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::sidereal::SiderealModel;
+    ///
+    /// // 1987-04-10 19:21:00 UT (Meeus, example 12.b):
+    /// let gmst = SiderealModel::Iau1982.gmst(2446896.30625);
+    /// // 8h 34m 57.0896s
+    /// assert!((gmst * 3600.0 - 30897.0896).abs() < 1e-3);
+    /// ```
+    pub fn gmst(&self, jd: f64) -> f64 {
+        normalize_degrees(self.gmst_degrees(jd)) / DEGREES_PER_HOUR
+    }
+
+    /// [Private] Returns GMST in degrees, not reduced to `[0, 360)`
+    fn gmst_degrees(&self, jd: f64) -> f64 {
+        match self {
+            SiderealModel::Iau1982 => {
+                let t = julian_centuries(jd);
+                280.46061837
+                    + 360.98564736629 * (jd - J2000)
+                    + t * t * (0.000387933 - t / 38710000.0)
+            }
+            SiderealModel::Iau2006 => {
+                let t = julian_centuries(ut2tt(jd));
+                let arcseconds = 0.014506
+                    + t * (4612.156534
+                        + t * (1.3915817
+                            + t * (-0.00000044 + t * (-0.000029956 - t * 0.0000000368))));
+                earth_rotation_angle(jd) + arcseconds / 3600.0
+            }
+        }
+    }
+
+    /// Returns the Greenwich apparent sidereal time, in hours, at the given
+    /// Julian Date (UT1)
+    pub fn gast(&self, jd: f64) -> f64 {
+        normalize_degrees(self.gast_degrees(jd)) / DEGREES_PER_HOUR
+    }
+
+    /// [Private] Returns GAST in degrees, not reduced to `[0, 360)`
+    fn gast_degrees(&self, jd: f64) -> f64 {
+        let ee_degrees = equation_of_the_equinoxes(jd) * DEGREES_PER_HOUR / 3600.0;
+        self.gmst_degrees(jd) + ee_degrees
+    }
+
+    /// Returns the local mean sidereal time, in hours, at the given longitude
+    /// (in degrees east of Greenwich) and Julian Date (UT1)
+    pub fn local_mean(&self, jd: f64, longitude: f64) -> f64 {
+        normalize_degrees(self.gmst_degrees(jd) + longitude) / DEGREES_PER_HOUR
+    }
+
+    /// Returns the local apparent sidereal time, in hours, at the given
+    /// longitude (in degrees east of Greenwich) and Julian Date (UT1)
+    pub fn local_apparent(&self, jd: f64, longitude: f64) -> f64 {
+        normalize_degrees(self.gast_degrees(jd) + longitude) / DEGREES_PER_HOUR
+    }
+}
+
+/// Returns the Earth rotation angle, in degrees in the range `[0, 360)`, at
+/// the given Julian Date (UT1), as defined by IAU 2000 Resolution B1.8
+pub fn earth_rotation_angle(jd: f64) -> f64 {
+    let du = jd - J2000;
+    // Splitting off the whole days of `du` preserves precision, as each whole
+    // day contributes exactly one full rotation plus a small excess.
+    let whole = libm::floor(du);
+    let fraction = du - whole;
+    normalize_degrees(360.0 * (0.7790572732640 + fraction + 0.00273781191135448 * du))
+}
+
+/// Returns the equation of the equinoxes — the difference between apparent
+/// and mean sidereal time — in seconds of time at the given Julian Date
+///
+/// This uses the low-accuracy nutation series of Meeus, *Astronomical
+/// Algorithms*, chapter 22, and is accurate to about 0.03 seconds.
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::sidereal::equation_of_the_equinoxes;
+///
+/// // 1987-04-10 00:00 (Meeus, example 12.a):
+/// let ee = equation_of_the_equinoxes(2446895.5);
+/// assert!((ee - -0.2317).abs() < 0.03);
+/// ```
+pub fn equation_of_the_equinoxes(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    let (delta_psi, delta_epsilon) = nutation(t);
+    let epsilon = mean_obliquity(t) + delta_epsilon / 3600.0;
+    delta_psi * libm::cos(epsilon.to_radians()) / DEGREES_PER_HOUR
+}
+
+/// [Private] Returns the nutation in longitude and the nutation in obliquity,
+/// both in arcseconds, for the given number of Julian centuries since J2000.0
+fn nutation(t: f64) -> (f64, f64) {
+    // Longitude of the ascending node of the Moon's mean orbit:
+    let omega = (125.04452 - 1934.136261 * t).to_radians();
+    // Mean longitudes of the Sun and Moon:
+    let l_sun = (280.4665 + 36000.7698 * t).to_radians();
+    let l_moon = (218.3165 + 481267.8813 * t).to_radians();
+    let delta_psi =
+        -17.20 * libm::sin(omega) - 1.32 * libm::sin(2.0 * l_sun) - 0.23 * libm::sin(2.0 * l_moon)
+            + 0.21 * libm::sin(2.0 * omega);
+    let delta_epsilon =
+        9.20 * libm::cos(omega) + 0.57 * libm::cos(2.0 * l_sun) + 0.10 * libm::cos(2.0 * l_moon)
+            - 0.09 * libm::cos(2.0 * omega);
+    (delta_psi, delta_epsilon)
+}
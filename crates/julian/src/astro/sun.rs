@@ -0,0 +1,221 @@
+//! Sunrise, sunset, & twilight
+//!
+//! This module computes the times of sunrise, solar noon, and sunset — or of
+//! the beginning & end of civil, nautical, or astronomical twilight — on a
+//! given date at a given location, using the algorithm of the NOAA solar
+//! calculator, which is based on Meeus, *Astronomical Algorithms*.  Results
+//! are accurate to within about a minute for latitudes between ±72° and
+//! within about ten minutes closer to the poles, before accounting for local
+//! variations in atmospheric refraction and the height of the horizon.
+//!
+//! Latitudes are given in degrees north of the equator (with southern
+//! latitudes negative), and longitudes are given in degrees east of Greenwich
+//! (with western longitudes negative).  The events computed for a [`Date`] are
+//! those surrounding the solar noon nearest to noon local mean time on that
+//! date; all times are returned as Julian Dates in UT, which can be converted
+//! to dates & times of day with [`jd2date()`][super::jd2date].
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, astro::{jd2date, sun::{Horizon, sun_times}}};
+//!
+//! // Greenwich on the June solstice of 2023:
+//! let date = Calendar::GREGORIAN.at_ymd(2023, Month::June, 21).unwrap();
+//! let times = sun_times(date, 51.4769, -0.0005, Horizon::Official);
+//! let (rise_date, rise) = jd2date(times.sunrise().unwrap(), Calendar::GREGORIAN).unwrap();
+//! assert_eq!(rise_date, date);
+//! // 03:43 UTC
+//! assert!((rise - 13380.0).abs() < 60.0);
+//! let (_, set) = jd2date(times.sunset().unwrap(), Calendar::GREGORIAN).unwrap();
+//! // 20:21 UTC
+//! assert!((set - 73260.0).abs() < 60.0);
+//!
+//! // The Sun never sinks far enough below the horizon for astronomical
+//! // twilight to end:
+//! let times = sun_times(date, 51.4769, -0.0005, Horizon::Astronomical);
+//! assert!(times.is_polar_day());
+//! ```
+use super::{SECONDS_IN_DAY, equation_of_time, julian_centuries, mean_obliquity};
+use crate::Date;
+
+/// An enumeration of the altitudes of the Sun's center that define the
+/// boundary between day and night for different purposes
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Horizon {
+    /// Sunrise & sunset, when the upper limb of the Sun appears on the
+    /// horizon.  This occurs when the Sun's center is 50′ below the horizon,
+    /// accounting for the Sun's semidiameter and atmospheric refraction.
+    #[default]
+    Official,
+
+    /// Civil twilight, which begins & ends when the Sun's center is 6° below
+    /// the horizon
+    Civil,
+
+    /// Nautical twilight, which begins & ends when the Sun's center is 12°
+    /// below the horizon
+    Nautical,
+
+    /// Astronomical twilight, which begins & ends when the Sun's center is
+    /// 18° below the horizon
+    Astronomical,
+}
+
+impl Horizon {
+    /// Returns the altitude of the Sun's center, in degrees, at which the
+    /// events for this horizon occur
+    pub const fn altitude(&self) -> f64 {
+        match self {
+            Horizon::Official => -50.0 / 60.0,
+            Horizon::Civil => -6.0,
+            Horizon::Nautical => -12.0,
+            Horizon::Astronomical => -18.0,
+        }
+    }
+}
+
+/// Whether & when the Sun crosses a [`Horizon`] on a given day
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Daylight {
+    /// The Sun rises above and sets below the horizon at the given instants
+    Normal {
+        /// The instant at which the Sun rises above the horizon, as a Julian
+        /// Date (UT)
+        sunrise: f64,
+
+        /// The instant at which the Sun sets below the horizon, as a Julian
+        /// Date (UT)
+        sunset: f64,
+    },
+
+    /// The Sun remains above the horizon all day
+    PolarDay,
+
+    /// The Sun remains below the horizon all day
+    PolarNight,
+}
+
+/// The times of solar events at a given location on a given day, as returned
+/// by [`sun_times()`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SunTimes {
+    solar_noon: f64,
+    daylight: Daylight,
+}
+
+impl SunTimes {
+    /// Returns the instant of solar noon, when the Sun crosses the meridian,
+    /// as a Julian Date (UT)
+    pub const fn solar_noon(&self) -> f64 {
+        self.solar_noon
+    }
+
+    /// Returns whether & when the Sun crosses the horizon
+    pub const fn daylight(&self) -> Daylight {
+        self.daylight
+    }
+
+    /// Returns the instant at which the Sun rises above the horizon, as a
+    /// Julian Date (UT), or `None` if the Sun does not cross the horizon that
+    /// day
+    pub const fn sunrise(&self) -> Option<f64> {
+        match self.daylight {
+            Daylight::Normal { sunrise, .. } => Some(sunrise),
+            _ => None,
+        }
+    }
+
+    /// Returns the instant at which the Sun sets below the horizon, as a
+    /// Julian Date (UT), or `None` if the Sun does not cross the horizon that
+    /// day
+    pub const fn sunset(&self) -> Option<f64> {
+        match self.daylight {
+            Daylight::Normal { sunset, .. } => Some(sunset),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the Sun remains above the horizon all day
+    pub const fn is_polar_day(&self) -> bool {
+        matches!(self.daylight, Daylight::PolarDay)
+    }
+
+    /// Returns true if the Sun remains below the horizon all day
+    pub const fn is_polar_night(&self) -> bool {
+        matches!(self.daylight, Daylight::PolarNight)
+    }
+
+    /// Returns the number of seconds between sunrise and sunset.  This is
+    /// 86400 for a polar day and 0 for a polar night.
+    pub fn day_length(&self) -> f64 {
+        match self.daylight {
+            Daylight::Normal { sunrise, sunset } => (sunset - sunrise) * SECONDS_IN_DAY,
+            Daylight::PolarDay => SECONDS_IN_DAY,
+            Daylight::PolarNight => 0.0,
+        }
+    }
+}
+
+/// Computes the times of solar noon and of the Sun's crossings of the given
+/// horizon on the given date at the given latitude & longitude
+///
+/// If the Sun does not cross the horizon on the date, the result will report
+/// a [polar day][Daylight::PolarDay] or [polar night][Daylight::PolarNight].
+pub fn sun_times(date: Date, latitude: f64, longitude: f64, horizon: Horizon) -> SunTimes {
+    let local_noon = f64::from(date.julian_day_number()) - longitude / 360.0;
+    let transit = |jd: f64| local_noon - equation_of_time(jd) / SECONDS_IN_DAY;
+    let mut solar_noon = local_noon;
+    for _ in 0..2 {
+        solar_noon = transit(solar_noon);
+    }
+    let latitude = latitude.to_radians();
+    let altitude = horizon.altitude().to_radians();
+    let cos_hour_angle = |jd: f64| {
+        let decl = declination(jd);
+        (libm::sin(altitude) - libm::sin(latitude) * libm::sin(decl))
+            / (libm::cos(latitude) * libm::cos(decl))
+    };
+    let cos_h = cos_hour_angle(solar_noon);
+    let daylight = if cos_h > 1.0 {
+        Daylight::PolarNight
+    } else if cos_h < -1.0 {
+        Daylight::PolarDay
+    } else {
+        // Refine each event using the Sun's position at the estimated time of
+        // the event itself:
+        let event = |sign: f64| {
+            let mut jd = solar_noon;
+            for _ in 0..3 {
+                let hour_angle = libm::acos(cos_hour_angle(jd).clamp(-1.0, 1.0));
+                jd = transit(jd) + sign * hour_angle.to_degrees() / 360.0;
+            }
+            jd
+        };
+        Daylight::Normal {
+            sunrise: event(-1.0),
+            sunset: event(1.0),
+        }
+    };
+    SunTimes {
+        solar_noon,
+        daylight,
+    }
+}
+
+/// [Private] Returns the Sun's apparent declination in radians at the given
+/// Julian Date
+fn declination(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    // Geometric mean longitude & mean anomaly of the Sun:
+    let l0 = 280.46646 + t * (36000.76983 + t * 0.0003032);
+    let m = (357.52911 + t * (35999.05029 - 0.0001537 * t)).to_radians();
+    // Equation of the center:
+    let c = libm::sin(m) * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + libm::sin(2.0 * m) * (0.019993 - 0.000101 * t)
+        + libm::sin(3.0 * m) * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude = (l0 + c - 0.00569 - 0.00478 * libm::sin(omega)).to_radians();
+    let obliquity = (mean_obliquity(t) + 0.00256 * libm::cos(omega)).to_radians();
+    libm::asin(libm::sin(obliquity) * libm::sin(apparent_longitude))
+}
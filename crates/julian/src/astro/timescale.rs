@@ -0,0 +1,417 @@
+//! Conversions between astronomical time scales
+//!
+//! This module converts Julian Dates between the following time scales:
+//!
+//! - Coordinated Universal Time (UTC), the basis of civil time, which is kept
+//!   within a second of UT1 by the insertion of leap seconds
+//!
+//! - International Atomic Time (TAI), which differs from UTC by a whole number
+//!   of seconds that changes whenever a leap second occurs.  These changes are
+//!   recorded in a [`LeapSecondTable`].
+//!
+//! - Terrestrial Time (TT), the time scale used for geocentric ephemerides,
+//!   which is always exactly [`TT_MINUS_TAI`] seconds ahead of TAI
+//!
+//! - Universal Time (UT1), which follows the rotation of the Earth.  The
+//!   difference TT − UT1, known as ΔT, is not predictable; this module
+//!   [approximates it][delta_t] using the polynomials of Espenak & Meeus.
+//!
+//! As a UTC day containing a leap second is 86401 seconds long, UTC instants
+//! are given as a Julian day number plus a number of seconds since midnight
+//! rather than as a fractional Julian Date.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, astro::timescale::{LeapSecondTable, tai2tt}};
+//!
+//! let date = Calendar::GREGORIAN.at_ymd(2023, Month::May, 1).unwrap();
+//! let table = LeapSecondTable::BUILTIN;
+//! assert_eq!(table.tai_minus_utc(date.julian_day_number()), Some(37));
+//! let tai = table.utc2tai(date.julian_day_number(), 0.0).unwrap();
+//! let tt = tai2tt(tai);
+//! assert!((tt - 2460065.5 - 69.184 / 86400.0).abs() < 1e-9);
+//! ```
+use super::{J2000, SECONDS_IN_DAY, jd2jdn, jdn2jd};
+use crate::Jdnum;
+use crate::errors::LeapSecondTableError;
+
+/// The number of seconds by which Terrestrial Time is ahead of International
+/// Atomic Time
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+/// The number of days in a Julian year
+const DAYS_IN_YEAR: f64 = 365.25;
+
+/// An entry in a [`LeapSecondTable`], recording the value of TAI − UTC in
+/// effect from the start of a given UTC day
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LeapSecond {
+    jdn: Jdnum,
+    tai_minus_utc: i32,
+}
+
+impl LeapSecond {
+    /// Construct an entry stating that TAI − UTC equals `tai_minus_utc`
+    /// seconds from the start of the day with Julian day number `jdn`
+    pub const fn new(jdn: Jdnum, tai_minus_utc: i32) -> LeapSecond {
+        LeapSecond { jdn, tai_minus_utc }
+    }
+
+    /// Returns the Julian day number of the day on which the entry takes
+    /// effect
+    pub const fn julian_day_number(&self) -> Jdnum {
+        self.jdn
+    }
+
+    /// Returns the number of seconds by which TAI is ahead of UTC from the
+    /// start of the entry's day
+    pub const fn tai_minus_utc(&self) -> i32 {
+        self.tai_minus_utc
+    }
+}
+
+/// The leap seconds announced by the IERS as of Bulletin C 70 (July 2025),
+/// starting with the introduction of whole-second offsets on 1972-01-01
+static BUILTIN_LEAP_SECONDS: [LeapSecond; 28] = [
+    LeapSecond::new(2441318, 10), // 1972-01-01
+    LeapSecond::new(2441500, 11), // 1972-07-01
+    LeapSecond::new(2441684, 12), // 1973-01-01
+    LeapSecond::new(2442049, 13), // 1974-01-01
+    LeapSecond::new(2442414, 14), // 1975-01-01
+    LeapSecond::new(2442779, 15), // 1976-01-01
+    LeapSecond::new(2443145, 16), // 1977-01-01
+    LeapSecond::new(2443510, 17), // 1978-01-01
+    LeapSecond::new(2443875, 18), // 1979-01-01
+    LeapSecond::new(2444240, 19), // 1980-01-01
+    LeapSecond::new(2444787, 20), // 1981-07-01
+    LeapSecond::new(2445152, 21), // 1982-07-01
+    LeapSecond::new(2445517, 22), // 1983-07-01
+    LeapSecond::new(2446248, 23), // 1985-07-01
+    LeapSecond::new(2447162, 24), // 1988-01-01
+    LeapSecond::new(2447893, 25), // 1990-01-01
+    LeapSecond::new(2448258, 26), // 1991-01-01
+    LeapSecond::new(2448805, 27), // 1992-07-01
+    LeapSecond::new(2449170, 28), // 1993-07-01
+    LeapSecond::new(2449535, 29), // 1994-07-01
+    LeapSecond::new(2450084, 30), // 1996-01-01
+    LeapSecond::new(2450631, 31), // 1997-07-01
+    LeapSecond::new(2451180, 32), // 1999-01-01
+    LeapSecond::new(2453737, 33), // 2006-01-01
+    LeapSecond::new(2454833, 34), // 2009-01-01
+    LeapSecond::new(2456110, 35), // 2012-07-01
+    LeapSecond::new(2457205, 36), // 2015-07-01
+    LeapSecond::new(2457755, 37), // 2017-01-01
+];
+
+/// A table of the values of TAI − UTC over time, used to convert between UTC
+/// and TAI.
+///
+/// [`LeapSecondTable::BUILTIN`] contains the leap seconds known as of this
+/// crate's release.  When the IERS announces a new leap second, a table
+/// including it can be constructed with [`LeapSecondTable::new()`].
+///
+/// UTC before 1972 (when its offset from TAI was not a whole number of
+/// seconds) is not supported.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LeapSecondTable<'a> {
+    entries: &'a [LeapSecond],
+}
+
+impl<'a> LeapSecondTable<'a> {
+    /// The table of leap seconds built into this crate, current as of IERS
+    /// Bulletin C 70 (July 2025), which announced no leap second through at
+    /// least June 2026
+    pub const BUILTIN: LeapSecondTable<'static> = LeapSecondTable {
+        entries: &BUILTIN_LEAP_SECONDS,
+    };
+
+    /// Construct a table from a list of entries
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LeapSecondTableError::Empty`] if `entries` is empty.
+    ///
+    /// Returns [`LeapSecondTableError::Unsorted`] if the entries are not in
+    /// strictly ascending order of Julian day number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::timescale::{LeapSecond, LeapSecondTable};
+    ///
+    /// let mut entries = LeapSecondTable::BUILTIN.entries().to_vec();
+    /// // A hypothetical leap second at the end of 2030-12-31:
+    /// entries.push(LeapSecond::new(2462868, 38));
+    /// let table = LeapSecondTable::new(&entries).unwrap();
+    /// assert_eq!(table.tai_minus_utc(2462868), Some(38));
+    /// ```
+    pub fn new(entries: &'a [LeapSecond]) -> Result<LeapSecondTable<'a>, LeapSecondTableError> {
+        if entries.is_empty() {
+            Err(LeapSecondTableError::Empty)
+        } else if entries
+            .windows(2)
+            .any(|w| matches!(w, [a, b] if a.jdn >= b.jdn))
+        {
+            Err(LeapSecondTableError::Unsorted)
+        } else {
+            Ok(LeapSecondTable { entries })
+        }
+    }
+
+    /// Returns the entries of the table
+    pub const fn entries(&self) -> &'a [LeapSecond] {
+        self.entries
+    }
+
+    /// [Private] Returns the index of the entry in effect on the day with the
+    /// given Julian day number, or `None` if the day is before the first
+    /// entry
+    fn index_for(&self, jdn: Jdnum) -> Option<usize> {
+        self.entries
+            .partition_point(|ls| ls.jdn <= jdn)
+            .checked_sub(1)
+    }
+
+    /// Returns the number of seconds by which TAI is ahead of UTC at the start
+    /// of the UTC day with the given Julian day number.  Returns `None` if the
+    /// day is before the first entry in the table.
+    pub fn tai_minus_utc(&self, jdn: Jdnum) -> Option<i32> {
+        let i = self.index_for(jdn)?;
+        self.entries.get(i).map(LeapSecond::tai_minus_utc)
+    }
+
+    /// Converts a UTC instant, given as a Julian day number plus a number of
+    /// seconds since midnight UTC (which may be 86400 or more during a leap
+    /// second), to a Julian Date in TAI.  Returns `None` if the day is before
+    /// the first entry in the table.
+    pub fn utc2tai(&self, jdn: Jdnum, seconds: f64) -> Option<f64> {
+        let offset = self.tai_minus_utc(jdn)?;
+        Some(jdn2jd(jdn, seconds + f64::from(offset)))
+    }
+
+    /// Converts a Julian Date in TAI to a UTC instant, given as a Julian day
+    /// number plus a number of seconds since midnight UTC.  During a leap
+    /// second, the number of seconds will be 86400 or more.  Returns `None`
+    /// if the instant is before the first entry in the table or if the Julian
+    /// day number would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::astro::timescale::LeapSecondTable;
+    ///
+    /// let table = LeapSecondTable::BUILTIN;
+    /// // Half a second into the leap second at the end of 2016-12-31
+    /// // (JDN 2457754):
+    /// let tai = table.utc2tai(2457754, 86400.5).unwrap();
+    /// let (jdn, seconds) = table.tai2utc(tai).unwrap();
+    /// assert_eq!(jdn, 2457754);
+    /// assert!((seconds - 86400.5).abs() < 1e-3);
+    /// ```
+    pub fn tai2utc(&self, jd: f64) -> Option<(Jdnum, f64)> {
+        // Find the last entry whose start (expressed in TAI) is not after
+        // `jd`:
+        let i = self
+            .entries
+            .partition_point(|ls| jdn2jd(ls.jdn, f64::from(ls.tai_minus_utc)) <= jd)
+            .checked_sub(1)?;
+        let entry = self.entries.get(i)?;
+        let (jdn, seconds) = jd2jdn(jd - f64::from(entry.tai_minus_utc) / SECONDS_IN_DAY).ok()?;
+        match self.entries.get(i + 1) {
+            // If the result is past the start of the next entry's day, then
+            // `jd` is within a leap second at the end of the preceding day.
+            Some(next) if jdn >= next.jdn => Some((jdn - 1, seconds + SECONDS_IN_DAY)),
+            _ => Some((jdn, seconds)),
+        }
+    }
+}
+
+impl Default for LeapSecondTable<'_> {
+    /// Returns [`LeapSecondTable::BUILTIN`]
+    fn default() -> Self {
+        LeapSecondTable::BUILTIN
+    }
+}
+
+/// Converts a Julian Date in TAI to a Julian Date in TT
+pub fn tai2tt(jd: f64) -> f64 {
+    jd + TT_MINUS_TAI / SECONDS_IN_DAY
+}
+
+/// Converts a Julian Date in TT to a Julian Date in TAI
+pub fn tt2tai(jd: f64) -> f64 {
+    jd - TT_MINUS_TAI / SECONDS_IN_DAY
+}
+
+/// Returns an approximation of ΔT = TT − UT1, in seconds, for the given
+/// decimal year (e.g., 1990.5 for the middle of 1990), using the polynomial
+/// expressions of Espenak & Meeus from *Five Millennium Canon of Solar
+/// Eclipses: −1999 to +3000* (2006).  Years are numbered astronomically, so
+/// that 1 BC is year 0.
+///
+/// As ΔT can only be determined from observations, the accuracy of these
+/// expressions depends on the era:
+///
+/// - Since about 1900, ΔT has been measured precisely, and the expressions are
+///   accurate to within about a second.
+///
+/// - From 1600 to 1900, ΔT is known from telescopic observations to within
+///   roughly 1 second in the 1800s, 5 seconds around 1700, and 20 seconds
+///   around 1600.
+///
+/// - Before 1600, ΔT is derived from records of eclipses and occultations, and
+///   its uncertainty grows to about 1 minute in the year 1000, 4 minutes in
+///   the year 0, and 7 minutes by 500 BC.  Before 500 BC, the expressions are
+///   an extrapolation from a parabolic model, and the uncertainty is
+///   measured in hours by 2000 BC.
+///
+/// - After the present, ΔT is an extrapolation whose uncertainty grows by
+///   roughly a minute per century.
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::timescale::delta_t;
+///
+/// assert!((delta_t(2000.0) - 63.86).abs() < 1e-9);
+/// assert!((delta_t(1000.0) - 1574.2).abs() < 1e-9);
+/// ```
+pub fn delta_t(year: f64) -> f64 {
+    let y = year;
+    if y < -500.0 {
+        long_term_parabola(y)
+    } else if y < 500.0 {
+        polynomial(
+            y / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        )
+    } else if y < 1600.0 {
+        polynomial(
+            (y - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        )
+    } else if y < 1700.0 {
+        polynomial(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0])
+    } else if y < 1800.0 {
+        polynomial(
+            y - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+        )
+    } else if y < 1860.0 {
+        polynomial(
+            y - 1800.0,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        )
+    } else if y < 1900.0 {
+        polynomial(
+            y - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233174.0,
+            ],
+        )
+    } else if y < 1920.0 {
+        polynomial(
+            y - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        )
+    } else if y < 1941.0 {
+        polynomial(y - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936])
+    } else if y < 1961.0 {
+        polynomial(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0])
+    } else if y < 1986.0 {
+        polynomial(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+    } else if y < 2005.0 {
+        polynomial(
+            y - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        )
+    } else if y < 2050.0 {
+        polynomial(y - 2000.0, &[62.92, 0.32217, 0.005589])
+    } else if y < 2150.0 {
+        long_term_parabola(y) - 0.5628 * (2150.0 - y)
+    } else {
+        long_term_parabola(y)
+    }
+}
+
+/// [Private] The long-term parabolic model of ΔT used by Espenak & Meeus
+/// outside of the range of historical observations
+fn long_term_parabola(year: f64) -> f64 {
+    let u = (year - 1820.0) / 100.0;
+    -20.0 + 32.0 * u * u
+}
+
+/// [Private] Evaluate the polynomial with the given coefficients (in order of
+/// increasing degree) at `x`
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Returns the decimal year corresponding to a Julian Date, reckoning years
+/// as Julian years of 365.25 days from J2000.0.  This is the form of year
+/// expected by [`delta_t()`].
+pub fn decimal_year(jd: f64) -> f64 {
+    2000.0 + (jd - J2000) / DAYS_IN_YEAR
+}
+
+/// Converts a Julian Date in UT1 to a Julian Date in TT using the ΔT
+/// approximation of [`delta_t()`]
+///
+/// # Example
+///
+/// ```
+/// use julian::astro::timescale::ut2tt;
+///
+/// // 1000-01-01 00:00 UT (Julian calendar), when ΔT was about 26 minutes:
+/// let tt = ut2tt(2086307.5);
+/// assert!(((tt - 2086307.5) * 86400.0 - 1574.0).abs() < 1.0);
+/// ```
+pub fn ut2tt(jd: f64) -> f64 {
+    jd + delta_t(decimal_year(jd)) / SECONDS_IN_DAY
+}
+
+/// Converts a Julian Date in TT to a Julian Date in UT1 using the ΔT
+/// approximation of [`delta_t()`]
+pub fn tt2ut(jd: f64) -> f64 {
+    // ΔT changes by well under a second per day, so evaluating it at the TT
+    // instant instead of the UT instant makes no practical difference.
+    jd - delta_t(decimal_year(jd)) / SECONDS_IN_DAY
+}
@@ -0,0 +1,379 @@
+//! The Byzantine calendar, with years counted *Anno Mundi*
+//!
+//! The [Byzantine calendar][byz] uses the months and leap years of the Julian
+//! calendar, but it numbers years from the creation of the world (*Anno
+//! Mundi*, "AM"), which it dates to 5509 BC, and each year begins on
+//! September 1.  Thus, AM 7208 ran from September 1, 1699, through August
+//! 31, 1700 (Julian).  This reckoning was used throughout the Byzantine Empire
+//! and in Russia until Peter the Great decreed that, following December 31 of
+//! AM 7208, the new year would begin on January 1, AD 1700; Russia then kept
+//! using the Julian calendar until switching to the Gregorian calendar in
+//! 1918.
+//!
+//! A [`ByzantineCalendar`] pairs an underlying [`Calendar`], which determines
+//! the months & leap years, with an optional date on which year numbering
+//! switches from *Anno Mundi* to *Anno Domini*.  Two instances are provided:
+//! [`ByzantineCalendar::BYZANTINE`], which uses the proleptic Julian calendar
+//! and counts years *Anno Mundi* forever, and [`ByzantineCalendar::RUSSIA`],
+//! which models the Russian switch to *Anno Domini* in 1700 and the
+//! reformation at [`ncal::RUSSIA`].
+//!
+//! Note that, prior to 1492, Russian years generally began on March 1 rather
+//! than September 1; this module does not attempt to model that.
+//!
+//! [byz]: https://en.wikipedia.org/wiki/Byzantine_calendar
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Month, byzantine::{ByzantineCalendar, Era}};
+//!
+//! let cal = ByzantineCalendar::RUSSIA;
+//! let date = cal.at_ymd(Era::AnnoMundi, 7208, Month::December, 31).unwrap();
+//! assert_eq!(date.to_string(), "7208-12-31 AM");
+//! assert_eq!(date.indiction(), 8);
+//! let next = cal.at_jdn(date.julian_day_number() + 1);
+//! assert_eq!(next.to_string(), "1700-01-01 AD");
+//! ```
+use crate::errors::{ByzantineDateError, DateError};
+use crate::{Calendar, Date, Jdnum, Month, ncal};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// The number of years that the *Anno Mundi* year of a date in January through
+/// August exceeds the astronomical *Anno Domini* year of the date
+const AM_OFFSET: i32 = 5508;
+
+/// The number of years in an indiction cycle
+const INDICTION_CYCLE: i32 = 15;
+
+/// The Julian day number of 1700-01-01 O.S., the first day on which Russia
+/// numbered years *Anno Domini*
+const RUSSIA_ANNO_DOMINI: Jdnum = 2341983;
+
+/// An era in which years in a [`ByzantineCalendar`] are numbered
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Era {
+    /// Years are counted from the creation of the world (September 1, 5509
+    /// BC) and begin on September 1
+    AnnoMundi,
+
+    /// Years are counted from the (astronomically-numbered) Common Era and
+    /// begin on January 1
+    AnnoDomini,
+}
+
+impl Era {
+    /// Returns the abbreviation for the era, either "AM" or "AD"
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Era::AnnoMundi => "AM",
+            Era::AnnoDomini => "AD",
+        }
+    }
+
+    /// [Private] `const` equivalent of `==`
+    const fn eq(&self, other: Era) -> bool {
+        (*self as u8) == (other as u8)
+    }
+}
+
+impl fmt::Display for Era {
+    /// An `Era` is displayed as its abbreviation
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.abbreviation())
+    }
+}
+
+/// A calendar with Julian-style months that numbers years *Anno Mundi*,
+/// possibly switching to *Anno Domini* at some point.
+///
+/// `ByzantineCalendar` instances are ordered first by underlying
+/// [`Calendar`], then by the start of *Anno Domini* numbering, with
+/// calendars that never switch to *Anno Domini* coming first.
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ByzantineCalendar {
+    calendar: Calendar,
+    anno_domini_start: Option<Jdnum>,
+}
+
+impl ByzantineCalendar {
+    /// The Byzantine calendar proper, using the proleptic Julian calendar and
+    /// numbering all years *Anno Mundi*
+    pub const BYZANTINE: ByzantineCalendar = ByzantineCalendar {
+        calendar: Calendar::JULIAN,
+        anno_domini_start: None,
+    };
+
+    /// The calendar as used in Russia: years are numbered *Anno Mundi* until
+    /// the end of AM 7208 (1699-12-31 O.S.), after which they are numbered
+    /// *Anno Domini* starting with 1700-01-01 O.S.; the Gregorian calendar is
+    /// used starting at [`ncal::RUSSIA`] (1918-02-14, following 1918-01-31
+    /// O.S.).
+    pub const RUSSIA: ByzantineCalendar = ByzantineCalendar {
+        calendar: match Calendar::reforming(ncal::RUSSIA) {
+            Ok(cal) => cal,
+            Err(_) => panic!("ncal::RUSSIA should be a valid reformation"),
+        },
+        anno_domini_start: Some(RUSSIA_ANNO_DOMINI),
+    };
+
+    /// Construct a `ByzantineCalendar` with months & leap years determined by
+    /// `calendar` and with years numbered *Anno Mundi* until (but not
+    /// including) the Julian day number `anno_domini_start`, if any, after
+    /// which years are numbered *Anno Domini*.
+    pub const fn new(calendar: Calendar, anno_domini_start: Option<Jdnum>) -> ByzantineCalendar {
+        ByzantineCalendar {
+            calendar,
+            anno_domini_start,
+        }
+    }
+
+    /// Returns the underlying [`Calendar`]
+    pub const fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    /// Returns the Julian day number of the first date on which years are
+    /// numbered *Anno Domini*, if any
+    pub const fn anno_domini_start(&self) -> Option<Jdnum> {
+        self.anno_domini_start
+    }
+
+    /// Returns the era in use on the given Julian day number
+    pub const fn era_at(&self, jdn: Jdnum) -> Era {
+        match self.anno_domini_start {
+            Some(start) if start <= jdn => Era::AnnoDomini,
+            _ => Era::AnnoMundi,
+        }
+    }
+
+    /// Returns the date with the given Julian day number under the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, byzantine::{ByzantineCalendar, Era}};
+    ///
+    /// let date = ByzantineCalendar::BYZANTINE.at_jdn(2251915);
+    /// assert_eq!(date.era(), Era::AnnoMundi);
+    /// assert_eq!(date.year(), 6961);
+    /// assert_eq!(date.month(), Month::May);
+    /// assert_eq!(date.day(), 29);
+    /// ```
+    pub const fn at_jdn(&self, jdn: Jdnum) -> ByzantineDate {
+        let date = self.calendar.at_jdn(jdn);
+        let era = self.era_at(jdn);
+        let year = match era {
+            Era::AnnoMundi => am_year(date.year(), date.month()),
+            Era::AnnoDomini => date.year(),
+        };
+        ByzantineDate {
+            calendar: *self,
+            era,
+            year,
+            date,
+        }
+    }
+
+    /// Returns the date of the calendar with the given era, year, month, and
+    /// day of month.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ByzantineDateError::InvalidDate`] if the corresponding date
+    /// in the underlying calendar is invalid; see [`Calendar::at_ymd()`] for
+    /// details.
+    ///
+    /// Returns [`ByzantineDateError::EraMismatch`] if the given date falls on
+    /// a day on which years were not numbered in the given era, e.g., if an
+    /// *Anno Mundi* year is given for a date in [`ByzantineCalendar::RUSSIA`]
+    /// after 1699.
+    pub const fn at_ymd(
+        &self,
+        era: Era,
+        year: i32,
+        month: Month,
+        day: u32,
+    ) -> Result<ByzantineDate, ByzantineDateError> {
+        let ad_year = match era {
+            Era::AnnoMundi => {
+                let offset = if Month::September.le(month) {
+                    AM_OFFSET + 1
+                } else {
+                    AM_OFFSET
+                };
+                match year.checked_sub(offset) {
+                    Some(y) => y,
+                    None => return Err(ByzantineDateError::InvalidDate(DateError::Arithmetic)),
+                }
+            }
+            Era::AnnoDomini => year,
+        };
+        let date = match self.calendar.at_ymd(ad_year, month, day) {
+            Ok(date) => date,
+            Err(e) => return Err(ByzantineDateError::InvalidDate(e)),
+        };
+        if !self.era_at(date.julian_day_number()).eq(era) {
+            return Err(ByzantineDateError::EraMismatch { era, year });
+        }
+        Ok(ByzantineDate {
+            calendar: *self,
+            era,
+            year,
+            date,
+        })
+    }
+}
+
+/// A date in a [`ByzantineCalendar`].
+///
+/// `ByzantineDate` instances are ordered first by Julian day number, then by
+/// [`ByzantineCalendar`].
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct ByzantineDate {
+    calendar: ByzantineCalendar,
+    era: Era,
+    year: i32,
+    date: Date,
+}
+
+impl ByzantineDate {
+    /// Returns the [`ByzantineCalendar`] to which the date belongs
+    pub const fn calendar(&self) -> ByzantineCalendar {
+        self.calendar
+    }
+
+    /// Returns the era in which the date's year is numbered
+    pub const fn era(&self) -> Era {
+        self.era
+    }
+
+    /// Returns the date's year, numbered according to [`ByzantineDate::era()`]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the *Anno Mundi* year in which the date falls, regardless of
+    /// the era in which the calendar numbered years at the time
+    pub const fn anno_mundi_year(&self) -> i32 {
+        am_year(self.date.year(), self.date.month())
+    }
+
+    /// Returns the date's month
+    pub const fn month(&self) -> Month {
+        self.date.month()
+    }
+
+    /// Returns the date's day of month
+    pub const fn day(&self) -> u32 {
+        self.date.day()
+    }
+
+    /// Returns the date's [indiction][], the position of its year in a
+    /// fifteen-year cycle, as a number from 1 through 15.
+    ///
+    /// Indictions are reckoned in the Byzantine manner, beginning on
+    /// September 1, so this is the remainder of the *Anno Mundi* year divided
+    /// by 15, with 15 used in place of 0.  This is computed from
+    /// [`ByzantineDate::anno_mundi_year()`] even for dates in the *Anno
+    /// Domini* era.
+    ///
+    /// [indiction]: https://en.wikipedia.org/wiki/Indiction
+    pub const fn indiction(&self) -> u32 {
+        indiction(self.anno_mundi_year())
+    }
+
+    /// Returns the Julian day number of the date
+    pub const fn julian_day_number(&self) -> Jdnum {
+        self.date.julian_day_number()
+    }
+
+    /// Returns the same day as a [`Date`] in the underlying calendar, with the
+    /// year numbered *Anno Domini*
+    pub const fn to_date(&self) -> Date {
+        self.date
+    }
+}
+
+impl PartialOrd for ByzantineDate {
+    fn partial_cmp(&self, other: &ByzantineDate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByzantineDate {
+    fn cmp(&self, other: &ByzantineDate) -> Ordering {
+        self.julian_day_number()
+            .cmp(&other.julian_day_number())
+            .then_with(|| self.date.cmp(&other.date))
+            .then_with(|| self.calendar.cmp(&other.calendar))
+    }
+}
+
+impl fmt::Display for ByzantineDate {
+    /// A `ByzantineDate` is displayed in the format `YYYY-MM-DD ERA`, where
+    /// `ERA` is either "AM" or "AD".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {}",
+            self.year,
+            self.month().number(),
+            self.day(),
+            self.era
+        )
+    }
+}
+
+/// Returns the *Anno Mundi* year for a date in the given astronomical *Anno
+/// Domini* year and month.
+///
+/// Returns `None` on numeric overflow, which can only happen for years greater
+/// than 2147478139.
+///
+/// # Example
+///
+/// ```
+/// use julian::{Month, byzantine::anno_mundi_year};
+///
+/// assert_eq!(anno_mundi_year(1699, Month::August), Some(7207));
+/// assert_eq!(anno_mundi_year(1699, Month::September), Some(7208));
+/// assert_eq!(anno_mundi_year(1700, Month::January), Some(7208));
+/// ```
+pub const fn anno_mundi_year(year: i32, month: Month) -> Option<i32> {
+    if Month::September.le(month) {
+        year.checked_add(AM_OFFSET + 1)
+    } else {
+        year.checked_add(AM_OFFSET)
+    }
+}
+
+/// [Private] Returns the *Anno Mundi* year for a date in the given
+/// astronomical *Anno Domini* year and month, which must be the year & month
+/// of a valid `Date`
+const fn am_year(year: i32, month: Month) -> i32 {
+    match anno_mundi_year(year, month) {
+        Some(y) => y,
+        None => unreachable!(),
+    }
+}
+
+/// Returns the indiction (from 1 through 15) of the given *Anno Mundi* year
+///
+/// # Example
+///
+/// ```
+/// use julian::byzantine::indiction;
+///
+/// assert_eq!(indiction(7208), 8);
+/// assert_eq!(indiction(7215), 15);
+/// assert_eq!(indiction(7216), 1);
+/// ```
+#[allow(clippy::cast_sign_loss)]
+pub const fn indiction(anno_mundi_year: i32) -> u32 {
+    match anno_mundi_year.rem_euclid(INDICTION_CYCLE) {
+        0 => INDICTION_CYCLE as u32,
+        n => n as u32,
+    }
+}
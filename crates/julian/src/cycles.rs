@@ -0,0 +1,296 @@
+//! Traditional chronological cycles
+//!
+//! Medieval and early modern chronologists labelled each year with its
+//! position in three cycles: the 19-year lunar (Metonic) cycle, given by the
+//! year's [golden number][golden_number]; the 28-year [solar
+//! cycle][solar_cycle], after which the days of the week recur on the same
+//! dates of the Julian calendar; and the 15-year [indiction] cycle,
+//! inherited from Roman tax assessments.  Joseph Scaliger combined the three
+//! into the 7980-year [Julian Period][jp], whose first year, 4713 BC, was the
+//! first year of all three cycles and whose first day is JDN 0.  Since 19, 28,
+//! and 15 have no common factors, each year of a Julian Period is identified
+//! by its three cycle values; see [`julian_period_year_from_cycles()`].
+//!
+//! The [epact] of a year is the age of the Moon, in days, on a fixed day
+//! near the start of the year according to the tables used to compute the
+//! date of Easter.
+//! The [Julian epact][julian_epact] depends only on the golden number, while
+//! the [Gregorian epact][gregorian_epact] includes corrections for the
+//! Gregorian leap rule and for the inaccuracy of the lunar cycle.  An epact of
+//! 0 is traditionally written as an asterisk.
+//!
+//! All cycles here begin on January 1.  Years are numbered astronomically, so
+//! that 1 BC is year 0.  Byzantine indictions, which begin on September 1, can
+//! be obtained from [`ByzantineDate::indiction()`][crate::byzantine::ByzantineDate::indiction].
+//!
+//! [jp]: https://en.wikipedia.org/wiki/Julian_day#Julian_Period
+//! [epact]: https://en.wikipedia.org/wiki/Epact
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, cycles::{self, Cycles}};
+//!
+//! let date = Calendar::GREGORIAN.at_ymd(2024, Month::March, 31).unwrap();
+//! let c = Cycles::for_date(&date);
+//! assert_eq!(c.golden_number(), 11);
+//! assert_eq!(c.solar_cycle(), 17);
+//! assert_eq!(c.indiction(), 2);
+//! assert_eq!(c.epact(), 19);
+//! assert_eq!(cycles::julian_period_year(2024), Some(6737));
+//! assert_eq!(c.julian_period_year(), 6737);
+//! ```
+use crate::Date;
+use crate::errors::CycleError;
+
+/// The number of years in the lunar (Metonic) cycle
+pub const LUNAR_CYCLE: u32 = 19;
+
+/// The number of years in the solar cycle
+pub const SOLAR_CYCLE: u32 = 28;
+
+/// The number of years in the indiction cycle
+pub const INDICTION_CYCLE: u32 = 15;
+
+/// The number of years in the Julian Period
+pub const JULIAN_PERIOD: u32 = LUNAR_CYCLE * SOLAR_CYCLE * INDICTION_CYCLE;
+
+/// The number of years that the year of the Julian Period exceeds the
+/// astronomical year
+const JULIAN_PERIOD_OFFSET: i32 = 4713;
+
+/// The number of days that the Moon's age increases over a common year of 12
+/// lunar months
+const EPACT_STEP: i64 = 11;
+
+/// The number of days in a lunar month, for the purpose of computing epacts
+const EPACT_MONTH: i64 = 30;
+
+/// [Private] Returns the one-based position of the given astronomical year in
+/// a cycle of `length` years that begins with the year `-offset`
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn position(year: i32, offset: i64, length: u32) -> u32 {
+    // rem_euclid() always returns a nonnegative number less than `length`, so
+    // the cast is lossless:
+    ((year as i64 + offset).rem_euclid(length as i64) as u32) + 1
+}
+
+/// Returns the golden number (from 1 through 19) of the given year — its
+/// position in the 19-year lunar cycle
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::golden_number;
+///
+/// assert_eq!(golden_number(2024), 11);
+/// assert_eq!(golden_number(1), 2);
+/// ```
+pub const fn golden_number(year: i32) -> u32 {
+    position(year, 0, LUNAR_CYCLE)
+}
+
+/// Returns the position (from 1 through 28) of the given year in the 28-year
+/// solar cycle
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::solar_cycle;
+///
+/// assert_eq!(solar_cycle(2024), 17);
+/// assert_eq!(solar_cycle(1), 10);
+/// ```
+pub const fn solar_cycle(year: i32) -> u32 {
+    position(year, 8, SOLAR_CYCLE)
+}
+
+/// Returns the indiction (from 1 through 15) of the given year, reckoned from
+/// January 1
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::indiction;
+///
+/// assert_eq!(indiction(2024), 2);
+/// assert_eq!(indiction(1), 4);
+/// ```
+pub const fn indiction(year: i32) -> u32 {
+    position(year, 2, INDICTION_CYCLE)
+}
+
+/// Returns the year of the Julian Period corresponding to the given
+/// astronomical year.  Year 1 of the Julian Period is 4713 BC (year −4712).
+///
+/// Returns `None` on numeric overflow, which can only happen for years greater
+/// than 2147478934.
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::julian_period_year;
+///
+/// assert_eq!(julian_period_year(2024), Some(6737));
+/// assert_eq!(julian_period_year(-4712), Some(1));
+/// ```
+pub const fn julian_period_year(year: i32) -> Option<i32> {
+    year.checked_add(JULIAN_PERIOD_OFFSET)
+}
+
+/// Returns the year (from 1 through 7980) of the Julian Period that has the
+/// given solar cycle, golden number, and indiction.  Subtract 4713 from the
+/// result to get the astronomical year in the current Julian Period, which
+/// runs from 4713 BC through AD 3267.
+///
+/// # Errors
+///
+/// Returns [`CycleError`] if any of the values are zero or greater than the
+/// length of the respective cycle.
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::julian_period_year_from_cycles;
+///
+/// assert_eq!(julian_period_year_from_cycles(17, 11, 2), Ok(6737));
+/// assert_eq!(julian_period_year_from_cycles(28, 19, 15), Ok(7980));
+/// ```
+pub const fn julian_period_year_from_cycles(
+    solar_cycle: u32,
+    golden_number: u32,
+    indiction: u32,
+) -> Result<u32, CycleError> {
+    if solar_cycle == 0 || solar_cycle > SOLAR_CYCLE {
+        return Err(CycleError::SolarCycle(solar_cycle));
+    }
+    if golden_number == 0 || golden_number > LUNAR_CYCLE {
+        return Err(CycleError::GoldenNumber(golden_number));
+    }
+    if indiction == 0 || indiction > INDICTION_CYCLE {
+        return Err(CycleError::Indiction(indiction));
+    }
+    // Each coefficient is congruent to 1 modulo its own cycle and to 0 modulo
+    // the other two (Chinese remainder theorem):
+    let year = (4845 * solar_cycle + 4200 * golden_number + 6916 * indiction) % JULIAN_PERIOD;
+    if year == 0 {
+        Ok(JULIAN_PERIOD)
+    } else {
+        Ok(year)
+    }
+}
+
+/// Returns the Julian epact (from 0 through 29) of the given year, which is
+/// determined by the year's golden number alone
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::julian_epact;
+///
+/// assert_eq!(julian_epact(2024), 20);
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn julian_epact(year: i32) -> u32 {
+    let g = golden_number(year) as i64;
+    // rem_euclid() always returns a nonnegative number less than 30, so the
+    // cast is lossless:
+    (EPACT_STEP * (g - 1)).rem_euclid(EPACT_MONTH) as u32
+}
+
+/// Returns the Gregorian epact (from 0 through 29) of the given year,
+/// computed proleptically for years before 1583.
+///
+/// This is the Julian epact adjusted by the *solar equation*, which drops a
+/// day for each centennial year that is not a Gregorian leap year, and by the
+/// *lunar equation*, which adds eight days every 2500 years to correct the
+/// drift of the 19-year lunar cycle.
+///
+/// # Example
+///
+/// ```
+/// use julian::cycles::gregorian_epact;
+///
+/// assert_eq!(gregorian_epact(2024), 19);
+/// assert_eq!(gregorian_epact(2025), 0);
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn gregorian_epact(year: i32) -> u32 {
+    let century = (year as i64).div_euclid(100) + 1;
+    let solar_equation = (3 * century).div_euclid(4);
+    let lunar_equation = (8 * century + 5).div_euclid(25);
+    let epact = julian_epact(year) as i64 - solar_equation + lunar_equation + 8;
+    // rem_euclid() always returns a nonnegative number less than 30, so the
+    // cast is lossless:
+    epact.rem_euclid(EPACT_MONTH) as u32
+}
+
+/// The positions of a year in the traditional chronological cycles, along
+/// with its epact
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Cycles {
+    golden_number: u32,
+    solar_cycle: u32,
+    indiction: u32,
+    epact: u32,
+}
+
+impl Cycles {
+    /// Returns the cycles for the given year, using the Julian epact
+    pub const fn for_julian_year(year: i32) -> Cycles {
+        Cycles {
+            golden_number: golden_number(year),
+            solar_cycle: solar_cycle(year),
+            indiction: indiction(year),
+            epact: julian_epact(year),
+        }
+    }
+
+    /// Returns the cycles for the given year, using the Gregorian epact
+    pub const fn for_gregorian_year(year: i32) -> Cycles {
+        Cycles {
+            epact: gregorian_epact(year),
+            ..Cycles::for_julian_year(year)
+        }
+    }
+
+    /// Returns the cycles for the year of the given date.  The Gregorian epact
+    /// is used if the date is in the Gregorian calendar, and the Julian epact
+    /// is used otherwise.
+    pub const fn for_date(date: &Date) -> Cycles {
+        if date.is_gregorian() {
+            Cycles::for_gregorian_year(date.year())
+        } else {
+            Cycles::for_julian_year(date.year())
+        }
+    }
+
+    /// Returns the golden number (from 1 through 19)
+    pub const fn golden_number(&self) -> u32 {
+        self.golden_number
+    }
+
+    /// Returns the position (from 1 through 28) in the solar cycle
+    pub const fn solar_cycle(&self) -> u32 {
+        self.solar_cycle
+    }
+
+    /// Returns the indiction (from 1 through 15)
+    pub const fn indiction(&self) -> u32 {
+        self.indiction
+    }
+
+    /// Returns the epact (from 0 through 29)
+    pub const fn epact(&self) -> u32 {
+        self.epact
+    }
+
+    /// Returns the year (from 1 through 7980) of the Julian Period with the
+    /// same solar cycle, golden number, and indiction
+    pub const fn julian_period_year(&self) -> u32 {
+        match julian_period_year_from_cycles(self.solar_cycle, self.golden_number, self.indiction) {
+            Ok(year) => year,
+            Err(_) => unreachable!(),
+        }
+    }
+}
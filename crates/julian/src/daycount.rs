@@ -0,0 +1,252 @@
+//! Day numbers counted from epochs other than that of the Julian day number
+//!
+//! Many fields count days from a more recent epoch than the Julian day number,
+//! typically by subtracting a fixed offset from it.  This module provides a
+//! [`DayCount`] enum of such counts and a [`DayNumber`] type pairing a day
+//! count with a value, which can be converted to & from Julian day numbers and
+//! [`Date`]s.
+//!
+//! Day counts that are defined in terms of a fractional Julian date are
+//! treated here as counting whole calendar days.  For counts whose days start
+//! at noon (like the Julian date itself), the day number of a date is the
+//! count's value at noon on that date; for counts whose days start at
+//! midnight (such as the Modified Julian Date), it is the count's value at the
+//! start of that date.
+//!
+//! A `DayNumber` is displayed and parsed in the form `{prefix}:{value}`, where
+//! `{prefix}` is the day count's [prefix][DayCount::prefix].
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, daycount::{DayCount, DayNumber}};
+//!
+//! let date = Calendar::GREGORIAN.at_ymd(2023, Month::February, 25).unwrap();
+//! let mjd = DayCount::Modified.for_date(&date).unwrap();
+//! assert_eq!(mjd.value(), 60000);
+//! assert_eq!(mjd.to_string(), "mjd:60000");
+//!
+//! let rd = "rd:738576".parse::<DayNumber>().unwrap();
+//! assert_eq!(rd.julian_day_number(), date.julian_day_number());
+//! assert_eq!(rd.to_date(Calendar::GREGORIAN), date);
+//! ```
+use crate::errors::{ParseDayCountError, ParseDayNumberError};
+use crate::{Calendar, Date, Jdnum, RATA_DIE_ZERO_JDN, REFORM1582_JDN, UNIX_EPOCH_JDN};
+use core::fmt;
+use core::str::FromStr;
+
+/// An enumeration of day counts, each of which numbers days consecutively
+/// from some epoch
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DayCount {
+    /// The Chronological Julian Day Number, which numbers calendar days from
+    /// midnight rather than noon and so coincides with the Julian day number
+    /// of a date
+    Chronological,
+
+    /// The Modified Julian Date, which counts days from 1858-11-17 (Gregorian)
+    Modified,
+
+    /// The Reduced Julian Date, which counts days from 1858-11-16 (Gregorian)
+    Reduced,
+
+    /// The Truncated Julian Date as defined by NASA, which counts days from
+    /// 1968-05-24 (Gregorian)
+    Truncated,
+
+    /// The Lilian day number, which counts days from 1582-10-15 (Gregorian),
+    /// the first day of the Gregorian calendar, as day 1
+    Lilian,
+
+    /// The [Rata Die][] day number, which counts days from 0001-01-01 in the
+    /// proleptic Gregorian calendar as day 1
+    ///
+    /// [Rata Die]: https://en.wikipedia.org/wiki/Rata_Die
+    RataDie,
+
+    /// The Dublin Julian Date, which counts days from 1899-12-31 (Gregorian)
+    Dublin,
+
+    /// The CNES Julian Date, which counts days from 1950-01-01 (Gregorian)
+    Cnes,
+
+    /// The number of days since the Unix epoch (1970-01-01)
+    Unix,
+}
+
+impl DayCount {
+    /// Returns the Julian day number of the day numbered zero in the day count
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{RATA_DIE_ZERO_JDN, daycount::DayCount};
+    ///
+    /// assert_eq!(DayCount::Modified.epoch_jdn(), 2400001);
+    /// assert_eq!(DayCount::RataDie.epoch_jdn(), RATA_DIE_ZERO_JDN);
+    /// ```
+    pub const fn epoch_jdn(&self) -> Jdnum {
+        match self {
+            DayCount::Chronological => 0,
+            DayCount::Modified => 2400001,
+            DayCount::Reduced => 2400000,
+            DayCount::Truncated => 2440001,
+            DayCount::Lilian => REFORM1582_JDN - 1,
+            DayCount::RataDie => RATA_DIE_ZERO_JDN,
+            DayCount::Dublin => 2415020,
+            DayCount::Cnes => 2433283,
+            DayCount::Unix => UNIX_EPOCH_JDN,
+        }
+    }
+
+    /// Returns the lowercase prefix used to identify the day count when
+    /// displaying & parsing [`DayNumber`]s
+    pub const fn prefix(&self) -> &'static str {
+        match self {
+            DayCount::Chronological => "cjd",
+            DayCount::Modified => "mjd",
+            DayCount::Reduced => "rjd",
+            DayCount::Truncated => "tjd",
+            DayCount::Lilian => "lilian",
+            DayCount::RataDie => "rd",
+            DayCount::Dublin => "djd",
+            DayCount::Cnes => "cnes",
+            DayCount::Unix => "unix",
+        }
+    }
+
+    /// Returns an iterator over all day counts
+    pub fn iter() -> impl Iterator<Item = DayCount> {
+        [
+            DayCount::Chronological,
+            DayCount::Modified,
+            DayCount::Reduced,
+            DayCount::Truncated,
+            DayCount::Lilian,
+            DayCount::RataDie,
+            DayCount::Dublin,
+            DayCount::Cnes,
+            DayCount::Unix,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the day number in this day count of the day with the given
+    /// Julian day number.  Returns `None` on arithmetic overflow/underflow.
+    pub const fn for_jdn(self, jdn: Jdnum) -> Option<DayNumber> {
+        match jdn.checked_sub(self.epoch_jdn()) {
+            Some(value) => Some(DayNumber { count: self, value }),
+            None => None,
+        }
+    }
+
+    /// Returns the day number in this day count of the given date.  Returns
+    /// `None` on arithmetic overflow/underflow.
+    pub const fn for_date(self, date: &Date) -> Option<DayNumber> {
+        self.for_jdn(date.julian_day_number())
+    }
+}
+
+impl fmt::Display for DayCount {
+    /// A `DayCount` is displayed as its prefix
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.prefix())
+    }
+}
+
+impl FromStr for DayCount {
+    type Err = ParseDayCountError;
+
+    /// Parses a day count from its prefix, case insensitive
+    fn from_str(s: &str) -> Result<DayCount, ParseDayCountError> {
+        DayCount::iter()
+            .find(|dc| dc.prefix().eq_ignore_ascii_case(s))
+            .ok_or(ParseDayCountError)
+    }
+}
+
+/// A day number in a given [`DayCount`].  A `DayNumber` always corresponds to
+/// a valid Julian day number.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct DayNumber {
+    count: DayCount,
+    value: Jdnum,
+}
+
+impl DayNumber {
+    /// Construct a day number from a day count and a value.  Returns `None` if
+    /// the corresponding Julian day number would overflow/underflow.
+    pub const fn new(count: DayCount, value: Jdnum) -> Option<DayNumber> {
+        if value.checked_add(count.epoch_jdn()).is_some() {
+            Some(DayNumber { count, value })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the day count
+    pub const fn count(&self) -> DayCount {
+        self.count
+    }
+
+    /// Returns the day number's value within its day count
+    pub const fn value(&self) -> Jdnum {
+        self.value
+    }
+
+    /// Returns the Julian day number of the day
+    pub const fn julian_day_number(&self) -> Jdnum {
+        // This cannot overflow, as it was checked on construction.
+        self.value + self.count.epoch_jdn()
+    }
+
+    /// Returns the day in the given calendar
+    pub const fn to_date(&self, calendar: Calendar) -> Date {
+        calendar.at_jdn(self.julian_day_number())
+    }
+
+    /// Returns the number of the same day in a different day count.  Returns
+    /// `None` on arithmetic overflow/underflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::daycount::{DayCount, DayNumber};
+    ///
+    /// let mjd = DayNumber::new(DayCount::Modified, 60000).unwrap();
+    /// let unix = mjd.convert(DayCount::Unix).unwrap();
+    /// assert_eq!(unix.value(), 19413);
+    /// ```
+    pub const fn convert(&self, count: DayCount) -> Option<DayNumber> {
+        count.for_jdn(self.julian_day_number())
+    }
+}
+
+impl fmt::Display for DayNumber {
+    /// A `DayNumber` is displayed in the form `{prefix}:{value}`, e.g.,
+    /// `mjd:60000`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.count.prefix(), self.value)
+    }
+}
+
+impl FromStr for DayNumber {
+    type Err = ParseDayNumberError;
+
+    /// Parses a day number in the form `{prefix}:{value}`, where `{prefix}` is
+    /// the prefix of a [`DayCount`] (case insensitive) and `{value}` is a
+    /// signed decimal integer
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseDayNumberError`] if the string is not of the above form
+    /// or if the resulting Julian day number would be out of range.
+    fn from_str(s: &str) -> Result<DayNumber, ParseDayNumberError> {
+        let (prefix, value) = s
+            .split_once(':')
+            .ok_or(ParseDayNumberError::MissingSeparator)?;
+        let count = prefix.parse::<DayCount>()?;
+        let value = value.parse::<Jdnum>()?;
+        DayNumber::new(count, value).ok_or(ParseDayNumberError::OutOfRange)
+    }
+}
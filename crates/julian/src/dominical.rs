@@ -0,0 +1,399 @@
+//! Dominical letters
+//!
+//! The [dominical letter][dom] system assigns the letters A through G to the
+//! days of the year in rotation, starting with A on January 1 and skipping the
+//! leap day, so that each calendar date always bears the same letter.  The
+//! letter that falls on the Sundays of a year is that year's *dominical
+//! letter*, which medieval and early modern almanacs used to identify the
+//! year's calendar.
+//!
+//! Because the leap day is not lettered, Sundays after it fall on the
+//! preceding letter, so a leap year has two letters: one for January and
+//! February and one for the rest of the year.  A year containing a
+//! calendar reformation generally changes letters again at the reformation's
+//! gap; for example, in 1582, the letter G was in use until October 4, and
+//! the letter C was in use from October 15 onwards.
+//!
+//! [dom]: https://en.wikipedia.org/wiki/Dominical_letter
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, dominical::{DominicalLetter, DominicalLetters, dominical_letters}};
+//!
+//! assert_eq!(
+//!     dominical_letters(Calendar::GREGORIAN, 2023),
+//!     Some(DominicalLetters::Single(DominicalLetter::A))
+//! );
+//! assert_eq!(
+//!     dominical_letters(Calendar::GREGORIAN, 2024),
+//!     Some(DominicalLetters::Double(DominicalLetter::G, DominicalLetter::F))
+//! );
+//!
+//! let letters = dominical_letters(Calendar::REFORM1582, 1582).unwrap();
+//! assert_eq!(letters.to_string(), "G/C");
+//! ```
+use crate::{Calendar, Date, Month, MonthKind, YearKind, iter::MonthIter};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+/// The number of days in a common year preceding the first of each month
+const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// An enumeration of the seven dominical letters
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DominicalLetter {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+impl DominicalLetter {
+    /// Returns the letter assigned to the given date.  February 29 is
+    /// assigned the same letter as February 28.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, dominical::DominicalLetter};
+    ///
+    /// let date = Calendar::GREGORIAN.at_ymd(2023, Month::March, 1).unwrap();
+    /// assert_eq!(DominicalLetter::for_date(&date), DominicalLetter::D);
+    /// ```
+    pub const fn for_date(date: &Date) -> DominicalLetter {
+        let day = if date.day() > 28 && matches!(date.month(), Month::February) {
+            28
+        } else {
+            date.day()
+        };
+        DominicalLetter::from_index0(DAYS_BEFORE_MONTH[date.month().number0() as usize] + day - 1)
+    }
+
+    /// [Private] Returns the dominical letter of the part of a year
+    /// containing the given date, i.e., the letter assigned to the last
+    /// Sunday on or before the date, as reckoned without any intervening leap
+    /// day or reformation gap.  `date` must not be February 29.
+    const fn for_sundays_around(date: &Date) -> DominicalLetter {
+        let days_since_sunday = date.weekday().number() % 7;
+        // Adding 7 keeps the value nonnegative for all letters and weekdays:
+        DominicalLetter::from_index0(
+            DominicalLetter::for_date(date).index0() + 7 - days_since_sunday,
+        )
+    }
+
+    /// Returns the letter itself as a `char`
+    pub const fn to_char(&self) -> char {
+        match self {
+            DominicalLetter::A => 'A',
+            DominicalLetter::B => 'B',
+            DominicalLetter::C => 'C',
+            DominicalLetter::D => 'D',
+            DominicalLetter::E => 'E',
+            DominicalLetter::F => 'F',
+            DominicalLetter::G => 'G',
+        }
+    }
+
+    /// Returns the letter before this one, wrapping around from A to G.  In a
+    /// leap year, this is the letter for the Sundays after February 29.
+    pub const fn pred(&self) -> DominicalLetter {
+        DominicalLetter::from_index0(self.index0() + 6)
+    }
+
+    /// Returns the letter after this one, wrapping around from G to A
+    pub const fn succ(&self) -> DominicalLetter {
+        DominicalLetter::from_index0(self.index0() + 1)
+    }
+
+    /// [Private] Returns the zero-based index of the letter, where A is 0
+    const fn index0(self) -> u32 {
+        self as u32
+    }
+
+    /// [Private] Returns the letter with the given zero-based index modulo 7
+    const fn from_index0(index: u32) -> DominicalLetter {
+        match index % 7 {
+            0 => DominicalLetter::A,
+            1 => DominicalLetter::B,
+            2 => DominicalLetter::C,
+            3 => DominicalLetter::D,
+            4 => DominicalLetter::E,
+            5 => DominicalLetter::F,
+            _ => DominicalLetter::G,
+        }
+    }
+}
+
+impl fmt::Display for DominicalLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_char(), f)
+    }
+}
+
+/// The dominical letter or letters of a year, as returned by
+/// [`dominical_letters()`]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum DominicalLetters {
+    /// A year with a single dominical letter, i.e., a common year in which
+    /// the letter was not changed by a calendar reformation
+    Single(DominicalLetter),
+
+    /// A leap year in which the letter was not changed by a calendar
+    /// reformation.  The first letter applies through February 29, and the
+    /// second letter applies from March 1 onwards.
+    Double(DominicalLetter, DominicalLetter),
+
+    /// A year in which the letter was changed by the gap of a calendar
+    /// reformation
+    Transition(Transition),
+}
+
+impl DominicalLetters {
+    /// Returns the letter in effect at the start of the year
+    pub const fn first(&self) -> DominicalLetter {
+        match self {
+            DominicalLetters::Single(letter) | DominicalLetters::Double(letter, _) => *letter,
+            DominicalLetters::Transition(t) => t.letters[0],
+        }
+    }
+
+    /// Returns the letter in effect at the end of the year
+    pub const fn last(&self) -> DominicalLetter {
+        match self {
+            DominicalLetters::Single(letter) | DominicalLetters::Double(_, letter) => *letter,
+            DominicalLetters::Transition(t) => t.letters[t.len - 1],
+        }
+    }
+
+    /// Returns true if the given letter is in effect at any point during the
+    /// year
+    pub fn contains(&self, letter: DominicalLetter) -> bool {
+        match self {
+            DominicalLetters::Single(l) => *l == letter,
+            DominicalLetters::Double(first, second) => *first == letter || *second == letter,
+            DominicalLetters::Transition(t) => t.letters().contains(&letter),
+        }
+    }
+}
+
+impl fmt::Display for DominicalLetters {
+    /// Single and double letters are displayed as the letters themselves
+    /// (e.g., "A" or "GF").  A transition is displayed with a slash marking the
+    /// reformation gap (e.g., "G/C" or "ED/A").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DominicalLetters::Single(letter) => write!(f, "{letter}"),
+            DominicalLetters::Double(first, second) => write!(f, "{first}{second}"),
+            DominicalLetters::Transition(t) => {
+                for letter in t.before() {
+                    write!(f, "{letter}")?;
+                }
+                write!(f, "/")?;
+                for letter in t.after() {
+                    write!(f, "{letter}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The dominical letters of a year in which the letter was changed by the gap
+/// of a calendar reformation.
+///
+/// There may be one or two letters on either side of the gap, depending on
+/// whether February 29 occurs in the part of the year before or after the
+/// gap.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Transition {
+    letters: [DominicalLetter; 3],
+    len: usize,
+    gap: usize,
+}
+
+impl Transition {
+    /// Returns all of the letters in effect during the year, in order
+    pub fn letters(&self) -> &[DominicalLetter] {
+        &self.letters[..self.len]
+    }
+
+    /// Returns the letters in effect before the reformation gap
+    pub fn before(&self) -> &[DominicalLetter] {
+        &self.letters[..self.gap]
+    }
+
+    /// Returns the letters in effect after the reformation gap
+    pub fn after(&self) -> &[DominicalLetter] {
+        &self.letters[self.gap..self.len]
+    }
+
+    /// Returns the letter in effect immediately before the reformation gap
+    pub const fn before_gap(&self) -> DominicalLetter {
+        self.letters[self.gap - 1]
+    }
+
+    /// Returns the letter in effect immediately after the reformation gap
+    pub const fn after_gap(&self) -> DominicalLetter {
+        self.letters[self.gap]
+    }
+}
+
+/// Returns the dominical letter or letters of the given year in the given
+/// calendar.
+///
+/// Returns `None` if the year was skipped entirely by a calendar reformation
+/// or if the first day of the year is outside the range of [`Date`].
+///
+/// # Example
+///
+/// ```
+/// use julian::{Calendar, dominical::{DominicalLetter, DominicalLetters, dominical_letters}, ncal};
+///
+/// // In Great Britain, 1752 was a leap year in which September 3 through 13
+/// // were skipped:
+/// let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+/// let Some(DominicalLetters::Transition(t)) = dominical_letters(cal, 1752) else {
+///     panic!("1752 should be a transition year");
+/// };
+/// assert_eq!(t.before(), [DominicalLetter::E, DominicalLetter::D]);
+/// assert_eq!(t.after(), [DominicalLetter::A]);
+/// ```
+pub fn dominical_letters(calendar: Calendar, year: i32) -> Option<DominicalLetters> {
+    match calendar.year_kind(year) {
+        YearKind::Common => {
+            let jan1 = calendar.at_ymd(year, Month::January, 1).ok()?;
+            Some(DominicalLetters::Single(
+                DominicalLetter::for_sundays_around(&jan1),
+            ))
+        }
+        YearKind::Leap => {
+            let jan1 = calendar.at_ymd(year, Month::January, 1).ok()?;
+            let letter = DominicalLetter::for_sundays_around(&jan1);
+            Some(DominicalLetters::Double(letter, letter.pred()))
+        }
+        YearKind::ReformCommon | YearKind::ReformLeap => Some(reform_letters(calendar, year)),
+        YearKind::Skipped => None,
+    }
+}
+
+/// [Private] Returns the dominical letters of a year affected by a calendar
+/// reformation, which must not have been skipped entirely
+fn reform_letters(calendar: Calendar, year: i32) -> DominicalLetters {
+    let mut letters = [DominicalLetter::A; 3];
+    let mut len = 0;
+    let mut gap = None;
+    // Whether the gap lies between the previous date and the current date:
+    let mut crossed_gap = false;
+    for month in MonthIter::new() {
+        let Some(shape) = calendar.month_shape(year, month) else {
+            crossed_gap = true;
+            continue;
+        };
+        let gap_end = shape.gap().map(|g| *g.end());
+        for date in shape.dates() {
+            if matches!(date.month(), Month::February) && date.day() == 29 {
+                continue;
+            }
+            if gap_end.is_some_and(|end| end + 1 == date.day()) {
+                crossed_gap = true;
+            }
+            let letter = DominicalLetter::for_sundays_around(&date);
+            if len == 0 {
+                letters[0] = letter;
+                len = 1;
+            } else if letters[len - 1] != letter {
+                if crossed_gap {
+                    gap = Some(len);
+                }
+                letters[len] = letter;
+                len += 1;
+            }
+            crossed_gap = false;
+        }
+        if matches!(shape.kind(), MonthKind::Tailless) {
+            crossed_gap = true;
+        }
+    }
+    match (gap, len) {
+        (Some(gap), _) => DominicalLetters::Transition(Transition { letters, len, gap }),
+        (None, 1) => DominicalLetters::Single(letters[0]),
+        (None, _) => DominicalLetters::Double(letters[0], letters[1]),
+    }
+}
+
+/// Returns an iterator over the years in the given range of the given
+/// calendar in which the given dominical letter is in effect at any point
+///
+/// # Example
+///
+/// ```
+/// use julian::{Calendar, dominical::{DominicalLetter, years_with_letter}};
+///
+/// let years = years_with_letter(Calendar::GREGORIAN, DominicalLetter::A, 2000..=2030)
+///     .collect::<Vec<_>>();
+/// assert_eq!(years, [2000, 2006, 2012, 2017, 2023, 2028]);
+/// ```
+pub const fn years_with_letter(
+    calendar: Calendar,
+    letter: DominicalLetter,
+    years: RangeInclusive<i32>,
+) -> YearsWithLetter {
+    YearsWithLetter {
+        calendar,
+        letter,
+        years,
+    }
+}
+
+/// An iterator over the years in which a given dominical letter is in effect.
+///
+/// A `YearsWithLetter` instance can be acquired by calling
+/// [`years_with_letter()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YearsWithLetter {
+    calendar: Calendar,
+    letter: DominicalLetter,
+    years: RangeInclusive<i32>,
+}
+
+impl YearsWithLetter {
+    /// [Private] Returns true if the letter is in effect in the given year
+    fn matches(&self, year: i32) -> bool {
+        dominical_letters(self.calendar, year).is_some_and(|dl| dl.contains(self.letter))
+    }
+}
+
+impl Iterator for YearsWithLetter {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        while let Some(year) = self.years.next() {
+            if self.matches(year) {
+                return Some(year);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.years.size_hint().1)
+    }
+}
+
+impl FusedIterator for YearsWithLetter {}
+
+impl DoubleEndedIterator for YearsWithLetter {
+    fn next_back(&mut self) -> Option<i32> {
+        while let Some(year) = self.years.next_back() {
+            if self.matches(year) {
+                return Some(year);
+            }
+        }
+        None
+    }
+}
@@ -1,6 +1,11 @@
 //! Error types
-use crate::Month;
+use crate::byzantine::Era;
+use crate::roman::Reference;
+use crate::saka::SakaMonth;
+use crate::{Month, Style, Weekday};
 use core::num::ParseIntError;
+#[cfg(feature = "tz")]
+use std::string::String;
 use thiserror::Error;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
@@ -32,6 +37,11 @@ pub struct ParseWeekdayError;
 #[error("value out of range for weekday number; must be from 1 through 7")]
 pub struct TryIntoWeekdayError;
 
+/// Error returned when parsing a locale fails
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid or unsupported locale")]
+pub struct ParseLocaleError;
+
 /// Error returned by [`Calendar::reforming()`][crate::Calendar::reforming]
 /// when given an invalid reformation date
 #[derive(Copy, Clone, Debug, Eq, Error, Hash, PartialEq)]
@@ -47,6 +57,42 @@ pub enum ReformingError {
     Arithmetic,
 }
 
+/// Error returned by [`LeapRule::from_clauses()`][crate::leap::LeapRule::from_clauses]
+/// and [`LeapRule::from_cycle()`][crate::leap::LeapRule::from_cycle] on
+/// invalid input
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum LeapRuleError {
+    /// Returned if a clause's divisor is zero
+    #[error("leap rule divisors must be nonzero")]
+    ZeroDivisor,
+
+    /// Returned if a clause's divisor is not a multiple of the divisor of the
+    /// clause before it
+    #[error("leap rule divisor {divisor} is not a multiple of preceding divisor {previous}")]
+    UnnestedDivisor {
+        /// The divisor in question
+        divisor: u32,
+
+        /// The divisor of the preceding clause
+        previous: u32,
+    },
+
+    /// Returned if a cycle's period is zero
+    #[error("leap cycle period must be nonzero")]
+    ZeroPeriod,
+
+    /// Returned if a cycle's leap year is not less than the period or is not
+    /// greater than the leap year before it
+    #[error("leap cycle year {year} is out of order or not less than cycle period {period}")]
+    InvalidCycleYear {
+        /// The leap year in question
+        year: u32,
+
+        /// The cycle's period
+        period: u32,
+    },
+}
+
 /// Error returned by various date-construction methods on invalid input
 #[derive(Copy, Clone, Debug, Eq, Error, Hash, PartialEq)]
 pub enum DateError {
@@ -91,7 +137,22 @@ pub enum DateError {
     /// Returned by [`Calendar::at_ymd()`][crate::Calendar::at_ymd] if the
     /// given date was skipped by a calendar reformation
     #[error("date {year:04}-{:02}-{day:02} was skipped by calendar reform", month.number())]
-    SkippedDate { year: i32, month: Month, day: u32 },
+    SkippedDate {
+        /// The year value supplied
+        year: i32,
+        /// The month value supplied
+        month: Month,
+        /// The day of month value supplied
+        day: u32,
+        /// The year, month, and day of the last date before the skipped
+        /// range, i.e., the calendar's
+        /// [`last_julian_date()`][crate::Calendar::last_julian_date]
+        before: (i32, Month, u32),
+        /// The year, month, and day of the first date after the skipped
+        /// range, i.e., the calendar's
+        /// [`first_gregorian_date()`][crate::Calendar::first_gregorian_date]
+        after: (i32, Month, u32),
+    },
 }
 
 /// Error returned when an internal arithmetic operation encounters numeric
@@ -100,6 +161,12 @@ pub enum DateError {
 #[error("arithmetic overflow/underflow")]
 pub struct ArithmeticError;
 
+/// Error returned by [`UtcOffset::new()`][crate::UtcOffset::new] when given an
+/// offset of a day or more in magnitude
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("UTC offset out of range; must be from -86399 through 86399 seconds")]
+pub struct UtcOffsetError;
+
 impl From<ArithmeticError> for ReformingError {
     fn from(_: ArithmeticError) -> ReformingError {
         ReformingError::Arithmetic
@@ -112,8 +179,354 @@ impl From<ArithmeticError> for DateError {
     }
 }
 
+/// Error returned by
+/// [`ByzantineCalendar::at_ymd()`][crate::byzantine::ByzantineCalendar::at_ymd]
+/// on invalid input
+#[derive(Copy, Clone, Debug, Eq, Error, Hash, PartialEq)]
+pub enum ByzantineDateError {
+    /// Returned if the date is not valid in the underlying calendar
+    #[error("invalid calendar date: {0}")]
+    InvalidDate(#[from] DateError),
+
+    /// Returned if years were not numbered in the given era on the given date
+    #[error("{era} year {year} was not in use on the given date")]
+    EraMismatch {
+        /// The era supplied
+        era: Era,
+        /// The year value supplied
+        year: i32,
+    },
+}
+
+/// Error returned by [`SakaDate`][crate::saka::SakaDate] constructors on
+/// invalid input
+#[derive(Copy, Clone, Debug, Eq, Error, Hash, PartialEq)]
+pub enum SakaDateError {
+    /// Returned if an internal arithmetic operation encounters numeric
+    /// overflow or underflow
+    #[error("arithmetic overflow/underflow")]
+    Arithmetic,
+
+    /// Returned by [`SakaDate::at_ymd()`][crate::saka::SakaDate::at_ymd] if
+    /// the given day of month value was zero or greater than the length of the
+    /// given month in the given year
+    #[error("day {day} is outside of valid range 1-{max_day} for Saka {year:04} {month}")]
+    DayOutOfRange {
+        /// The year value supplied
+        year: i32,
+        /// The month value supplied
+        month: SakaMonth,
+        /// The invalid day of month supplied
+        day: u32,
+        /// The last valid day of the month
+        max_day: u32,
+    },
+
+    /// Returned by
+    /// [`SakaDate::at_ordinal_date()`][crate::saka::SakaDate::at_ordinal_date]
+    /// if the given day of year value was zero or greater than the length of
+    /// the given year
+    #[error(
+        "day-of-year ordinal {ordinal} is outside of valid range 1-{max_ordinal} for Saka year {year:04}"
+    )]
+    OrdinalOutOfRange {
+        /// The year value supplied
+        year: i32,
+        /// The invalid day of year value supplied
+        ordinal: u32,
+        /// The maximum valid day of year value
+        max_ordinal: u32,
+    },
+}
+
+impl From<ArithmeticError> for SakaDateError {
+    fn from(_: ArithmeticError) -> SakaDateError {
+        SakaDateError::Arithmetic
+    }
+}
+
+/// Error returned when parsing a [`SakaDate`][crate::saka::SakaDate] fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseSakaDateError {
+    /// Returned if the date string was not in a valid format
+    #[error(transparent)]
+    InvalidFormat(#[from] ParseDateError),
+
+    /// Returned if the date specified by the date string does not occur in the
+    /// Indian national calendar
+    #[error("invalid Saka calendar date: {0}")]
+    InvalidDate(#[from] SakaDateError),
+}
+
+/// Error returned when parsing a [`SakaMonth`] fails
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid Saka month name")]
+pub struct ParseSakaMonthError;
+
+/// Error returned by [`RomanDate::new()`][crate::roman::RomanDate::new] and
+/// [`RomanDate::to_date()`][crate::roman::RomanDate::to_date] on invalid input
+#[derive(Copy, Clone, Debug, Eq, Error, Hash, PartialEq)]
+pub enum RomanDateError {
+    /// Returned by [`RomanDate::new()`][crate::roman::RomanDate::new] if the
+    /// count was zero or would pass the preceding reference day
+    #[error("count {count} is outside of valid range 1-{max} for the {reference} of {month}")]
+    CountOutOfRange {
+        /// The reference day supplied
+        reference: Reference,
+        /// The month supplied
+        month: Month,
+        /// The invalid count supplied
+        count: u32,
+        /// The largest valid count
+        max: u32,
+    },
+
+    /// Returned by [`RomanDate::to_date()`][crate::roman::RomanDate::to_date]
+    /// if the bissextile day was converted in a year that is not a leap year
+    #[error("the bissextile day does not occur in year {year}")]
+    NotLeapYear {
+        /// The year value supplied
+        year: i32,
+    },
+
+    /// Returned by [`RomanDate::to_date()`][crate::roman::RomanDate::to_date]
+    /// if the resulting date is not valid in the calendar
+    #[error("invalid calendar date: {0}")]
+    InvalidDate(#[from] DateError),
+}
+
+/// Error returned when parsing a [`RomanDate`][crate::roman::RomanDate] fails
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid Roman date")]
+pub struct ParseRomanDateError;
+
+/// Error returned when parsing a [`Stem`][crate::sexagenary::Stem],
+/// [`Branch`][crate::sexagenary::Branch], or
+/// [`Sexagenary`][crate::sexagenary::Sexagenary] fails
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid sexagenary stem or branch name")]
+pub struct ParseSexagenaryError;
+
+/// Error returned by
+/// [`julian_period_year_from_cycles()`][crate::cycles::julian_period_year_from_cycles]
+/// when given a value outside the range of its cycle
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum CycleError {
+    /// Returned if the solar cycle value was not from 1 through 28
+    #[error("solar cycle {0} is outside of valid range 1-28")]
+    SolarCycle(u32),
+
+    /// Returned if the golden number was not from 1 through 19
+    #[error("golden number {0} is outside of valid range 1-19")]
+    GoldenNumber(u32),
+
+    /// Returned if the indiction was not from 1 through 15
+    #[error("indiction {0} is outside of valid range 1-15")]
+    Indiction(u32),
+}
+
+/// Error returned when parsing a [`DayCount`][crate::daycount::DayCount] fails
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid day count prefix")]
+pub struct ParseDayCountError;
+
+/// Error returned when parsing a [`DayNumber`][crate::daycount::DayNumber]
+/// fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseDayNumberError {
+    /// Returned if the string did not contain a colon separating the day
+    /// count prefix from the value
+    #[error("expected ':' between day count prefix and value")]
+    MissingSeparator,
+
+    /// Returned if the day count prefix was not recognized
+    #[error(transparent)]
+    DayCount(#[from] ParseDayCountError),
+
+    /// Returned if the value could not be parsed as an integer
+    #[error("numeric parse error: {0}")]
+    ParseInt(#[from] ParseIntError),
+
+    /// Returned if the Julian day number of the parsed day number would
+    /// overflow/underflow
+    #[error("day number out of range")]
+    OutOfRange,
+}
+
+/// Error returned when converting a spreadsheet serial date fails
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum SerialDateError {
+    /// Returned if the serial date is the nonexistent 1900-02-29 of the 1900
+    /// date system (serial 60)
+    #[error("serial 60 is the nonexistent date 1900-02-29 in the 1900 date system")]
+    LotusLeapDay,
+
+    /// Returned if the serial date is not finite or if numeric
+    /// overflow/underflow occurs during conversion
+    #[error("serial date out of range")]
+    OutOfRange,
+}
+
+/// Error returned by
+/// [`LeapSecondTable::new()`][crate::astro::timescale::LeapSecondTable::new]
+/// when given an invalid list of entries
+#[cfg(feature = "astro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "astro")))]
+#[derive(Clone, Copy, Debug, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LeapSecondTableError {
+    /// Returned if the list of entries was empty
+    #[error("leap second table is empty")]
+    Empty,
+
+    /// Returned if the entries were not in strictly ascending order of Julian
+    /// day number
+    #[error("leap second table entries are not in ascending order")]
+    Unsorted,
+}
+
+/// Error returned when loading a [`TimeZone`][crate::tz::TimeZone] fails
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+#[derive(Debug, Error)]
+pub enum TzError {
+    /// Returned by [`TimeZone::named()`][crate::tz::TimeZone::named] if the
+    /// time zone name is empty, absolute, or contains a `..` component
+    #[error("invalid time zone name: {0:?}")]
+    InvalidName(String),
+
+    /// Returned if the time zone file could not be read
+    #[error("failed to read time zone file")]
+    Io(#[from] std::io::Error),
+
+    /// Returned if the time zone file was not a valid TZif file
+    #[error("invalid TZif data")]
+    Tzif(#[from] ParseTzifError),
+}
+
+/// Error returned when parsing TZif data fails
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq)]
+pub enum ParseTzifError {
+    /// Returned if the data did not start with the TZif magic number
+    #[error("data does not start with TZif magic number")]
+    BadMagic,
+
+    /// Returned if the TZif version was not recognized
+    #[error("unsupported TZif version byte {0:#04x}")]
+    UnsupportedVersion(u8),
+
+    /// Returned if the data ended prematurely
+    #[error("TZif data is truncated")]
+    Truncated,
+
+    /// Returned if the data did not define any local time types or time zone
+    /// abbreviations
+    #[error("TZif data does not define any local time types")]
+    NoLocalTimeTypes,
+
+    /// Returned if the data contained leap second records, which are not
+    /// supported
+    #[error("TZif data with leap seconds is not supported")]
+    LeapSeconds,
+
+    /// Returned if the transition times were not in strictly ascending order
+    #[error("TZif transition times are not in ascending order")]
+    UnsortedTransitions,
+
+    /// Returned if a transition referred to a nonexistent local time type
+    #[error("TZif transition refers to nonexistent local time type")]
+    InvalidTypeIndex,
+
+    /// Returned if a local time type had an invalid UTC offset, DST indicator,
+    /// or abbreviation
+    #[error("invalid local time type in TZif data")]
+    InvalidLocalTimeType,
+
+    /// Returned if the footer was malformed or contained an invalid POSIX `TZ`
+    /// string
+    #[error("invalid TZif footer")]
+    InvalidFooter,
+}
+
+/// Error returned by
+/// [`TimeZone::from_tz_string()`][crate::tz::TimeZone::from_tz_string] on an
+/// invalid POSIX `TZ` string
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+#[derive(Clone, Copy, Debug, Default, Error, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[error("invalid POSIX TZ string")]
+pub struct ParseTzStringError;
+
+/// Error returned by
+/// [`Calendar::parse_long_date()`][crate::Calendar::parse_long_date] on an
+/// invalid or ambiguous input date string
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseLongDateError {
+    /// Returned if the date string was not in any recognized format
+    #[error("unrecognized date format")]
+    Unrecognized,
+
+    /// Returned if a numeric month component of the date string was zero or
+    /// greater than twelve
+    #[error("invalid month number: {value}")]
+    InvalidMonth {
+        /// The invalid month number
+        value: u32,
+    },
+
+    /// Returned if the year was too large or was zero while accompanied by an
+    /// era marker like "BC" or "AD"
+    #[error("invalid year")]
+    InvalidYear,
+
+    /// Returned if a numeric date like "03/04/1752" could be read with either
+    /// the day or the month first, giving two different valid dates, and no
+    /// weekday was given that matched just one of them
+    #[error(
+        "ambiguous date: could be {:04}-{:02}-{:02} or {:04}-{:02}-{:02}",
+        day_first.0,
+        day_first.1.number(),
+        day_first.2,
+        month_first.0,
+        month_first.1.number(),
+        month_first.2
+    )]
+    Ambiguous {
+        /// The year, month, and day obtained by reading the day first
+        day_first: (i32, Month, u32),
+
+        /// The year, month, and day obtained by reading the month first
+        month_first: (i32, Month, u32),
+    },
+
+    /// Returned if the date string included a weekday that does not match the
+    /// date
+    #[error(
+        "weekday mismatch: {:04}-{:02}-{:02} is a {actual}, not a {weekday}",
+        date.0,
+        date.1.number(),
+        date.2
+    )]
+    WeekdayMismatch {
+        /// The weekday given in the date string
+        weekday: Weekday,
+
+        /// The weekday on which the date actually falls
+        actual: Weekday,
+
+        /// The year, month, and day specified by the date string
+        date: (i32, Month, u32),
+    },
+
+    /// Returned if the date specified by the date string does not occur in the
+    /// calendar
+    #[error("invalid calendar date: {0}")]
+    InvalidDate(#[from] DateError),
+}
+
 /// Error returned by [`Calendar::parse_date()`][crate::Calendar::parse_date]
-/// on an invalid input date string
+/// and other date-parsing functions on an invalid input date string
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ParseDateError {
     /// Returned if the date specified by the date string does not occur in the
@@ -177,4 +590,15 @@ pub enum ParseDateError {
     /// as an integer
     #[error("numeric parse error: {0}")]
     ParseInt(#[from] ParseIntError),
+
+    /// Returned by
+    /// [`Calendar::parse_date_with_policy()`][crate::Calendar::parse_date_with_policy]
+    /// with [`StylePolicy::Reject`][crate::StylePolicy::Reject] if the date
+    /// string's style marker does not match the date, e.g., if a date marked
+    /// "N.S." falls before the calendar's reformation
+    #[error("date marked {style} is not in that style in this calendar")]
+    StyleMismatch {
+        /// The style marker given in the date string
+        style: Style,
+    },
 }
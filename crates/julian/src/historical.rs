@@ -0,0 +1,496 @@
+//! The Julian calendar as historically observed before AD 8
+//!
+//! When the Julian calendar was introduced in 45 BC, its leap-year rule was
+//! misapplied: the Roman pontiffs inserted a leap day every third year rather
+//! than every fourth.  Augustus corrected this [leap year error][error] by
+//! suspending leap years for a time, after which leap years resumed every four
+//! years in step with the proleptic Julian calendar.  The surviving sources do
+//! not determine exactly which years were leap years, and several scholarly
+//! reconstructions exist; this module lets you pick one in the form of a
+//! [`Reconstruction`].
+//!
+//! Under every reconstruction, dates from January 1 of the reconstruction's
+//! [resumption year][Reconstruction::resumption_year] onwards coincide with
+//! the proleptic Julian calendar.  Dates before 45 BC, when the Julian
+//! calendar was not yet in use, are reckoned by extending the calendar of 45
+//! BC backwards with a leap year every four years.
+//!
+//! All years are numbered astronomically, so 45 BC is year -44.
+//!
+//! [error]: https://en.wikipedia.org/wiki/Julian_calendar#Leap_year_error
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Month, historical::Reconstruction};
+//!
+//! let date = Reconstruction::Bennett.at_ymd(-43, Month::March, 15).unwrap();
+//! assert_eq!(date.julian_day_number(), 1705425);
+//! assert_eq!(date.proleptic_julian_day_number(), Some(1705426));
+//! assert_eq!(date.to_date().to_string(), "-043-03-14");
+//! ```
+use crate::errors::DateError;
+use crate::{COMMON_YEAR_LENGTH, Calendar, Date, Jdnum, LEAP_YEAR_LENGTH, Month, inner};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// The (astronomical) year in which the Julian calendar was introduced, 45 BC
+pub const JULIAN_START_YEAR: i32 = -44;
+
+/// An enumeration of reconstructions of the leap years observed in the early
+/// Julian calendar
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Reconstruction {
+    /// Joseph Scaliger's reconstruction (1583): leap years every three years
+    /// from 42 BC through 9 BC, resuming every four years in AD 8
+    Scaliger,
+
+    /// Christian Ludwig Ideler's reconstruction (1825): leap years every
+    /// three years from 45 BC through 9 BC, resuming every four years in AD 8
+    Ideler,
+
+    /// Johannes Kepler's reconstruction (1614): leap years every three years
+    /// from 43 BC through 10 BC, resuming every four years in AD 8
+    Kepler,
+
+    /// Chris Bennett's reconstruction (2004): leap years every three years
+    /// from 44 BC through 8 BC, resuming every four years in AD 4
+    Bennett,
+}
+
+impl Reconstruction {
+    /// Returns the surname of the scholar who proposed the reconstruction.
+    /// This is the same as the reconstruction's Rust identifier.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Reconstruction::Scaliger => "Scaliger",
+            Reconstruction::Ideler => "Ideler",
+            Reconstruction::Kepler => "Kepler",
+            Reconstruction::Bennett => "Bennett",
+        }
+    }
+
+    /// Returns the (astronomically-numbered) leap years from 45 BC up to (but
+    /// not including) the resumption year, in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::historical::Reconstruction;
+    ///
+    /// let leaps = Reconstruction::Scaliger.leap_years();
+    /// assert_eq!(leaps.first(), Some(&-41));
+    /// assert_eq!(leaps.last(), Some(&-8));
+    /// ```
+    pub const fn leap_years(&self) -> &'static [i32] {
+        match self {
+            Reconstruction::Scaliger => {
+                &[-41, -38, -35, -32, -29, -26, -23, -20, -17, -14, -11, -8]
+            }
+            Reconstruction::Ideler => &[
+                -44, -41, -38, -35, -32, -29, -26, -23, -20, -17, -14, -11, -8,
+            ],
+            Reconstruction::Kepler => &[-42, -39, -36, -33, -30, -27, -24, -21, -18, -15, -12, -9],
+            Reconstruction::Bennett => &[
+                -43, -40, -37, -34, -31, -28, -25, -22, -19, -16, -13, -10, -7,
+            ],
+        }
+    }
+
+    /// Returns the first year in which leap years were again observed every
+    /// four years.  From January 1 of this year onwards, dates coincide with
+    /// the proleptic Julian calendar.
+    pub const fn resumption_year(&self) -> i32 {
+        match self {
+            Reconstruction::Bennett => 4,
+            _ => 8,
+        }
+    }
+
+    /// Returns true if the given year was a leap year under the reconstruction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::historical::Reconstruction;
+    ///
+    /// let r = Reconstruction::Kepler;
+    /// assert!(r.is_leap_year(-9));
+    /// assert!(!r.is_leap_year(-8));
+    /// assert!(!r.is_leap_year(4));
+    /// assert!(r.is_leap_year(8));
+    /// ```
+    pub const fn is_leap_year(&self, year: i32) -> bool {
+        if JULIAN_START_YEAR <= year && year < self.resumption_year() {
+            let leaps = self.leap_years();
+            let mut i = 0;
+            while i < leaps.len() {
+                if leaps[i] == year {
+                    return true;
+                }
+                i += 1;
+            }
+            false
+        } else {
+            inner::is_julian_leap_year(year)
+        }
+    }
+
+    /// Returns the number of days in the given year under the reconstruction
+    pub const fn year_length(&self, year: i32) -> u32 {
+        if self.is_leap_year(year) {
+            LEAP_YEAR_LENGTH as u32
+        } else {
+            COMMON_YEAR_LENGTH as u32
+        }
+    }
+
+    /// Returns the date with the given year, month, and day of month under
+    /// the reconstruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::DayOutOfRange`] if `day` is zero or greater than
+    /// the last day of the given month for the given year.
+    ///
+    /// Returns [`DateError::Arithmetic`] if numeric overflow/underflow occurs
+    /// while calculating the date's Julian day number.
+    pub const fn at_ymd(
+        &self,
+        year: i32,
+        month: Month,
+        day: u32,
+    ) -> Result<HistoricalDate, DateError> {
+        let leap = self.is_leap_year(year);
+        let max_day = month_length(month, leap);
+        if day < 1 || day > max_day {
+            return Err(DateError::DayOutOfRange {
+                year,
+                month,
+                day,
+                min_day: 1,
+                max_day,
+            });
+        }
+        let ordinal = days_before_month(month, leap) + day;
+        match self.get_jdn(year, ordinal) {
+            Some(jdn) => Ok(HistoricalDate {
+                reconstruction: *self,
+                year,
+                ordinal,
+                month,
+                day,
+                jdn,
+            }),
+            None => Err(DateError::Arithmetic),
+        }
+    }
+
+    /// Returns the date with the given year and day-of-year under the
+    /// reconstruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::OrdinalOutOfRange`] if `ordinal` is zero or
+    /// greater than the length of the year.
+    ///
+    /// Returns [`DateError::Arithmetic`] if numeric overflow/underflow occurs
+    /// while calculating the date's Julian day number.
+    pub const fn at_ordinal_date(
+        &self,
+        year: i32,
+        ordinal: u32,
+    ) -> Result<HistoricalDate, DateError> {
+        let max_ordinal = self.year_length(year);
+        if ordinal < 1 || ordinal > max_ordinal {
+            return Err(DateError::OrdinalOutOfRange {
+                year,
+                ordinal,
+                max_ordinal,
+            });
+        }
+        let (month, day) = ordinal2md(ordinal, self.is_leap_year(year));
+        match self.get_jdn(year, ordinal) {
+            Some(jdn) => Ok(HistoricalDate {
+                reconstruction: *self,
+                year,
+                ordinal,
+                month,
+                day,
+                jdn,
+            }),
+            None => Err(DateError::Arithmetic),
+        }
+    }
+
+    /// Returns the date with the given Julian day number under the
+    /// reconstruction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::historical::Reconstruction;
+    ///
+    /// let date = Reconstruction::Bennett.at_jdn(1705426);
+    /// assert_eq!(date.to_string(), "-043-03-16");
+    /// assert_eq!(date.to_date().to_string(), "-043-03-15");
+    /// ```
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn at_jdn(&self, jdn: Jdnum) -> HistoricalDate {
+        let (year, ordinal) = if jdn >= self.resumption_jdn() {
+            inner::jdn2julian(jdn)
+        } else if jdn >= self.start_jdn() {
+            let mut year = JULIAN_START_YEAR;
+            // jdn >= start_jdn, so this is nonnegative:
+            let mut days = (jdn - self.start_jdn()) as u32;
+            while days >= self.year_length(year) {
+                days -= self.year_length(year);
+                year += 1;
+            }
+            (year, days + 1)
+        } else {
+            let (mut year, mut ordinal) = inner::jdn2julian(jdn);
+            // Step from the proleptic date to the date `shift` days earlier,
+            // which is at most a day or two away:
+            let mut shift = self.start_jdn() - julian_start_jdn();
+            while shift > 0 {
+                if ordinal > 1 {
+                    ordinal -= 1;
+                } else {
+                    year -= 1;
+                    ordinal = self.year_length(year);
+                }
+                shift -= 1;
+            }
+            while shift < 0 {
+                if ordinal < self.year_length(year) {
+                    ordinal += 1;
+                } else {
+                    year += 1;
+                    ordinal = 1;
+                }
+                shift += 1;
+            }
+            (year, ordinal)
+        };
+        let (month, day) = ordinal2md(ordinal, self.is_leap_year(year));
+        HistoricalDate {
+            reconstruction: *self,
+            year,
+            ordinal,
+            month,
+            day,
+            jdn,
+        }
+    }
+
+    /// [Private] Returns the Julian day number of January 1 of the
+    /// resumption year
+    const fn resumption_jdn(&self) -> Jdnum {
+        match inner::julian2jdn(self.resumption_year(), 1) {
+            Some(jdn) => jdn,
+            None => unreachable!(),
+        }
+    }
+
+    /// [Private] Returns the Julian day number of January 1, 45 BC, under the
+    /// reconstruction
+    #[allow(clippy::cast_possible_wrap)]
+    const fn start_jdn(&self) -> Jdnum {
+        let mut jdn = self.resumption_jdn();
+        let mut year = JULIAN_START_YEAR;
+        while year < self.resumption_year() {
+            jdn -= self.year_length(year) as Jdnum;
+            year += 1;
+        }
+        jdn
+    }
+
+    /// [Private] Calculates the Julian day number of the date with the given
+    /// year and valid day of year under the reconstruction.  Returns `None` on
+    /// arithmetic overflow/underflow.
+    #[allow(clippy::cast_possible_wrap)]
+    const fn get_jdn(&self, year: i32, ordinal: u32) -> Option<Jdnum> {
+        if year >= self.resumption_year() {
+            inner::julian2jdn(year, ordinal)
+        } else if year >= JULIAN_START_YEAR {
+            let mut jdn = self.start_jdn();
+            let mut y = JULIAN_START_YEAR;
+            while y < year {
+                jdn += self.year_length(y) as Jdnum;
+                y += 1;
+            }
+            Some(jdn + (ordinal - 1) as Jdnum)
+        } else {
+            match inner::julian2jdn(year, ordinal) {
+                Some(jdn) => jdn.checked_add(self.start_jdn() - julian_start_jdn()),
+                None => None,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Reconstruction {
+    /// A `Reconstruction` is displayed as its name
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// A date in the historical Julian calendar under a given [`Reconstruction`]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct HistoricalDate {
+    reconstruction: Reconstruction,
+    year: i32,
+    ordinal: u32,
+    month: Month,
+    day: u32,
+    jdn: Jdnum,
+}
+
+impl HistoricalDate {
+    /// Returns the reconstruction under which the date is reckoned
+    pub const fn reconstruction(&self) -> Reconstruction {
+        self.reconstruction
+    }
+
+    /// Returns the date's year
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the date's month
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the date's day of month
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the date's day of year
+    pub const fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// Returns the Julian day number of the date under the reconstruction
+    pub const fn julian_day_number(&self) -> Jdnum {
+        self.jdn
+    }
+
+    /// Returns the Julian day number that the same year, month, and day would
+    /// have in the proleptic Julian calendar.  Returns `None` if the date is a
+    /// February 29 that does not exist in the proleptic Julian calendar or if
+    /// numeric overflow/underflow occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, historical::Reconstruction};
+    ///
+    /// let date = Reconstruction::Scaliger.at_ymd(-41, Month::February, 29).unwrap();
+    /// assert_eq!(date.proleptic_julian_day_number(), None);
+    /// ```
+    pub const fn proleptic_julian_day_number(&self) -> Option<Jdnum> {
+        match Calendar::JULIAN.at_ymd(self.year, self.month, self.day) {
+            Ok(date) => Some(date.julian_day_number()),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns true if the reconstructed and proleptic interpretations of the
+    /// date's year, month, and day refer to the same day
+    pub const fn matches_proleptic(&self) -> bool {
+        matches!(self.proleptic_julian_day_number(), Some(jdn) if jdn == self.jdn)
+    }
+
+    /// Returns the date in the proleptic Julian calendar that falls on the
+    /// same day (i.e., that has the same Julian day number)
+    pub const fn to_date(&self) -> Date {
+        Calendar::JULIAN.at_jdn(self.jdn)
+    }
+}
+
+impl PartialOrd for HistoricalDate {
+    fn partial_cmp(&self, other: &HistoricalDate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HistoricalDate {
+    /// `HistoricalDate`s are ordered by Julian day number, with ties broken
+    /// by reconstruction
+    fn cmp(&self, other: &HistoricalDate) -> Ordering {
+        self.jdn
+            .cmp(&other.jdn)
+            .then_with(|| self.reconstruction.cmp(&other.reconstruction))
+    }
+}
+
+impl fmt::Display for HistoricalDate {
+    /// A `HistoricalDate` is displayed in the format `YYYY-MM-DD` (year, month
+    /// number, and day of month) by default.  Selecting the alternate form
+    /// with `{:#}` instead produces a string of the form `YYYY-JJJ` (year and
+    /// day of year).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-", self.year)?;
+        if f.alternate() {
+            write!(f, "{:03}", self.ordinal)?;
+        } else {
+            write!(f, "{:02}-{:02}", self.month.number(), self.day)?;
+        }
+        Ok(())
+    }
+}
+
+/// [Private] Returns the Julian day number of January 1, 45 BC, in the
+/// proleptic Julian calendar
+const fn julian_start_jdn() -> Jdnum {
+    match inner::julian2jdn(JULIAN_START_YEAR, 1) {
+        Some(jdn) => jdn,
+        None => unreachable!(),
+    }
+}
+
+/// [Private] Returns the number of days in the given month in a common or leap
+/// year
+const fn month_length(month: Month, leap: bool) -> u32 {
+    use Month::*;
+    match month {
+        February if leap => 29,
+        February => 28,
+        April | June | September | November => 30,
+        _ => 31,
+    }
+}
+
+/// [Private] Returns the number of days in a common or leap year before the
+/// start of the given month
+const fn days_before_month(month: Month, leap: bool) -> u32 {
+    let mut days = 0;
+    let mut m = Month::January;
+    while !m.eq(month) {
+        days += month_length(m, leap);
+        m = match m.succ() {
+            Some(m2) => m2,
+            None => unreachable!(),
+        };
+    }
+    days
+}
+
+/// [Private] Returns the month and day of month for the given valid day of
+/// year in a common or leap year
+const fn ordinal2md(ordinal: u32, leap: bool) -> (Month, u32) {
+    let mut day = ordinal;
+    let mut month = Month::January;
+    while day > month_length(month, leap) {
+        day -= month_length(month, leap);
+        month = match month.succ() {
+            Some(m) => m,
+            None => unreachable!(),
+        };
+    }
+    (month, day)
+}
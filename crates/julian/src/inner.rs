@@ -1,12 +1,17 @@
-use super::{COMMON_YEAR_LENGTH, Jdnum, LEAP_YEAR_LENGTH, Month, errors::ParseDateError};
+use super::{
+    COMMON_YEAR_LENGTH, Jdnum, LEAP_YEAR_LENGTH, Month, Style, Weekday,
+    errors::{ParseDateError, ParseLongDateError},
+    leap::LeapRule,
+    locale,
+};
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 
 // Julian-calendar year in which Julian day number 0 occurs
 const JDN0_YEAR: i32 = -4712;
 
-const GREGORIAN_CYCLE_DAYS: Jdnum = 146097;
-const GREGORIAN_CYCLE_YEARS: i32 = 400;
+pub(crate) const GREGORIAN_CYCLE_DAYS: Jdnum = 146097;
+pub(crate) const GREGORIAN_CYCLE_YEARS: i32 = 400;
 
 const JULIAN_LEAP_CYCLE_DAYS: Jdnum = 1461;
 const JULIAN_LEAP_CYCLE_YEARS: i32 = 4;
@@ -21,6 +26,46 @@ pub(crate) enum Calendar {
         // `reformation`:
         gap: ReformGap,
     },
+    /// A proleptic calendar following a leap rule other than the Julian or
+    /// Gregorian rule
+    Custom(&'static LeapRule),
+    /// A calendar that reforms from the Julian calendar to a leap rule other
+    /// than the Gregorian rule
+    CustomReforming {
+        reformation: Jdnum,
+        rule: &'static LeapRule,
+        // Ignored by comparison traits and Hash, as it's a function of
+        // `reformation` and `rule`.  Stored in compact form so that this
+        // variant is no larger than `Reforming`.
+        gap: CompactGap,
+    },
+}
+
+impl Calendar {
+    /// Returns the leap rule followed by the calendar after any reformation
+    pub(crate) const fn rule(&self) -> LeapRule {
+        match self {
+            Calendar::Julian => LeapRule::JULIAN,
+            Calendar::Gregorian | Calendar::Reforming { .. } => LeapRule::GREGORIAN,
+            Calendar::Custom(rule) | Calendar::CustomReforming { rule, .. } => **rule,
+        }
+    }
+
+    pub(crate) const fn reformation(&self) -> Option<Jdnum> {
+        match self {
+            Calendar::Reforming { reformation, .. }
+            | Calendar::CustomReforming { reformation, .. } => Some(*reformation),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn gap(&self) -> Option<ReformGap> {
+        match self {
+            Calendar::Reforming { gap, .. } => Some(*gap),
+            Calendar::CustomReforming { gap, .. } => Some(gap.expand()),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for Calendar {
@@ -39,23 +84,14 @@ impl PartialOrd for Calendar {
 
 impl Ord for Calendar {
     fn cmp(&self, other: &Calendar) -> Ordering {
-        use Calendar::*;
-        match (self, other) {
-            (Julian, Julian) => Ordering::Equal,
-            (Julian, _) => Ordering::Less,
-            (Reforming { .. }, Julian) => Ordering::Greater,
-            (
-                Reforming {
-                    reformation: r1, ..
-                },
-                Reforming {
-                    reformation: r2, ..
-                },
-            ) => r1.cmp(r2),
-            (Reforming { .. }, Gregorian) => Ordering::Less,
-            (Gregorian, Gregorian) => Ordering::Equal,
-            (Gregorian, _) => Ordering::Greater,
-        }
+        self.rule().cmp(&other.rule()).then_with(|| {
+            match (self.reformation(), other.reformation()) {
+                (Some(r1), Some(r2)) => r1.cmp(&r2),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        })
     }
 }
 
@@ -69,6 +105,17 @@ impl Hash for Calendar {
                 state.write_u8(3);
                 reformation.hash(state);
             }
+            Calendar::Custom(rule) => {
+                state.write_u8(4);
+                rule.hash(state);
+            }
+            Calendar::CustomReforming {
+                reformation, rule, ..
+            } => {
+                state.write_u8(5);
+                reformation.hash(state);
+                rule.hash(state);
+            }
         }
     }
 }
@@ -106,6 +153,62 @@ impl ReformGap {
     }
 }
 
+/// A `ReformGap` with each field narrowed to the smallest type that can hold
+/// it.  (Days of year are at most 366, and days of month are at most 31.)
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CompactGap {
+    pre_year: i32,
+    post_year: i32,
+    pre_ordinal: u16,
+    post_ordinal: u16,
+    ordinal_gap_start: u16,
+    ordinal_gap: u16,
+    pre_month: Month,
+    post_month: Month,
+    pre_day: u8,
+    post_day: u8,
+    kind: GapKind,
+}
+
+impl CompactGap {
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn new(gap: ReformGap) -> CompactGap {
+        CompactGap {
+            pre_year: gap.pre_reform.year,
+            post_year: gap.post_reform.year,
+            pre_ordinal: gap.pre_reform.ordinal as u16,
+            post_ordinal: gap.post_reform.ordinal as u16,
+            ordinal_gap_start: gap.ordinal_gap_start as u16,
+            ordinal_gap: gap.ordinal_gap as u16,
+            pre_month: gap.pre_reform.month,
+            post_month: gap.post_reform.month,
+            pre_day: gap.pre_reform.day as u8,
+            post_day: gap.post_reform.day as u8,
+            kind: gap.kind,
+        }
+    }
+
+    pub(crate) const fn expand(self) -> ReformGap {
+        ReformGap {
+            pre_reform: Date {
+                year: self.pre_year,
+                ordinal: self.pre_ordinal as u32,
+                month: self.pre_month,
+                day: self.pre_day as u32,
+            },
+            post_reform: Date {
+                year: self.post_year,
+                ordinal: self.post_ordinal as u32,
+                month: self.post_month,
+                day: self.post_day as u32,
+            },
+            kind: self.kind,
+            ordinal_gap_start: self.ordinal_gap_start as u32,
+            ordinal_gap: self.ordinal_gap as u32,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 pub(crate) struct Date {
     pub(crate) year: i32,
@@ -250,6 +353,282 @@ pub(crate) fn scan<P: FnMut(char) -> bool>(s: &str, mut predicate: P) -> (&str,
     s.split_at(boundary)
 }
 
+// Splits a trailing style marker, preceded by whitespace, off of a date
+// string
+pub(crate) fn strip_style(s: &str) -> (&str, Option<Style>) {
+    const MARKERS: [(&str, Style); 6] = [
+        ("O.S.", Style::Old),
+        ("OS", Style::Old),
+        ("Julian", Style::Old),
+        ("N.S.", Style::New),
+        ("NS", Style::New),
+        ("Gregorian", Style::New),
+    ];
+    for (marker, style) in MARKERS {
+        if let Some(i) = s.len().checked_sub(marker.len())
+            && let Some((date, suffix)) = s.split_at_checked(i)
+            && suffix.eq_ignore_ascii_case(marker)
+            && date.ends_with(char::is_whitespace)
+        {
+            return (date.trim_end(), Some(style));
+        }
+    }
+    (s, None)
+}
+
+// Maximum number of words in a long-form date string
+const MAX_LONG_DATE_WORDS: usize = 12;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct LongDate {
+    pub(crate) weekday: Option<Weekday>,
+    pub(crate) year: i32,
+    pub(crate) month_day: MonthDay,
+    pub(crate) style: Option<Style>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum MonthDay {
+    Known { month: Month, day: u32 },
+    // Two different numbers from 1 through 12, either of which could be the
+    // month
+    Either { first: u32, second: u32 },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Era {
+    BeforeChrist,
+    AnnoDomini,
+}
+
+// The words of a long-form date string, separated by whitespace and commas
+#[derive(Clone, Copy, Debug)]
+struct Words<'a> {
+    s: &'a str,
+    spans: [(usize, usize); MAX_LONG_DATE_WORDS],
+    len: usize,
+    pos: usize,
+}
+
+impl<'a> Words<'a> {
+    fn new(s: &'a str) -> Result<Self, ParseLongDateError> {
+        let mut spans = [(0, 0); MAX_LONG_DATE_WORDS];
+        let mut len = 0;
+        let mut start = None;
+        for (i, c) in s.char_indices().chain(core::iter::once((s.len(), ' '))) {
+            if c.is_whitespace() || c == ',' {
+                if let Some(st) = start.take() {
+                    *spans.get_mut(len).ok_or(ParseLongDateError::Unrecognized)? = (st, i);
+                    len += 1;
+                }
+            } else if start.is_none() {
+                start = Some(i);
+            }
+        }
+        Ok(Words {
+            s,
+            spans,
+            len,
+            pos: 0,
+        })
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.pos >= self.len
+    }
+
+    // Returns the next `n` words as a single slice of the input, including
+    // the separators between them
+    fn peek_n(&self, n: usize) -> Option<&'a str> {
+        if n == 0 || self.pos + n > self.len {
+            return None;
+        }
+        let (start, _) = self.spans[self.pos];
+        let (_, end) = self.spans[self.pos + n - 1];
+        Some(&self.s[start..end])
+    }
+
+    fn next_word(&mut self) -> Result<&'a str, ParseLongDateError> {
+        let word = self.peek_n(1).ok_or(ParseLongDateError::Unrecognized)?;
+        self.pos += 1;
+        Ok(word)
+    }
+
+    fn skip_any(&mut self, words: &[&str]) -> bool {
+        for n in [2, 1] {
+            if let Some(w) = self.peek_n(n)
+                && words.iter().any(|word| w.eq_ignore_ascii_case(word))
+            {
+                self.pos += n;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+pub(crate) fn parse_long_date(s: &str) -> Result<LongDate, ParseLongDateError> {
+    let words = Words::new(s)?;
+    // A leading word like "Mar" could be either a weekday (in French or
+    // Italian) or a month, so try both readings.
+    let mut weekday_err = None;
+    for n in [2, 1] {
+        if let Some((weekday, _)) = words.peek_n(n).and_then(|w| locale::parse_weekday(w).ok()) {
+            let mut rest = words;
+            rest.pos += n;
+            match parse_long_date_words(rest) {
+                Ok(date) => {
+                    return Ok(LongDate {
+                        weekday: Some(weekday),
+                        ..date
+                    });
+                }
+                Err(e) => {
+                    weekday_err.get_or_insert(e);
+                }
+            }
+        }
+    }
+    parse_long_date_words(words).map_err(|e| weekday_err.unwrap_or(e))
+}
+
+fn parse_long_date_words(mut words: Words<'_>) -> Result<LongDate, ParseLongDateError> {
+    words.skip_any(&["the"]);
+    let first = words.next_word()?;
+    let (month_day, year) = if let Some((month_day, year)) = parse_numeric_date(first)? {
+        let era = parse_era(&mut words);
+        (month_day, apply_era(year, era)?)
+    } else if let Some(day) = parse_day(first) {
+        words.skip_any(&["of"]);
+        let month = parse_month_name(words.next_word()?)?;
+        (MonthDay::Known { month, day }, parse_year(&mut words)?)
+    } else {
+        let month = parse_month_name(first)?;
+        let day = parse_day(words.next_word()?).ok_or(ParseLongDateError::Unrecognized)?;
+        (MonthDay::Known { month, day }, parse_year(&mut words)?)
+    };
+    // Russian dates often follow the year with "г." (for "года"):
+    words.skip_any(&["г.", "г"]);
+    let style = if words.skip_any(&["O.S.", "OS", "(O.S.)", "(OS)", "Old Style", "Julian"]) {
+        Some(Style::Old)
+    } else if words.skip_any(&["N.S.", "NS", "(N.S.)", "(NS)", "New Style", "Gregorian"]) {
+        Some(Style::New)
+    } else {
+        None
+    };
+    if !words.is_empty() {
+        return Err(ParseLongDateError::Unrecognized);
+    }
+    Ok(LongDate {
+        weekday: None,
+        year,
+        month_day,
+        style,
+    })
+}
+
+// Parses a date of the form `D/M/Y`, `M/D/Y`, or `Y/M/D`, where the slashes
+// may also be periods or hyphens.  The year is returned unparsed so that an
+// era can be applied to it.
+fn parse_numeric_date(word: &str) -> Result<Option<(MonthDay, &str)>, ParseLongDateError> {
+    let mut parts = word.split(['/', '.', '-']);
+    let (Some(a), Some(b), Some(c), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Ok(None);
+    };
+    if [a, b, c]
+        .iter()
+        .any(|p| p.is_empty() || !p.bytes().all(|ch| ch.is_ascii_digit()))
+    {
+        return Ok(None);
+    }
+    let number = |p: &str| {
+        p.parse::<u32>()
+            .map_err(|_| ParseLongDateError::Unrecognized)
+    };
+    let month =
+        |value: u32| Month::try_from(value).map_err(|_| ParseLongDateError::InvalidMonth { value });
+    if a.len() > 2 {
+        let month_day = MonthDay::Known {
+            month: month(number(b)?)?,
+            day: number(c)?,
+        };
+        return Ok(Some((month_day, a)));
+    }
+    let (x, y) = (number(a)?, number(b)?);
+    let month_day = if x == y || x == 0 || y == 0 || y > 12 {
+        MonthDay::Known {
+            month: month(x)?,
+            day: y,
+        }
+    } else if x > 12 {
+        MonthDay::Known {
+            month: month(y)?,
+            day: x,
+        }
+    } else {
+        MonthDay::Either {
+            first: x,
+            second: y,
+        }
+    };
+    Ok(Some((month_day, c)))
+}
+
+// Parses a day of the month with an optional ordinal suffix, like "30th",
+// "30.", or "1er"
+fn parse_day(word: &str) -> Option<u32> {
+    let (digits, suffix) = scan(word, |c| c.is_ascii_digit());
+    if ["", "st", "nd", "rd", "th", ".", "er", "e"]
+        .iter()
+        .any(|sfx| suffix.eq_ignore_ascii_case(sfx))
+    {
+        digits.parse::<u32>().ok()
+    } else {
+        None
+    }
+}
+
+fn parse_month_name(word: &str) -> Result<Month, ParseLongDateError> {
+    locale::parse_month(word)
+        .map(|(month, _)| month)
+        .map_err(|_| ParseLongDateError::Unrecognized)
+}
+
+fn parse_year(words: &mut Words<'_>) -> Result<i32, ParseLongDateError> {
+    let era = parse_era(words);
+    let year = words.next_word()?;
+    if year.is_empty() || !year.bytes().all(|ch| ch.is_ascii_digit()) {
+        return Err(ParseLongDateError::Unrecognized);
+    }
+    let era = era.or_else(|| parse_era(words));
+    apply_era(year, era)
+}
+
+fn parse_era(words: &mut Words<'_>) -> Option<Era> {
+    if words.skip_any(&["BC", "B.C.", "BCE", "B.C.E."]) {
+        Some(Era::BeforeChrist)
+    } else if words.skip_any(&["AD", "A.D.", "CE", "C.E."]) {
+        Some(Era::AnnoDomini)
+    } else {
+        None
+    }
+}
+
+// Converts a string of digits and an optional era to an astronomical year
+fn apply_era(year: &str, era: Option<Era>) -> Result<i32, ParseLongDateError> {
+    let year = year
+        .parse::<i32>()
+        .map_err(|_| ParseLongDateError::InvalidYear)?;
+    match era {
+        None => Ok(year),
+        Some(_) if year == 0 => Err(ParseLongDateError::InvalidYear),
+        Some(Era::AnnoDomini) => Ok(year),
+        Some(Era::BeforeChrist) => Ok(1 - year),
+    }
+}
+
 pub(crate) const fn is_julian_leap_year(year: i32) -> bool {
     year % JULIAN_LEAP_CYCLE_YEARS == 0
 }
@@ -0,0 +1,443 @@
+//! Leap-year rules for calendars other than the Julian & Gregorian
+//!
+//! A [`LeapRule`] describes which years of a "Julian-style" calendar (twelve
+//! months with an occasional leap day at the end of February) are leap years.
+//! In addition to the Julian and Gregorian rules, a rule can be constructed
+//! from a list of divisor clauses (such as the Gregorian "every fourth year,
+//! except centennial years, except years divisible by 400") or from a table of
+//! leap years within a repeating cycle of years.  A calendar following a rule
+//! can then be obtained with
+//! [`Calendar::with_leap_rule()`][crate::Calendar::with_leap_rule], and a
+//! calendar that switches from the Julian calendar to a rule can be obtained
+//! with [`Calendar::reforming_with_rule()`][crate::Calendar::reforming_with_rule].
+//!
+//! Calendars following a rule other than [`LeapRule::JULIAN`] are aligned
+//! with the proleptic Gregorian calendar at 2000-01-01 (JDN 2451545); i.e.,
+//! January 1, 2000, in such a calendar is the same day as January 1, 2000, in
+//! the proleptic Gregorian calendar.  A rule constructed from the Julian
+//! rule's clauses is equal to [`LeapRule::JULIAN`] and thus keeps the Julian
+//! calendar's alignment.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, leap::LeapRule};
+//!
+//! let cal = Calendar::with_leap_rule(&LeapRule::HERSCHEL);
+//! assert!(!cal.year_kind(4000).is_leap());
+//! assert!(Calendar::GREGORIAN.year_kind(4000).is_leap());
+//!
+//! let date = cal.at_ymd(4001, Month::January, 1).unwrap();
+//! assert_eq!(date.convert_to(Calendar::GREGORIAN).to_string(), "4000-12-31");
+//! ```
+use crate::errors::LeapRuleError;
+use crate::{COMMON_YEAR_LENGTH, Jdnum, inner};
+
+/// The year at which calendars following custom rules are aligned with the
+/// proleptic Gregorian calendar
+const ALIGNMENT_YEAR: i32 = 2000;
+
+/// The Julian day number of January 1 of `ALIGNMENT_YEAR`
+const ALIGNMENT_JDN: Jdnum = 2451545;
+
+/// A rule for determining which years of a calendar are leap years.
+///
+/// Rules are ordered as follows: the Julian rule is less than all other
+/// rules; it is followed by the Gregorian rule, then by rules constructed with
+/// [`LeapRule::from_clauses()`] (ordered by their clauses), then by rules
+/// constructed with [`LeapRule::from_cycle()`] (ordered by period and then by
+/// leap years).
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LeapRule(Repr);
+
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+enum Repr {
+    Julian,
+    Gregorian,
+    Clauses(&'static [LeapClause]),
+    Cycle {
+        period: u32,
+        leap_years: &'static [u32],
+    },
+}
+
+impl LeapRule {
+    /// The Julian rule: every year divisible by four is a leap year
+    pub const JULIAN: LeapRule = LeapRule(Repr::Julian);
+
+    /// The Gregorian rule: every year divisible by four is a leap year, except
+    /// for years divisible by 100 but not by 400
+    pub const GREGORIAN: LeapRule = LeapRule(Repr::Gregorian);
+
+    /// The Gregorian rule with John Herschel's proposed refinement that years
+    /// divisible by 4000 are common years
+    pub const HERSCHEL: LeapRule = LeapRule(Repr::Clauses(&[
+        LeapClause::Include(4),
+        LeapClause::Exclude(100),
+        LeapClause::Include(400),
+        LeapClause::Exclude(4000),
+    ]));
+
+    /// The "128-year rule": every year divisible by four is a leap year, except
+    /// for years divisible by 128
+    pub const RULE128: LeapRule = LeapRule(Repr::Clauses(&[
+        LeapClause::Include(4),
+        LeapClause::Exclude(128),
+    ]));
+
+    /// Construct a rule from a list of divisor clauses.
+    ///
+    /// A year is a leap year if the last clause whose divisor evenly divides
+    /// the year is a [`LeapClause::Include`]; if no clause's divisor divides
+    /// the year, or if the last such clause is a [`LeapClause::Exclude`], the
+    /// year is a common year.  Each divisor must be a multiple of the divisor
+    /// before it.
+    ///
+    /// If `clauses` is equal to the clauses of the Julian or Gregorian rule,
+    /// the result is equal to [`LeapRule::JULIAN`] or [`LeapRule::GREGORIAN`],
+    /// respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::leap::{LeapClause, LeapRule};
+    ///
+    /// let rule = LeapRule::from_clauses(&[
+    ///     LeapClause::Include(4),
+    ///     LeapClause::Exclude(100),
+    ///     LeapClause::Include(400),
+    /// ]);
+    /// assert_eq!(rule, Ok(LeapRule::GREGORIAN));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LeapRuleError::ZeroDivisor`] if any divisor is zero.
+    ///
+    /// Returns [`LeapRuleError::UnnestedDivisor`] if any divisor is not a
+    /// multiple of the divisor before it.
+    pub const fn from_clauses(clauses: &'static [LeapClause]) -> Result<LeapRule, LeapRuleError> {
+        let mut previous = 1;
+        let mut i = 0;
+        while i < clauses.len() {
+            let divisor = clauses[i].divisor();
+            if divisor == 0 {
+                return Err(LeapRuleError::ZeroDivisor);
+            }
+            if !divisor.is_multiple_of(previous) {
+                return Err(LeapRuleError::UnnestedDivisor { divisor, previous });
+            }
+            previous = divisor;
+            i += 1;
+        }
+        if matches!(clauses, [LeapClause::Include(4)]) {
+            Ok(LeapRule::JULIAN)
+        } else if is_gregorian_clauses(clauses) {
+            Ok(LeapRule::GREGORIAN)
+        } else {
+            Ok(LeapRule(Repr::Clauses(clauses)))
+        }
+    }
+
+    /// Construct a rule from a cycle of years.  A year is a leap year if &
+    /// only if the remainder of dividing it by `period` (using Euclidean
+    /// division) is one of the values in `leap_years`.
+    ///
+    /// If the cycle has exactly the leap years of the Julian or Gregorian
+    /// rule, the result is equal to [`LeapRule::JULIAN`] or
+    /// [`LeapRule::GREGORIAN`], respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::leap::LeapRule;
+    ///
+    /// // A 33-year cycle with eight leap years
+    /// let rule = LeapRule::from_cycle(33, &[0, 4, 8, 12, 16, 20, 24, 28]).unwrap();
+    /// assert!(rule.is_leap_year(1980));
+    /// assert!(!rule.is_leap_year(1982));
+    /// assert!(rule.is_leap_year(1984));
+    /// assert!(!rule.is_leap_year(2009));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LeapRuleError::ZeroPeriod`] if `period` is zero.
+    ///
+    /// Returns [`LeapRuleError::InvalidCycleYear`] if any value in
+    /// `leap_years` is not less than `period` or is not greater than the
+    /// value before it.
+    pub const fn from_cycle(
+        period: u32,
+        leap_years: &'static [u32],
+    ) -> Result<LeapRule, LeapRuleError> {
+        if period == 0 {
+            return Err(LeapRuleError::ZeroPeriod);
+        }
+        let mut i = 0;
+        while i < leap_years.len() {
+            let year = leap_years[i];
+            if year >= period || (i > 0 && year <= leap_years[i - 1]) {
+                return Err(LeapRuleError::InvalidCycleYear { year, period });
+            }
+            i += 1;
+        }
+        if is_cycle_of(period, leap_years, LeapRule::JULIAN) {
+            Ok(LeapRule::JULIAN)
+        } else if is_cycle_of(period, leap_years, LeapRule::GREGORIAN) {
+            Ok(LeapRule::GREGORIAN)
+        } else {
+            Ok(LeapRule(Repr::Cycle { period, leap_years }))
+        }
+    }
+
+    /// Returns true if the given year is a leap year under the rule
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::leap::LeapRule;
+    ///
+    /// assert!(LeapRule::JULIAN.is_leap_year(1900));
+    /// assert!(!LeapRule::GREGORIAN.is_leap_year(1900));
+    /// assert!(LeapRule::RULE128.is_leap_year(1900));
+    /// assert!(!LeapRule::RULE128.is_leap_year(1920));
+    /// ```
+    pub const fn is_leap_year(&self, year: i32) -> bool {
+        match self.0 {
+            Repr::Julian => inner::is_julian_leap_year(year),
+            Repr::Gregorian => inner::is_gregorian_leap_year(year),
+            Repr::Clauses(clauses) => {
+                let mut leap = false;
+                let mut i = 0;
+                while i < clauses.len() {
+                    if (year as i64) % (clauses[i].divisor() as i64) == 0 {
+                        leap = matches!(clauses[i], LeapClause::Include(_));
+                    }
+                    i += 1;
+                }
+                leap
+            }
+            Repr::Cycle { period, leap_years } => {
+                contains(leap_years, cycle_position(year as i64, period))
+            }
+        }
+    }
+
+    /// Returns true if this is the Julian rule
+    pub const fn is_julian(&self) -> bool {
+        matches!(self.0, Repr::Julian)
+    }
+
+    /// Returns true if this is the Gregorian rule
+    pub const fn is_gregorian(&self) -> bool {
+        matches!(self.0, Repr::Gregorian)
+    }
+
+    /// [Private] Returns the length in years of a period after which the
+    /// pattern of leap years repeats
+    pub(crate) const fn period(&self) -> i64 {
+        match self.0 {
+            Repr::Julian => 4,
+            Repr::Gregorian => 400,
+            _ => self.period_leaps().0,
+        }
+    }
+
+    /// [Private] Converts a Julian day number to the corresponding year and
+    /// day of year in the proleptic calendar following this rule.
+    ///
+    /// Valid for all `Jdnum` values.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(crate) const fn jdn2year_ordinal(&self, jdn: Jdnum) -> (i32, u32) {
+        match self.0 {
+            Repr::Julian => inner::jdn2julian(jdn),
+            Repr::Gregorian => inner::jdn2gregorian(jdn),
+            _ => {
+                let days = jdn as i64 - ALIGNMENT_JDN as i64;
+                let (period, leaps) = self.period_leaps();
+                // Estimate the year using the mean year length, then correct
+                // the estimate, which is off by at most a few years:
+                let mean_length = COMMON_YEAR_LENGTH as i128 * period as i128 + leaps as i128;
+                let mut year = ALIGNMENT_YEAR as i64
+                    + ((days as i128 * period as i128).div_euclid(mean_length)) as i64;
+                while self.days_before(year) > days {
+                    year -= 1;
+                }
+                while self.days_before(year + 1) <= days {
+                    year += 1;
+                }
+                // The year of any Jdnum fits in an i32, and the ordinal is
+                // always from 1 through 366.
+                (year as i32, (days - self.days_before(year) + 1) as u32)
+            }
+        }
+    }
+
+    /// [Private] Converts a year and day of year in the proleptic calendar
+    /// following this rule to the corresponding Julian day number.
+    ///
+    /// Returns `None` on arithmetic underflow/overflow.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn year_ordinal2jdn(&self, year: i32, ordinal: u32) -> Option<Jdnum> {
+        match self.0 {
+            Repr::Julian => inner::julian2jdn(year, ordinal),
+            Repr::Gregorian => inner::gregorian2jdn(year, ordinal),
+            _ => {
+                let jdn = ALIGNMENT_JDN as i64 + self.days_before(year as i64) + ordinal as i64 - 1;
+                if jdn < Jdnum::MIN as i64 || jdn > Jdnum::MAX as i64 {
+                    None
+                } else {
+                    Some(jdn as Jdnum)
+                }
+            }
+        }
+    }
+
+    /// [Private] Returns the number of days from January 1 of
+    /// `ALIGNMENT_YEAR` to January 1 of `year` (negative if `year` is before
+    /// `ALIGNMENT_YEAR`).  Only valid for custom rules.
+    const fn days_before(&self, year: i64) -> i64 {
+        COMMON_YEAR_LENGTH as i64 * (year - ALIGNMENT_YEAR as i64) + self.leaps_before(year)
+            - self.leaps_before(ALIGNMENT_YEAR as i64)
+    }
+
+    /// [Private] Returns the number of leap years from year 0 up to (but not
+    /// including) `year`, negated if `year` is negative.  Only valid for
+    /// custom rules.
+    #[allow(clippy::cast_possible_wrap)]
+    const fn leaps_before(&self, year: i64) -> i64 {
+        match self.0 {
+            Repr::Clauses(clauses) => {
+                let mut leaps = 0;
+                let mut i = 0;
+                while i < clauses.len() {
+                    if let LeapClause::Include(divisor) = clauses[i] {
+                        leaps += multiples_before(year, divisor);
+                        if i + 1 < clauses.len() {
+                            leaps -= multiples_before(year, clauses[i + 1].divisor());
+                        }
+                    }
+                    i += 1;
+                }
+                leaps
+            }
+            Repr::Cycle { period, leap_years } => {
+                let position = cycle_position(year, period);
+                let mut leaps = year.div_euclid(period as i64) * leap_years.len() as i64;
+                let mut i = 0;
+                while i < leap_years.len() && leap_years[i] < position {
+                    leaps += 1;
+                    i += 1;
+                }
+                leaps
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// [Private] Returns the length in years of a period after which the
+    /// pattern of leap years repeats along with the number of leap years in
+    /// such a period.  Only valid for custom rules.
+    const fn period_leaps(&self) -> (i64, i64) {
+        let period = match self.0 {
+            Repr::Clauses(clauses) => match clauses.last() {
+                Some(clause) => clause.divisor() as i64,
+                None => 1,
+            },
+            Repr::Cycle { period, .. } => period as i64,
+            _ => unreachable!(),
+        };
+        (period, self.leaps_before(period))
+    }
+}
+
+/// A clause in a divisor-based leap rule.  See [`LeapRule::from_clauses()`].
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LeapClause {
+    /// Years divisible by the given number are leap years (unless overridden
+    /// by a later clause)
+    Include(u32),
+
+    /// Years divisible by the given number are common years (unless
+    /// overridden by a later clause)
+    Exclude(u32),
+}
+
+impl LeapClause {
+    /// Returns the clause's divisor
+    pub const fn divisor(&self) -> u32 {
+        match self {
+            LeapClause::Include(d) | LeapClause::Exclude(d) => *d,
+        }
+    }
+}
+
+/// [Private] Returns true if `clauses` equals the clauses of the Gregorian
+/// rule
+const fn is_gregorian_clauses(clauses: &[LeapClause]) -> bool {
+    matches!(
+        clauses,
+        [
+            LeapClause::Include(4),
+            LeapClause::Exclude(100),
+            LeapClause::Include(400)
+        ]
+    )
+}
+
+/// [Private] Returns true if the (already validated) cycle of `leap_years`
+/// out of `period` years has exactly the leap years of `rule`, which must be
+/// the Julian or Gregorian rule
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn is_cycle_of(period: u32, leap_years: &[u32], rule: LeapRule) -> bool {
+    let (rule_period, rule_leaps) = match rule.0 {
+        Repr::Julian => (4, 1),
+        Repr::Gregorian => (400, 97),
+        _ => unreachable!(),
+    };
+    if !period.is_multiple_of(rule_period)
+        || leap_years.len() != (period / rule_period) as usize * rule_leaps
+    {
+        return false;
+    }
+    // The values in `leap_years` are distinct, so if there are as many of
+    // them as `rule` has leap years in `period` years and they're all leap
+    // years under `rule`, they're exactly the leap years of `rule`.  Each
+    // value is reduced modulo `rule_period` (which `period` is a multiple
+    // of) first so that the cast to `i32` is lossless.
+    let mut i = 0;
+    while i < leap_years.len() {
+        if !rule.is_leap_year((leap_years[i] % rule_period) as i32) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// [Private] Returns the number of multiples of `divisor` from 0 up to (but
+/// not including) `year`, negated if `year` is negative
+const fn multiples_before(year: i64, divisor: u32) -> i64 {
+    (year + divisor as i64 - 1).div_euclid(divisor as i64)
+}
+
+/// [Private] Returns the position of `year` within a cycle of length `period`
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn cycle_position(year: i64, period: u32) -> u32 {
+    // rem_euclid() always returns a nonnegative number less than `period`, so
+    // the cast is lossless:
+    year.rem_euclid(period as i64) as u32
+}
+
+/// [Private] Like `slice::contains()`, but const
+const fn contains(values: &[u32], value: u32) -> bool {
+    let mut i = 0;
+    while i < values.len() {
+        if values[i] == value {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
@@ -19,12 +19,18 @@
 //!   mode.  When this feature is disabled, functions that use
 //!   [`std::time::SystemTime`] are not available.
 //!
+//! - `astro` — Enables the [`astro`] module for astronomical computations on
+//!   fractional Julian Dates.
+//!
 //! - `chrono` — Enables converting values of certain `julian` types to the
 //!   corresponding [`chrono`] types and *vice versa*.
 //!
 //! - `time` — Enables converting values of certain `julian` types to the
 //!   corresponding [`time`] types and *vice versa*.
 //!
+//! - `tz` — Enables the [`tz`] module for determining local dates in time
+//!   zones described by TZif files.  This feature implies `std`.
+//!
 //! Examples
 //! ========
 //!
@@ -96,7 +102,8 @@
 //! extended backwards before its historical introduction, including pretending
 //! that [early inaccuracies in applying the leap year rule][leap-error] never
 //! happened.  If you wish to determine the actual Julian day number of an
-//! event recorded before AD 8, you may need to consult external sources.
+//! event recorded before AD 8, see the [`historical`] module, which models the
+//! leap years actually observed under several scholarly reconstructions.
 //!
 //! However, the Julian calendar's rule for inserting leap years proved
 //! insufficiently accurate to the solar year, causing the dates of the
@@ -167,19 +174,42 @@
 //! day numbers fit in this type.  Thus, the ranges of accepted input values
 //! are:
 //!
-//! |                         | Minimum          | Maximum         |
-//! | ----------------------- | ---------------- | --------------- |
-//! | Julian day number       | -2147483648      | 2147483647      |
-//! | Julian calendar date    | -5884202-03-16   | 5874777-10-17   |
-//! | Gregorian calendar date | -5884323-05-15   | 5874898-06-03   |
-//! | Unix timestamp          | -185753453990400 | 185331720383999 |
-
+//! |                         | Minimum                   | Maximum                  |
+//! | ----------------------- | ------------------------- | ------------------------ |
+//! | Julian day number       | -2147483648               | 2147483647               |
+//! | Julian calendar date    | -5884202-03-16            | 5874777-10-17            |
+//! | Gregorian calendar date | -5884323-05-15            | 5874898-06-03            |
+//! | Unix timestamp          | -185753453990400          | 185331720383999          |
+//! | Unix time in millis     | -185753453990400000       | 185331720383999999       |
+//! | Unix time in nanos      | -185753453990400000000000 | 185331720383999999999999 |
+
+#[cfg(feature = "astro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "astro")))]
+pub mod astro;
+pub mod byzantine;
+pub mod cycles;
+pub mod daycount;
+pub mod dominical;
 pub mod errors;
+pub mod historical;
 mod inner;
 pub mod iter;
+pub mod leap;
+pub mod locale;
 pub mod ncal;
+pub mod perpetual;
+pub mod roman;
+pub mod saka;
+pub mod sexagenary;
+pub mod spreadsheet;
+pub mod timestamp;
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+pub mod tz;
 use crate::errors::*;
 use crate::iter::*;
+use crate::leap::LeapRule;
+use crate::locale::{Locale, Localized};
 use core::cmp::Ordering;
 use core::fmt;
 use core::ops::RangeInclusive;
@@ -205,13 +235,20 @@ pub const UNIX_EPOCH_JDN: Jdnum = 2440588;
 /// in the proleptic Gregorian calendar.
 ///
 /// The Rata Die day number for a given date can be determined by subtracting
-/// this constant from the date's Julian day number.
+/// this constant from the date's Julian day number, or by using
+/// [`DayCount::RataDie`][crate::daycount::DayCount::RataDie].
 ///
 /// [Rata Die]: https://en.wikipedia.org/wiki/Rata_Die
 pub const RATA_DIE_ZERO_JDN: Jdnum = 1721425;
 
 const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
 
+const MILLIS_IN_DAY: i64 = SECONDS_IN_DAY * 1000;
+
+const NANOS_IN_DAY: i128 = (SECONDS_IN_DAY as i128) * 1_000_000_000;
+
+const NANOS_IN_MILLI: u64 = 1_000_000;
+
 const COMMON_YEAR_LENGTH: Jdnum = 365;
 const LEAP_YEAR_LENGTH: Jdnum = 366;
 
@@ -275,6 +312,78 @@ impl YearKind {
     }
 }
 
+/// An enumeration of the two styles in which dates were written while the
+/// Julian and Gregorian calendars were both in use
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Style {
+    /// "Old Style" (O.S.), i.e., the Julian calendar
+    Old,
+
+    /// "New Style" (N.S.), i.e., the Gregorian calendar
+    New,
+}
+
+impl Style {
+    /// Returns the proleptic calendar used by dates in this style
+    pub const fn calendar(&self) -> Calendar {
+        match self {
+            Style::Old => Calendar::JULIAN,
+            Style::New => Calendar::GREGORIAN,
+        }
+    }
+
+    /// Returns true if the given date is in this style, i.e., if it is in the
+    /// Julian calendar for `Old` or in the Gregorian calendar for `New`
+    pub const fn matches(&self, date: &Date) -> bool {
+        match self {
+            Style::Old => date.is_julian(),
+            Style::New => date.is_gregorian(),
+        }
+    }
+}
+
+impl fmt::Display for Style {
+    /// A `Style` is displayed as "O.S." or "N.S."
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Style::Old => f.pad("O.S."),
+            Style::New => f.pad("N.S."),
+        }
+    }
+}
+
+/// What [`Calendar::parse_date_with_policy()`] should do when a date string
+/// is marked with a [`Style`] that does not match the date's position in the
+/// calendar, like "1582-10-10 N.S." in a calendar that reforms after that
+/// date
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum StylePolicy {
+    /// Convert the date from the marked style to the calendar
+    #[default]
+    Convert,
+
+    /// Return [`ParseDateError::StyleMismatch`]
+    Reject,
+}
+
+/// How [`Calendar::at_ymd_lenient()`] should resolve a date that was skipped
+/// by a calendar reformation, such as a date recorded by someone who kept
+/// using the Julian calendar after the reformation
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SkippedDateStrategy {
+    /// Use the first date after the skipped range, i.e., the calendar's
+    /// [`first_gregorian_date()`][Calendar::first_gregorian_date]
+    Forward,
+
+    /// Use the last date before the skipped range, i.e., the calendar's
+    /// [`last_julian_date()`][Calendar::last_julian_date]
+    Backward,
+
+    /// Read the date as an Old Style (Julian) date and convert it to the
+    /// calendar
+    OldStyle,
+}
+
 /// A "Julian-style" calendar, featuring twelve months and occasionally a leap
 /// day at the end of February.
 ///
@@ -284,11 +393,17 @@ impl YearKind {
 /// 400), or a "reforming" calendar that starts out as Julian and changes to
 /// Gregorian at some date, with the reformation involving skipping a number of
 /// calendar days in order to align with the proleptic Gregorian calendar.
+/// Calendars can also follow (or reform to) other [leap-year
+/// rules][leap::LeapRule].
 ///
-/// The `Ord` implementation is such that the proleptic Julian calendar is
-/// smaller than all other calendars; it is followed by "reforming" calendars
-/// in ascending order of reformation date, and then the proleptic Gregorian
-/// calendar is larger than all other calendars.
+/// The `Ord` implementation orders calendars first by the [leap
+/// rule][Calendar::leap_rule] that they follow after any reformation.  Thus,
+/// the proleptic Julian calendar is smaller than all other calendars; it is
+/// followed by "reforming" Gregorian calendars in ascending order of
+/// reformation date, and then by the proleptic Gregorian calendar, which is
+/// in turn followed by calendars with other leap rules.  Among calendars with
+/// the same leap rule, "reforming" calendars are ordered by reformation date
+/// and are smaller than the corresponding proleptic calendar.
 #[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Calendar(inner::Calendar);
 
@@ -345,17 +460,55 @@ impl Calendar {
     /// only happen for Julian day numbers greater than 2147439588
     /// (corresponding to the date 5874777-10-17 N.S. or 5874657-03-02 O.S.).
     pub const fn reforming(reformation: Jdnum) -> Result<Calendar, ReformingError> {
+        Calendar::reforming_with_rule(reformation, &LeapRule::GREGORIAN)
+    }
+
+    /// Construct an instance of a reforming calendar that switches from the
+    /// Julian calendar to a calendar following the given leap rule.
+    /// `reformation` is the Julian day number of the first day on which the
+    /// new rule is used.
+    ///
+    /// `Calendar::reforming_with_rule(reformation, &LeapRule::GREGORIAN)` is
+    /// equivalent to `Calendar::reforming(reformation)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, leap::LeapRule};
+    ///
+    /// let cal = Calendar::reforming_with_rule(2460000, &LeapRule::HERSCHEL).unwrap();
+    /// assert_eq!(cal.last_julian_date().unwrap().to_string(), "2023-02-10");
+    /// assert_eq!(cal.first_gregorian_date().unwrap().to_string(), "2023-02-24");
+    /// assert!(!cal.year_kind(4000).is_leap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReformingError::InvalidReformation`] if observing a
+    /// reformation at the given date would not cause the calendar to skip
+    /// forwards.
+    ///
+    /// Returns [`ReformingError::Arithmetic`] if numeric overflow/underflow
+    /// occurs while converting `reformation` to a calendar date.
+    pub const fn reforming_with_rule(
+        reformation: Jdnum,
+        rule: &'static LeapRule,
+    ) -> Result<Calendar, ReformingError> {
         let pre_reform = Calendar::JULIAN.at_jdn(match reformation.checked_sub(1) {
             Some(jdn) => jdn,
             None => return Err(ReformingError::InvalidReformation),
         });
-        let post_reform = Calendar::GREGORIAN.at_jdn(reformation);
+        let post_reform = Calendar::with_leap_rule(rule).at_jdn(reformation);
+        // Find the Julian day of year for the post-reform month & day:
         let mut ordinal = post_reform.ordinal();
-        if post_reform.year % 100 == 0
-            && post_reform.year % 400 != 0
-            && Month::February.lt(post_reform.month)
-        {
-            ordinal += 1;
+        if Month::February.lt(post_reform.month) {
+            let julian_leap = inner::is_julian_leap_year(post_reform.year);
+            let rule_leap = rule.is_leap_year(post_reform.year);
+            if julian_leap && !rule_leap {
+                ordinal += 1;
+            } else if rule_leap && !julian_leap {
+                ordinal -= 1;
+            }
         }
         match Calendar::JULIAN.get_jdn(post_reform.year(), ordinal) {
             Ok(date) if date <= reformation => return Err(ReformingError::InvalidReformation),
@@ -388,16 +541,52 @@ impl Calendar {
             month: post_reform.month,
             day: post_reform.day,
         };
-        Ok(Calendar(inner::Calendar::Reforming {
-            reformation,
-            gap: inner::ReformGap {
-                pre_reform,
-                post_reform,
-                kind,
-                ordinal_gap_start,
-                ordinal_gap,
-            },
-        }))
+        let gap = inner::ReformGap {
+            pre_reform,
+            post_reform,
+            kind,
+            ordinal_gap_start,
+            ordinal_gap,
+        };
+        if rule.is_gregorian() {
+            Ok(Calendar(inner::Calendar::Reforming { reformation, gap }))
+        } else {
+            Ok(Calendar(inner::Calendar::CustomReforming {
+                reformation,
+                rule,
+                gap: inner::CompactGap::new(gap),
+            }))
+        }
+    }
+
+    /// Construct a proleptic calendar following the given leap rule.
+    ///
+    /// `Calendar::with_leap_rule(&LeapRule::JULIAN)` is equal to
+    /// [`Calendar::JULIAN`], and `Calendar::with_leap_rule(&LeapRule::GREGORIAN)`
+    /// is equal to [`Calendar::GREGORIAN`].
+    ///
+    /// Calendars refer to custom rules rather than storing a copy of them, so
+    /// a rule constructed at runtime must be placed in a `static` (or leaked)
+    /// before it can be used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, leap::LeapRule};
+    ///
+    /// let cal = Calendar::with_leap_rule(&LeapRule::RULE128);
+    /// assert!(cal.year_kind(2100).is_leap());
+    /// let date = cal.at_ymd(2100, Month::February, 29).unwrap();
+    /// assert_eq!(date.convert_to(Calendar::GREGORIAN).to_string(), "2100-02-28");
+    /// ```
+    pub const fn with_leap_rule(rule: &'static LeapRule) -> Calendar {
+        if rule.is_julian() {
+            Calendar::JULIAN
+        } else if rule.is_gregorian() {
+            Calendar::GREGORIAN
+        } else {
+            Calendar(inner::Calendar::Custom(rule))
+        }
     }
 
     /// Returns the current date according to the calendar, along with a count
@@ -461,6 +650,147 @@ impl Calendar {
         }
     }
 
+    /// Returns the current date according to the calendar at the given fixed
+    /// offset from UTC, along with a count of seconds since local midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the local time is before
+    /// -5884323-05-15 (-5884202-03-16 O.S.) or after 5874898-06-03
+    /// (5874777-10-17 O.S.).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn now_with_offset(&self, offset: UtcOffset) -> Result<(Date, u32), ArithmeticError> {
+        self.at_system_time_with_offset(SystemTime::now(), offset)
+    }
+
+    /// Returns the date according to the calendar for the given system time at
+    /// the given fixed offset from UTC, along with a count of seconds since
+    /// local midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the local time is before
+    /// -5884323-05-15 (-5884202-03-16 O.S.) or after 5874898-06-03
+    /// (5874777-10-17 O.S.).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn at_system_time_with_offset(
+        &self,
+        t: SystemTime,
+        offset: UtcOffset,
+    ) -> Result<(Date, u32), ArithmeticError> {
+        self.at_unix_time_with_offset(system2unix(t)?, offset)
+    }
+
+    /// Returns the date according to the calendar for the given system time,
+    /// along with a count of nanoseconds since midnight UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the system time in UTC is
+    /// before -5884323-05-15 (-5884202-03-16 O.S.) or after 5874898-06-03
+    /// (5874777-10-17 O.S.).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn at_system_time_nanos(&self, t: SystemTime) -> Result<(Date, u64), ArithmeticError> {
+        let (jdn, nanos) = system2jdn_nanos(t)?;
+        Ok((self.at_jdn(jdn), nanos))
+    }
+
+    /// Returns the date according to the calendar for the given [Unix time][]
+    /// at the given fixed offset from UTC, along with a count of seconds since
+    /// local midnight.
+    ///
+    /// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, UtcOffset};
+    ///
+    /// let ist = UtcOffset::new(19800).unwrap();
+    /// let (date, seconds) = Calendar::GREGORIAN
+    ///     .at_unix_time_with_offset(1682879400, ist)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "2023-05-01");
+    /// assert_eq!(seconds, 0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the timestamp plus the
+    /// offset is less than -185753453990400 or greater than 185331720383999.
+    pub const fn at_unix_time_with_offset(
+        &self,
+        unix_time: i64,
+        offset: UtcOffset,
+    ) -> Result<(Date, u32), ArithmeticError> {
+        match unix_time.checked_add(offset.0 as i64) {
+            Some(local) => self.at_unix_time(local),
+            None => Err(ArithmeticError),
+        }
+    }
+
+    /// Returns the date according to the calendar for the given [Unix time][]
+    /// in milliseconds, along with a count of nanoseconds since midnight UTC.
+    ///
+    /// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::Calendar;
+    ///
+    /// let (date, nanos) = Calendar::GREGORIAN.at_unix_millis(1682906621123).unwrap();
+    /// assert_eq!(date.to_string(), "2023-05-01");
+    /// assert_eq!(nanos, 7421123000000);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the timestamp is less
+    /// than -185753453990400000 or greater than 185331720383999999.
+    pub const fn at_unix_millis(&self, unix_millis: i64) -> Result<(Date, u64), ArithmeticError> {
+        match unix_millis2jdn(unix_millis) {
+            Ok((jdn, nanos)) => Ok((self.at_jdn(jdn), nanos)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the date according to the calendar for the given [Unix time][]
+    /// in nanoseconds, along with a count of nanoseconds since midnight UTC.
+    ///
+    /// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::Calendar;
+    ///
+    /// let (date, nanos) = Calendar::GREGORIAN.at_unix_nanos(1682906621123456789).unwrap();
+    /// assert_eq!(date.to_string(), "2023-05-01");
+    /// assert_eq!(nanos, 7421123456789);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.  This can only happen if the timestamp is less
+    /// than -185753453990400000000000 or greater than
+    /// 185331720383999999999999.
+    pub const fn at_unix_nanos(&self, unix_nanos: i128) -> Result<(Date, u64), ArithmeticError> {
+        match unix_nanos2jdn(unix_nanos) {
+            Ok((jdn, nanos)) => Ok((self.at_jdn(jdn), nanos)),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns the date of the calendar with the given year, month, and day of
     /// month.
     ///
@@ -515,6 +845,73 @@ impl Calendar {
         })
     }
 
+    /// Returns the date of the calendar with the given year, month, and day of
+    /// month, like [`Calendar::at_ymd()`], but resolving a date skipped by a
+    /// calendar reformation using the given strategy instead of returning
+    /// [`DateError::SkippedDate`].
+    ///
+    /// The strategy is returned along with the date if it was applied, and
+    /// `None` is returned along with the date if the date was not skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, SkippedDateStrategy, ncal};
+    ///
+    /// let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    ///
+    /// let (date, adjustment) = cal
+    ///     .at_ymd_lenient(1752, Month::September, 5, SkippedDateStrategy::Forward)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "1752-09-14");
+    /// assert_eq!(adjustment, Some(SkippedDateStrategy::Forward));
+    ///
+    /// let (date, adjustment) = cal
+    ///     .at_ymd_lenient(1752, Month::September, 5, SkippedDateStrategy::OldStyle)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "1752-09-16");
+    /// assert_eq!(adjustment, Some(SkippedDateStrategy::OldStyle));
+    ///
+    /// let (date, adjustment) = cal
+    ///     .at_ymd_lenient(1752, Month::September, 1, SkippedDateStrategy::OldStyle)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "1752-09-01");
+    /// assert_eq!(adjustment, None);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::DayOutOfRange`] or [`DateError::Arithmetic`] under
+    /// the same conditions as [`Calendar::at_ymd()`].  With
+    /// [`SkippedDateStrategy::OldStyle`], `DayOutOfRange` is also returned if
+    /// the skipped date does not exist in the Julian calendar.
+    pub const fn at_ymd_lenient(
+        &self,
+        year: i32,
+        month: Month,
+        day: u32,
+        strategy: SkippedDateStrategy,
+    ) -> Result<(Date, Option<SkippedDateStrategy>), DateError> {
+        match self.at_ymd(year, month, day) {
+            Ok(date) => return Ok((date, None)),
+            Err(DateError::SkippedDate { .. }) => (),
+            Err(e) => return Err(e),
+        }
+        let date = match strategy {
+            SkippedDateStrategy::Forward => self.first_gregorian_date(),
+            SkippedDateStrategy::Backward => self.last_julian_date(),
+            SkippedDateStrategy::OldStyle => match Calendar::JULIAN.at_ymd(year, month, day) {
+                Ok(date) => Some(date.convert_to(*self)),
+                Err(e) => return Err(e),
+            },
+        };
+        match date {
+            Some(date) => Ok((date, Some(strategy))),
+            // Only "reforming" calendars skip dates.
+            None => unreachable!(),
+        }
+    }
+
     /// Returns the date of the calendar with the given year and day-of-year
     /// (starting counting from 1 at January 1).
     ///
@@ -573,13 +970,11 @@ impl Calendar {
     /// assert_eq!(date.day(), 30);
     /// ```
     pub const fn at_jdn(&self, jdn: Jdnum) -> Date {
-        use inner::Calendar::*;
-        let (year, mut ordinal) = if matches!(self.0, Julian)
-            || matches!(self.0, Reforming { reformation, .. } if jdn < reformation)
+        let (year, mut ordinal) = if matches!(self.reformation(), Some(reformation) if jdn < reformation)
         {
             inner::jdn2julian(jdn)
         } else {
-            inner::jdn2gregorian(jdn)
+            self.leap_rule().jdn2year_ordinal(jdn)
         };
         if let Some(gap) = self.gap()
             && year == gap.post_reform.year
@@ -612,6 +1007,15 @@ impl Calendar {
     /// each component may be any number of digits long, not just the
     /// "conventional" length shown here.
     ///
+    /// The date may be followed by whitespace and a style marker — "O.S.",
+    /// "OS", or "Julian" for an Old Style date, or "N.S.", "NS", or
+    /// "Gregorian" for a New Style date, all case-insensitive — in which case
+    /// the date is read in the marked calendar and converted to this one.
+    /// Thus, in a "reforming" calendar, the marker determines which side of
+    /// the reformation the date falls on.  To instead reject dates whose
+    /// marker does not match [`Date::is_julian()`] or [`Date::is_gregorian()`]
+    /// after parsing, use [`Calendar::parse_date_with_policy()`].
+    ///
     /// # Example
     ///
     /// ```
@@ -622,6 +1026,9 @@ impl Calendar {
     /// assert_eq!(date.year(), 2023);
     /// assert_eq!(date.month(), Month::April);
     /// assert_eq!(date.day(), 30);
+    ///
+    /// let date = Calendar::REFORM1582.parse_date("1582-10-10 O.S.").unwrap();
+    /// assert_eq!(date.to_string(), "1582-10-20");
     /// ```
     ///
     /// # Errors
@@ -629,6 +1036,46 @@ impl Calendar {
     /// Returns [`ParseDateError`] if the string or the date it represents is
     /// invalid
     pub fn parse_date(&self, s: &str) -> Result<Date, ParseDateError> {
+        self.parse_date_with_policy(s, StylePolicy::Convert)
+    }
+
+    /// Parse a calendar date from a string, like [`Calendar::parse_date()`],
+    /// using the given policy for dates whose style marker does not match
+    /// the date's position in this calendar.
+    ///
+    /// A marked date is considered to match if, after conversion to this
+    /// calendar, [`Style::matches()`] returns true for it.  Unmarked dates
+    /// are never converted or rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Style, StylePolicy, errors::ParseDateError};
+    ///
+    /// let cal = Calendar::REFORM1582;
+    /// let date = cal
+    ///     .parse_date_with_policy("1582-10-04 O.S.", StylePolicy::Reject)
+    ///     .unwrap();
+    /// assert_eq!(date.to_string(), "1582-10-04");
+    ///
+    /// assert_eq!(
+    ///     cal.parse_date_with_policy("1582-10-10 N.S.", StylePolicy::Reject),
+    ///     Err(ParseDateError::StyleMismatch { style: Style::New }),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseDateError`] if the string or the date it represents is
+    /// invalid, or if `policy` is [`StylePolicy::Reject`] and the date's style
+    /// marker does not match the date
+    pub fn parse_date_with_policy(
+        &self,
+        s: &str,
+        policy: StylePolicy,
+    ) -> Result<Date, ParseDateError> {
+        let (s, style) = inner::strip_style(s);
+        let cal = style.map_or(*self, |st| st.calendar());
         let mut parser = inner::DateParser::new(s);
         let year = parser.parse_int()?;
         parser.scan_char('-')?;
@@ -636,14 +1083,141 @@ impl Calendar {
         if !parser.is_empty() {
             return Err(ParseDateError::Trailing);
         }
-        match diny {
-            inner::DayInYear::Ordinal(ordinal) => Ok(self.at_ordinal_date(year, ordinal)?),
-            inner::DayInYear::Date { month, day } => Ok(self.at_ymd(year, month, day)?),
+        let date = match diny {
+            inner::DayInYear::Ordinal(ordinal) => cal.at_ordinal_date(year, ordinal)?,
+            inner::DayInYear::Date { month, day } => cal.at_ymd(year, month, day)?,
+        };
+        let Some(style) = style else {
+            return Ok(date);
+        };
+        let date = date.convert_to(*self);
+        if style.matches(&date) || policy == StylePolicy::Convert {
+            Ok(date)
+        } else {
+            Err(ParseDateError::StyleMismatch { style })
+        }
+    }
+
+    /// Parse a calendar date written out in words, as in a letter or
+    /// register, like "April 30, 2023" or "Sunday, the 30th of April 1752
+    /// O.S.".
+    ///
+    /// The date string consists of the following, separated by whitespace
+    /// and/or commas:
+    ///
+    /// - An optional weekday, which must match the date
+    ///
+    /// - The day, month, and year in one of these forms:
+    ///     - day, month name, and year, like "30 April 2023", "30th of April
+    ///       2023", or "30. April 2023"; the day may be preceded by "the"
+    ///     - month name, day, and year, like "April 30, 2023" or "April 30th
+    ///       2023"
+    ///     - all numbers, like "30/04/2023", "04/30/2023", or "2023-04-30";
+    ///       the components may be separated by slashes, periods, or hyphens
+    ///
+    /// - An optional era marker ("BC", "B.C.", "BCE", "AD", "A.D.", or "CE")
+    ///   either before or after the year
+    ///
+    /// - An optional style marker: "O.S." or "Old Style" to indicate that the
+    ///   date is in the Julian calendar, or "N.S." or "New Style" to indicate
+    ///   that the date is in the Gregorian calendar.  A marked date is
+    ///   converted to this calendar.
+    ///
+    /// Month and weekday names are accepted in any language supported by the
+    /// [`locale`] module.  Words are matched case-insensitively.
+    ///
+    /// An all-numeric date in which the year is last is read with the day
+    /// first if the first number is greater than twelve and with the month
+    /// first if the second number is greater than twelve.  If both numbers are
+    /// twelve or less and they differ, the date is ambiguous; if a weekday was
+    /// given, the reading that matches it is used, and otherwise
+    /// [`ParseLongDateError::Ambiguous`] is returned with both readings.  If a
+    /// weekday was given and neither reading matches it,
+    /// [`ParseLongDateError::WeekdayMismatch`] is returned for the day-first
+    /// reading.  If only one reading is a valid date in the calendar (e.g.,
+    /// because the other was skipped by a reformation), that reading is used
+    /// without reporting an ambiguity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, errors::ParseLongDateError, ncal};
+    ///
+    /// let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    /// let date = cal.parse_long_date("Thursday, 14th September 1752").unwrap();
+    /// assert_eq!(date.to_string(), "1752-09-14");
+    ///
+    /// let date = cal.parse_long_date("September 3, 1752 N.S.").unwrap();
+    /// assert_eq!(date.to_string(), "1752-08-23");
+    ///
+    /// let date = Calendar::JULIAN.parse_long_date("15th of March, 44 BC").unwrap();
+    /// assert_eq!(date.year(), -43);
+    ///
+    /// let err = cal.parse_long_date("03/04/1752").unwrap_err();
+    /// assert!(matches!(err, ParseLongDateError::Ambiguous { .. }));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLongDateError`] if the string is not in a recognized
+    /// format, if the date it represents is invalid or ambiguous, or if the
+    /// weekday does not match the date
+    pub fn parse_long_date(&self, s: &str) -> Result<Date, ParseLongDateError> {
+        let parsed = inner::parse_long_date(s)?;
+        let cal = parsed.style.map_or(*self, |st| st.calendar());
+        let at_ymd = |month: u32, day: u32| {
+            let month = Month::try_from(month)
+                .map_err(|_| ParseLongDateError::InvalidMonth { value: month })?;
+            Ok::<_, ParseLongDateError>(cal.at_ymd(parsed.year, month, day)?.convert_to(*self))
+        };
+        let fits = |date: &Date| parsed.weekday.is_none_or(|wd| date.weekday() == wd);
+        let date = match parsed.month_day {
+            inner::MonthDay::Known { month, day } => at_ymd(month.number(), day)?,
+            inner::MonthDay::Either { first, second } => {
+                match (at_ymd(second, first), at_ymd(first, second)) {
+                    (Ok(day_first), Ok(month_first)) => {
+                        match (fits(&day_first), fits(&month_first)) {
+                            (true, false) => day_first,
+                            (false, true) => month_first,
+                            // Neither reading falls on the given weekday, so
+                            // the check below reports a mismatch against the
+                            // day-first reading:
+                            (false, false) => day_first,
+                            (true, true) => {
+                                return Err(ParseLongDateError::Ambiguous {
+                                    day_first: (
+                                        day_first.year(),
+                                        day_first.month(),
+                                        day_first.day(),
+                                    ),
+                                    month_first: (
+                                        month_first.year(),
+                                        month_first.month(),
+                                        month_first.day(),
+                                    ),
+                                });
+                            }
+                        }
+                    }
+                    (Ok(date), Err(_)) | (Err(_), Ok(date)) => date,
+                    (Err(e), Err(_)) => return Err(e),
+                }
+            }
+        };
+        if let Some(weekday) = parsed.weekday
+            && !fits(&date)
+        {
+            return Err(ParseLongDateError::WeekdayMismatch {
+                weekday,
+                actual: date.weekday(),
+                date: (date.year(), date.month(), date.day()),
+            });
         }
+        Ok(date)
     }
 
-    /// Returns true if this is a proleptic Julian or Gregorian calendar, i.e.,
-    /// not a "reforming" calendar
+    /// Returns true if this is a proleptic calendar (such as the proleptic
+    /// Julian or Gregorian calendar), i.e., not a "reforming" calendar
     ///
     /// # Example
     ///
@@ -655,7 +1229,7 @@ impl Calendar {
     /// assert!(!Calendar::REFORM1582.is_proleptic());
     /// ```
     pub const fn is_proleptic(&self) -> bool {
-        matches!(self.0, inner::Calendar::Julian | inner::Calendar::Gregorian)
+        self.0.reformation().is_none()
     }
 
     /// Returns true if this is a "reforming" calendar
@@ -670,17 +1244,28 @@ impl Calendar {
     /// assert!(Calendar::REFORM1582.is_reforming());
     /// ```
     pub const fn is_reforming(&self) -> bool {
-        matches!(self.0, inner::Calendar::Reforming { .. })
+        self.0.reformation().is_some()
     }
 
     /// If this is a "reforming" calendar, returns the Julian day number of the
     /// reformation (the first day on which the Gregorian calendar is used)
     pub const fn reformation(&self) -> Option<Jdnum> {
-        if let inner::Calendar::Reforming { reformation, .. } = self.0 {
-            Some(reformation)
-        } else {
-            None
-        }
+        self.0.reformation()
+    }
+
+    /// Returns the leap rule followed by the calendar.  For a "reforming"
+    /// calendar, this is the rule followed starting at the reformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, leap::LeapRule};
+    ///
+    /// assert_eq!(Calendar::JULIAN.leap_rule(), LeapRule::JULIAN);
+    /// assert_eq!(Calendar::REFORM1582.leap_rule(), LeapRule::GREGORIAN);
+    /// ```
+    pub const fn leap_rule(&self) -> LeapRule {
+        self.0.rule()
     }
 
     /// If this is a "reforming" calendar, returns the last date that follows
@@ -698,7 +1283,7 @@ impl Calendar {
     /// assert_eq!(date.day(), 4);
     /// ```
     pub const fn last_julian_date(&self) -> Option<Date> {
-        if let inner::Calendar::Reforming { reformation, gap } = self.0 {
+        if let (Some(reformation), Some(gap)) = (self.reformation(), self.gap()) {
             Some(Date {
                 calendar: *self,
                 year: gap.pre_reform.year,
@@ -714,7 +1299,8 @@ impl Calendar {
     }
 
     /// If this is a "reforming" calendar, returns the first date that follows
-    /// the Gregorian calendar, i.e., the date of the reformation.
+    /// the Gregorian calendar (or other [leap rule][Calendar::leap_rule]),
+    /// i.e., the date of the reformation.
     ///
     /// # Example
     ///
@@ -728,7 +1314,7 @@ impl Calendar {
     /// assert_eq!(date.day(), 15);
     /// ```
     pub const fn first_gregorian_date(&self) -> Option<Date> {
-        if let inner::Calendar::Reforming { reformation, gap } = self.0 {
+        if let (Some(reformation), Some(gap)) = (self.reformation(), self.gap()) {
             let day_ordinal = if matches!(gap.kind, inner::GapKind::IntraMonth) {
                 gap.pre_reform.day + 1
             } else {
@@ -766,22 +1352,16 @@ impl Calendar {
     /// assert_eq!(cal2.year_kind(48902), YearKind::Common);
     /// ```
     pub const fn year_kind(&self, year: i32) -> YearKind {
-        match self.0 {
-            inner::Calendar::Julian => {
-                if inner::is_julian_leap_year(year) {
-                    YearKind::Leap
-                } else {
-                    YearKind::Common
-                }
-            }
-            inner::Calendar::Gregorian => {
-                if inner::is_gregorian_leap_year(year) {
+        let rule = self.leap_rule();
+        match self.gap() {
+            None => {
+                if rule.is_leap_year(year) {
                     YearKind::Leap
                 } else {
                     YearKind::Common
                 }
             }
-            inner::Calendar::Reforming { gap, .. } => {
+            Some(gap) => {
                 use inner::RangeOrdering::*;
                 match gap.cmp_year(year) {
                     Less => {
@@ -814,7 +1394,7 @@ impl Calendar {
                         if (Month::February.lt(gap.pre_reform.month)
                             && inner::is_julian_leap_year(year))
                             || (gap.post_reform.month.le(Month::February)
-                                && inner::is_gregorian_leap_year(year))
+                                && rule.is_leap_year(year))
                         {
                             YearKind::ReformLeap
                         } else {
@@ -826,13 +1406,13 @@ impl Calendar {
                             (gap.post_reform.month, gap.post_reform.day),
                             (Month::January, 1)
                         ) {
-                            if inner::is_gregorian_leap_year(year) {
+                            if rule.is_leap_year(year) {
                                 YearKind::Leap
                             } else {
                                 YearKind::Common
                             }
                         } else if gap.post_reform.month.le(Month::February)
-                            && inner::is_gregorian_leap_year(year)
+                            && rule.is_leap_year(year)
                         {
                             YearKind::ReformLeap
                         } else {
@@ -840,7 +1420,7 @@ impl Calendar {
                         }
                     }
                     Greater => {
-                        if inner::is_gregorian_leap_year(year) {
+                        if rule.is_leap_year(year) {
                             YearKind::Leap
                         } else {
                             YearKind::Common
@@ -872,30 +1452,27 @@ impl Calendar {
     /// assert_eq!(cal2.year_length(48902), 365);
     /// ```
     pub const fn year_length(&self, year: i32) -> u32 {
-        match self.0 {
-            inner::Calendar::Julian | inner::Calendar::Gregorian => match self.year_kind(year) {
+        match self.gap() {
+            None => match self.year_kind(year) {
                 YearKind::Common => COMMON_YEAR_LENGTH as u32,
                 YearKind::Leap => LEAP_YEAR_LENGTH as u32,
                 _ => unreachable!(),
             },
-            inner::Calendar::Reforming { gap, .. } => match self.year_kind(year) {
+            Some(gap) => match self.year_kind(year) {
                 YearKind::Common => COMMON_YEAR_LENGTH as u32,
                 YearKind::Leap => LEAP_YEAR_LENGTH as u32,
-                k @ (YearKind::ReformCommon | YearKind::ReformLeap) => {
-                    let length = if matches!(k, YearKind::ReformCommon) {
-                        COMMON_YEAR_LENGTH as u32
-                    } else {
-                        LEAP_YEAR_LENGTH as u32
-                    };
+                YearKind::ReformCommon | YearKind::ReformLeap => {
                     if year == gap.post_reform.year {
-                        // If this is a Julian-only leap year and the year kind
-                        // is ReformLeap, then the year contains a Julian-only
-                        // leap day and we need to add 1 to `ordinal_gap`
-                        // because the `pre_reform.ordinal` subtrahend that
-                        // produced it counted the leap day but the
-                        // `post_reform.ordinal` minuend did not.
-                        let correction = (year % 100 == 0 && year % 400 != 0 && k.is_leap()) as u32;
-                        length - gap.ordinal_gap - correction
+                        // The last day of the year has the same day of year
+                        // under the new rule as the year's length under that
+                        // rule, and `ordinal_gap` must be subtracted from
+                        // that to get its day of year in this calendar.
+                        let length = if self.leap_rule().is_leap_year(year) {
+                            LEAP_YEAR_LENGTH as u32
+                        } else {
+                            COMMON_YEAR_LENGTH as u32
+                        };
+                        length - gap.ordinal_gap
                     } else {
                         debug_assert!(year == gap.pre_reform.year);
                         gap.pre_reform.ordinal
@@ -1063,7 +1640,30 @@ impl Calendar {
         if let Some(shape) = self.month_shape(year, month) {
             shape.day_ordinal_err(day)
         } else {
-            Err(DateError::SkippedDate { year, month, day })
+            Err(self.skipped_date(year, month, day))
+        }
+    }
+
+    /// [Private] Returns a [`DateError::SkippedDate`] for the given date,
+    /// which must have been skipped by the calendar's reformation
+    const fn skipped_date(&self, year: i32, month: Month, day: u32) -> DateError {
+        let Some(gap) = self.gap() else {
+            unreachable!()
+        };
+        DateError::SkippedDate {
+            year,
+            month,
+            day,
+            before: (
+                gap.pre_reform.year,
+                gap.pre_reform.month,
+                gap.pre_reform.day,
+            ),
+            after: (
+                gap.post_reform.year,
+                gap.post_reform.month,
+                gap.post_reform.day,
+            ),
         }
     }
 
@@ -1074,19 +1674,17 @@ impl Calendar {
     ///
     /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs.
     const fn get_jdn(&self, year: i32, mut ordinal: u32) -> Result<Jdnum, ArithmeticError> {
-        use inner::Calendar::*;
         if let Some(gap) = self.gap()
             && year == gap.post_reform.year
             && ordinal >= gap.post_reform.ordinal
         {
             ordinal += gap.ordinal_gap;
         }
-        let r = if matches!(self.0, Julian)
-            || matches!(self.0, Reforming {gap, ..} if year < gap.post_reform.year || (year == gap.post_reform.year && ordinal < gap.post_reform.ordinal))
+        let r = if matches!(self.gap(), Some(gap) if year < gap.post_reform.year || (year == gap.post_reform.year && ordinal < gap.post_reform.ordinal))
         {
             inner::julian2jdn(year, ordinal)
         } else {
-            inner::gregorian2jdn(year, ordinal)
+            self.leap_rule().year_ordinal2jdn(year, ordinal)
         };
         match r {
             Some(jdn) => Ok(jdn),
@@ -1097,10 +1695,7 @@ impl Calendar {
     /// [Private] If this is a "reforming" calendar, returns the inner
     /// `ReformGap` field.
     const fn gap(&self) -> Option<inner::ReformGap> {
-        match self.0 {
-            inner::Calendar::Reforming { gap, .. } => Some(gap),
-            _ => None,
-        }
+        self.0.gap()
     }
 
     /// [Private] Returns the next year after `year`, skipping any skipped
@@ -1326,11 +1921,9 @@ impl MonthShape {
             Headless { min_day, max_day } if min_day <= day && day <= max_day => {
                 Ok(day - min_day + 1)
             }
-            Headless { min_day, .. } if 1 <= day && day < min_day => Err(DateError::SkippedDate {
-                year: self.year,
-                month: self.month,
-                day,
-            }),
+            Headless { min_day, .. } if 1 <= day && day < min_day => {
+                Err(self.calendar.skipped_date(self.year, self.month, day))
+            }
             Headless { min_day, max_day } => Err(DateError::DayOutOfRange {
                 year: self.year,
                 month: self.month,
@@ -1342,11 +1935,9 @@ impl MonthShape {
             Tailless {
                 max_day,
                 natural_max_day,
-            } if (max_day + 1) <= day && day <= natural_max_day => Err(DateError::SkippedDate {
-                year: self.year,
-                month: self.month,
-                day,
-            }),
+            } if (max_day + 1) <= day && day <= natural_max_day => {
+                Err(self.calendar.skipped_date(self.year, self.month, day))
+            }
             Tailless { max_day, .. } => Err(DateError::DayOutOfRange {
                 year: self.year,
                 month: self.month,
@@ -1362,11 +1953,9 @@ impl MonthShape {
                 max_day,
             }),
             Gapped { gap_start, .. } if day < gap_start => Ok(day),
-            Gapped { gap_end, .. } if day <= gap_end => Err(DateError::SkippedDate {
-                year: self.year,
-                month: self.month,
-                day,
-            }),
+            Gapped { gap_end, .. } if day <= gap_end => {
+                Err(self.calendar.skipped_date(self.year, self.month, day))
+            }
             Gapped {
                 gap_start, gap_end, ..
             } => Ok(day - (gap_end - gap_start + 1)),
@@ -1719,17 +2308,18 @@ impl Date {
     pub const fn is_julian(&self) -> bool {
         match self.calendar.0 {
             inner::Calendar::Julian => true,
-            inner::Calendar::Reforming { reformation, .. } => {
+            inner::Calendar::Gregorian | inner::Calendar::Custom(_) => false,
+            inner::Calendar::Reforming { reformation, .. }
+            | inner::Calendar::CustomReforming { reformation, .. } => {
                 self.julian_day_number() < reformation
             }
-            inner::Calendar::Gregorian => false,
         }
     }
 
     /// Returns true if the date is in the Gregorian calendar (a.k.a. "New
     /// Style"), i.e., if [`Date::calendar()`] is either a proleptic Gregorian
-    /// calendar or a "reforming" calendar for which the reformation occurs at
-    /// or before the date in question.
+    /// calendar or a "reforming" Gregorian calendar for which the reformation
+    /// occurs at or before the date in question.
     ///
     /// # Example
     ///
@@ -1746,11 +2336,13 @@ impl Date {
     /// ```
     pub const fn is_gregorian(&self) -> bool {
         match self.calendar.0 {
-            inner::Calendar::Julian => false,
+            inner::Calendar::Gregorian => true,
+            inner::Calendar::Julian
+            | inner::Calendar::Custom(_)
+            | inner::Calendar::CustomReforming { .. } => false,
             inner::Calendar::Reforming { reformation, .. } => {
                 reformation <= self.julian_day_number()
             }
-            inner::Calendar::Gregorian => true,
         }
     }
 
@@ -2100,6 +2692,22 @@ impl Month {
         }
     }
 
+    /// Returns a value that displays the month's name in the given locale's
+    /// language
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, locale::Locale};
+    ///
+    /// let month = Month::August.localized(Locale::French);
+    /// assert_eq!(month.to_string(), "août");
+    /// assert_eq!(format!("{month:#}"), "août");
+    /// ```
+    pub const fn localized(self, locale: Locale) -> Localized<Month> {
+        Localized::new(self, locale)
+    }
+
     /// Returns the number of the month, where January is 1.
     ///
     /// These values are also available as the enumeration discriminants and
@@ -2390,6 +2998,22 @@ impl Weekday {
         }
     }
 
+    /// Returns a value that displays the weekday's name in the given
+    /// locale's language
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Weekday, locale::Locale};
+    ///
+    /// let wd = Weekday::Wednesday.localized(Locale::German);
+    /// assert_eq!(wd.to_string(), "Mittwoch");
+    /// assert_eq!(format!("{wd:#}"), "Mi.");
+    /// ```
+    pub const fn localized(self, locale: Locale) -> Localized<Weekday> {
+        Localized::new(self, locale)
+    }
+
     /// Returns the number of the weekday, where Monday is 1 and Sunday is 7.
     ///
     /// These values are also available as the enumeration discriminants and
@@ -2590,6 +3214,67 @@ impl From<Weekday> for time::Weekday {
     }
 }
 
+/// A fixed offset from UTC, in seconds east of UTC, used to compute local
+/// dates from timestamps without a timezone database
+///
+/// # Example
+///
+/// ```
+/// use julian::{Calendar, UtcOffset};
+///
+/// let est = UtcOffset::new(-5 * 3600).unwrap();
+/// assert_eq!(est.to_string(), "-05:00");
+/// let (date, seconds) = Calendar::GREGORIAN
+///     .at_unix_time_with_offset(1682906621, est)
+///     .unwrap();
+/// assert_eq!(date.to_string(), "2023-04-30");
+/// assert_eq!(seconds, 75821);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UtcOffset(i32);
+
+impl UtcOffset {
+    /// The zero offset, i.e., UTC itself
+    pub const UTC: UtcOffset = UtcOffset(0);
+
+    /// The largest magnitude of a valid offset, in seconds
+    const MAX_SECONDS: i32 = 86399;
+
+    /// Construct a UTC offset from a number of seconds east of UTC
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UtcOffsetError`] if `seconds` is not between -86399 and
+    /// 86399, inclusive.
+    pub const fn new(seconds: i32) -> Result<UtcOffset, UtcOffsetError> {
+        if -UtcOffset::MAX_SECONDS <= seconds && seconds <= UtcOffset::MAX_SECONDS {
+            Ok(UtcOffset(seconds))
+        } else {
+            Err(UtcOffsetError)
+        }
+    }
+
+    /// Returns the offset as a number of seconds east of UTC
+    pub const fn seconds(&self) -> i32 {
+        self.0
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    /// A `UtcOffset` is displayed in the form `+HH:MM` (or `-HH:MM` for
+    /// offsets west of UTC), with a `:SS` suffix appended if the offset is not
+    /// a whole number of minutes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let secs = self.0.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", secs / 3600, secs % 3600 / 60)?;
+        if !secs.is_multiple_of(60) {
+            write!(f, ":{:02}", secs % 60)?;
+        }
+        Ok(())
+    }
+}
+
 /// Converts a [`std::time::SystemTime`] instance to the corresponding Julian
 /// day number, along with a count of seconds since midnight UTC.
 ///
@@ -2602,12 +3287,43 @@ impl From<Weekday> for time::Weekday {
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub fn system2jdn(t: SystemTime) -> Result<(Jdnum, u32), ArithmeticError> {
-    let ts = match t.duration_since(UNIX_EPOCH) {
+    unix2jdn(system2unix(t)?)
+}
+
+/// [Private] Converts a [`std::time::SystemTime`] instance to the
+/// corresponding Unix time in whole seconds.
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if the number of seconds does not fit in an
+/// `i64`.
+#[cfg(feature = "std")]
+fn system2unix(t: SystemTime) -> Result<i64, ArithmeticError> {
+    match t.duration_since(UNIX_EPOCH) {
         Ok(d) => i64::try_from(d.as_secs()),
         Err(e) => i64::try_from(e.duration().as_secs()).map(|i| -i),
     }
+    .map_err(|_| ArithmeticError)
+}
+
+/// Converts a [`std::time::SystemTime`] instance to the corresponding Julian
+/// day number, along with a count of nanoseconds since midnight UTC.
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+/// conversion.  This can only happen if the system time in UTC is before
+/// -5884323-05-15 (-5884202-03-16 O.S.) or after 5874898-06-03 (5874777-10-17
+/// O.S.).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn system2jdn_nanos(t: SystemTime) -> Result<(Jdnum, u64), ArithmeticError> {
+    let ts = match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => i128::try_from(d.as_nanos()),
+        Err(e) => i128::try_from(e.duration().as_nanos()).map(|i| -i),
+    }
     .map_err(|_| ArithmeticError)?;
-    unix2jdn(ts)
+    unix_nanos2jdn(ts)
 }
 
 /// Converts a [Unix time][] to the corresponding Julian day number, along with
@@ -2659,20 +3375,142 @@ pub const fn jdn2unix(jdn: Jdnum) -> i64 {
     ((jdn as i64) - (UNIX_EPOCH_JDN as i64)) * SECONDS_IN_DAY
 }
 
+/// Converts a [Unix time][] in milliseconds to the corresponding Julian day
+/// number, along with a count of nanoseconds since midnight UTC.
+///
+/// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+///
+/// # Example
+///
+/// ```
+/// use julian::unix_millis2jdn;
+///
+/// let (jdn, nanos) = unix_millis2jdn(1682906621123).unwrap();
+/// assert_eq!(jdn, 2460066);
+/// assert_eq!(nanos, 7421123000000);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+/// conversion.  This can only happen if the timestamp is less than
+/// -185753453990400000 or greater than 185331720383999999.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn unix_millis2jdn(unix_millis: i64) -> Result<(Jdnum, u64), ArithmeticError> {
+    let jd = unix_millis.div_euclid(MILLIS_IN_DAY) + (UNIX_EPOCH_JDN as i64);
+    if Jdnum::MIN as i64 <= jd && jd <= Jdnum::MAX as i64 {
+        let jd = jd as Jdnum;
+        let nanos = (unix_millis.rem_euclid(MILLIS_IN_DAY) as u64) * NANOS_IN_MILLI;
+        Ok((jd, nanos))
+    } else {
+        Err(ArithmeticError)
+    }
+}
+
+/// Converts a Julian day number and a count of nanoseconds since midnight UTC
+/// to the corresponding [Unix time][] in milliseconds.  Any fraction of a
+/// millisecond is discarded.
+///
+/// This is the inverse of [`unix_millis2jdn()`].
+///
+/// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+///
+/// # Example
+///
+/// ```
+/// use julian::jdn2unix_millis;
+///
+/// let ts = jdn2unix_millis(2460066, 7421123000000);
+/// assert_eq!(ts, 1682906621123);
+/// ```
+#[allow(clippy::cast_possible_wrap)]
+pub const fn jdn2unix_millis(jdn: Jdnum, nanos: u64) -> i64 {
+    // `u64::MAX / NANOS_IN_MILLI` is less than 2^45, so this cast is lossless.
+    ((jdn as i64) - (UNIX_EPOCH_JDN as i64)) * MILLIS_IN_DAY + ((nanos / NANOS_IN_MILLI) as i64)
+}
+
+/// Converts a [Unix time][] in nanoseconds to the corresponding Julian day
+/// number, along with a count of nanoseconds since midnight UTC.
+///
+/// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+///
+/// # Example
+///
+/// ```
+/// use julian::unix_nanos2jdn;
+///
+/// let (jdn, nanos) = unix_nanos2jdn(1682906621123456789).unwrap();
+/// assert_eq!(jdn, 2460066);
+/// assert_eq!(nanos, 7421123456789);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+/// conversion.  This can only happen if the timestamp is less than
+/// -185753453990400000000000 or greater than 185331720383999999999999.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn unix_nanos2jdn(unix_nanos: i128) -> Result<(Jdnum, u64), ArithmeticError> {
+    let jd = unix_nanos.div_euclid(NANOS_IN_DAY) + (UNIX_EPOCH_JDN as i128);
+    if Jdnum::MIN as i128 <= jd && jd <= Jdnum::MAX as i128 {
+        let jd = jd as Jdnum;
+        let nanos = unix_nanos.rem_euclid(NANOS_IN_DAY) as u64;
+        Ok((jd, nanos))
+    } else {
+        Err(ArithmeticError)
+    }
+}
+
+/// Converts a Julian day number and a count of nanoseconds since midnight UTC
+/// to the corresponding [Unix time][] in nanoseconds.
+///
+/// This is the inverse of [`unix_nanos2jdn()`].
+///
+/// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+///
+/// # Example
+///
+/// ```
+/// use julian::jdn2unix_nanos;
+///
+/// let ts = jdn2unix_nanos(2460066, 7421123456789);
+/// assert_eq!(ts, 1682906621123456789);
+/// ```
+pub const fn jdn2unix_nanos(jdn: Jdnum, nanos: u64) -> i128 {
+    ((jdn as i128) - (UNIX_EPOCH_JDN as i128)) * NANOS_IN_DAY + (nanos as i128)
+}
+
 #[cfg(test)]
 mod tests {
+    mod astro;
     mod at_ordinal_date;
     mod at_ymd;
     mod autogen;
+    mod byzantine;
     mod calendar;
     mod chrono;
+    mod cycles;
     mod date;
+    mod daycount;
+    mod dominical;
+    mod historical;
     mod jdn;
+    mod leap;
+    mod locale;
     mod month;
     mod parse_date;
+    mod parse_long_date;
+    mod perpetual;
     mod reformations;
+    mod roman;
+    mod saka;
+    mod sexagenary;
+    mod spreadsheet;
     mod time_crate;
+    mod timestamp;
+    mod tz;
     mod unix;
+    mod utc_offset;
     mod weekday;
     mod year_kind;
 }
@@ -0,0 +1,596 @@
+//! Month and weekday names in languages other than English
+//!
+//! The [`Display`][core::fmt::Display] and [`FromStr`] implementations of
+//! [`Month`] and [`Weekday`] only use English names.  This module provides a
+//! [`Locale`] type for the languages of the countries whose calendar
+//! reformations are listed in [`ncal`][crate::ncal], along with English and
+//! Latin, which supplies full, abbreviated, and (where the language has one)
+//! genitive month names plus full and abbreviated weekday names.  A month or
+//! weekday can be formatted in a locale with [`Month::localized()`] or
+//! [`Weekday::localized()`].
+//!
+//! Names are parsed case-insensitively, with any diacritics on Latin letters
+//! ignored (so that `"fevrier"` parses as `"février"`) and with the trailing
+//! period of an abbreviation optional.  In Latin, "J" is treated as "I" and
+//! "V" as "U".
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Month, Weekday, locale::Locale};
+//!
+//! assert_eq!(Locale::French.month_name(Month::February), "février");
+//! assert_eq!(Locale::Russian.month_genitive(Month::April), "апреля");
+//! assert_eq!(Locale::German.parse_month("MARZ"), Ok(Month::March));
+//! assert_eq!(Weekday::Sunday.localized(Locale::Swedish).to_string(), "söndag");
+//! assert_eq!(format!("{:#}", Month::May.localized(Locale::Dutch)), "mei");
+//! ```
+use crate::errors::{ParseLocaleError, ParseMonthError, ParseWeekdayError};
+use crate::{Month, Weekday, iter::MonthIter};
+use core::fmt;
+use core::str::FromStr;
+
+/// An enumeration of the languages in which month and weekday names are
+/// available
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Locale {
+    English,
+    Latin,
+    French,
+    German,
+    Russian,
+    Swedish,
+    Dutch,
+    Italian,
+}
+
+static MONTH_NAMES: [[&str; 12]; 8] = [
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "Ianuarius",
+        "Februarius",
+        "Martius",
+        "Aprilis",
+        "Maius",
+        "Iunius",
+        "Iulius",
+        "Augustus",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    [
+        "январь",
+        "февраль",
+        "март",
+        "апрель",
+        "май",
+        "июнь",
+        "июль",
+        "август",
+        "сентябрь",
+        "октябрь",
+        "ноябрь",
+        "декабрь",
+    ],
+    [
+        "januari",
+        "februari",
+        "mars",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "augusti",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+];
+
+static MONTH_SHORT_NAMES: [[&str; 12]; 8] = [
+    [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    [
+        "Ian.", "Feb.", "Mart.", "Apr.", "Mai.", "Iun.", "Iul.", "Aug.", "Sept.", "Oct.", "Nov.",
+        "Dec.",
+    ],
+    [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    [
+        "янв.",
+        "февр.",
+        "март",
+        "апр.",
+        "май",
+        "июнь",
+        "июль",
+        "авг.",
+        "сент.",
+        "окт.",
+        "нояб.",
+        "дек.",
+    ],
+    [
+        "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+        "dec.",
+    ],
+    [
+        "jan.", "feb.", "mrt.", "apr.", "mei", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+        "dec.",
+    ],
+    [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+    ],
+];
+
+/// Genitive month names for the locales that have them: Latin, then Russian
+static MONTH_GENITIVES: [[&str; 12]; 2] = [
+    [
+        "Ianuarii",
+        "Februarii",
+        "Martii",
+        "Aprilis",
+        "Maii",
+        "Iunii",
+        "Iulii",
+        "Augusti",
+        "Septembris",
+        "Octobris",
+        "Novembris",
+        "Decembris",
+    ],
+    [
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ],
+];
+
+static WEEKDAY_NAMES: [[&str; 7]; 8] = [
+    [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    [
+        "dies Lunae",
+        "dies Martis",
+        "dies Mercurii",
+        "dies Iovis",
+        "dies Veneris",
+        "dies Saturni",
+        "dies Solis",
+    ],
+    [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    [
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+        "воскресенье",
+    ],
+    [
+        "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+    ],
+    [
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+        "zondag",
+    ],
+    [
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+        "domenica",
+    ],
+];
+
+static WEEKDAY_SHORT_NAMES: [[&str; 7]; 8] = [
+    ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    ["Lun.", "Mart.", "Merc.", "Iov.", "Ven.", "Sat.", "Sol."],
+    ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+    ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+    ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+    ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+];
+
+impl Locale {
+    /// All locales, in the order in which they are tried by
+    /// [`parse_month()`] and [`parse_weekday()`]
+    pub const ALL: [Locale; 8] = [
+        Locale::English,
+        Locale::Latin,
+        Locale::French,
+        Locale::German,
+        Locale::Russian,
+        Locale::Swedish,
+        Locale::Dutch,
+        Locale::Italian,
+    ];
+
+    /// Returns the ISO 639-1 code for the locale's language
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Latin => "la",
+            Locale::French => "fr",
+            Locale::German => "de",
+            Locale::Russian => "ru",
+            Locale::Swedish => "sv",
+            Locale::Dutch => "nl",
+            Locale::Italian => "it",
+        }
+    }
+
+    /// Returns the English name of the locale's language
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Latin => "Latin",
+            Locale::French => "French",
+            Locale::German => "German",
+            Locale::Russian => "Russian",
+            Locale::Swedish => "Swedish",
+            Locale::Dutch => "Dutch",
+            Locale::Italian => "Italian",
+        }
+    }
+
+    /// Returns the name of the given month in the locale's language, in the
+    /// nominative case and capitalized as it would be in running text
+    pub const fn month_name(&self, month: Month) -> &'static str {
+        MONTH_NAMES[*self as usize][month.number0() as usize]
+    }
+
+    /// Returns the conventional abbreviation of the given month in the
+    /// locale's language.  Abbreviations include a trailing period where
+    /// the language uses one, and short month names that are not abbreviated
+    /// are returned in full.
+    pub const fn month_short_name(&self, month: Month) -> &'static str {
+        MONTH_SHORT_NAMES[*self as usize][month.number0() as usize]
+    }
+
+    /// Returns the name of the given month in the genitive case, as used in
+    /// dates such as "30 апреля" or "die 30 Aprilis".  For languages without
+    /// a distinct genitive, this is the same as [`Locale::month_name()`].
+    pub const fn month_genitive(&self, month: Month) -> &'static str {
+        let index = month.number0() as usize;
+        match self {
+            Locale::Latin => MONTH_GENITIVES[0][index],
+            Locale::Russian => MONTH_GENITIVES[1][index],
+            _ => self.month_name(month),
+        }
+    }
+
+    /// Returns the name of the given weekday in the locale's language
+    pub const fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        WEEKDAY_NAMES[*self as usize][weekday.number0() as usize]
+    }
+
+    /// Returns the conventional abbreviation of the given weekday in the
+    /// locale's language
+    pub const fn weekday_short_name(&self, weekday: Weekday) -> &'static str {
+        WEEKDAY_SHORT_NAMES[*self as usize][weekday.number0() as usize]
+    }
+
+    /// Parses a month from its full, abbreviated, or genitive name in the
+    /// locale's language.  Input is treated case-insensitively, diacritics
+    /// are ignored, and the trailing period of an abbreviation is optional.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseMonthError`] if the string is not the name of a month
+    /// in the locale's language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, locale::Locale};
+    ///
+    /// assert_eq!(Locale::French.parse_month("Fevr"), Ok(Month::February));
+    /// assert_eq!(Locale::Latin.parse_month("Januarii"), Ok(Month::January));
+    /// assert!(Locale::English.parse_month("janvier").is_err());
+    /// ```
+    pub fn parse_month(&self, s: &str) -> Result<Month, ParseMonthError> {
+        MonthIter::new()
+            .find(|&m| {
+                self.matches(s, self.month_name(m))
+                    || self.matches(s, self.month_short_name(m))
+                    || self.matches(s, self.month_genitive(m))
+            })
+            .ok_or(ParseMonthError)
+    }
+
+    /// Parses a weekday from its full or abbreviated name in the locale's
+    /// language.  Input is treated case-insensitively, diacritics are
+    /// ignored, and the trailing period of an abbreviation is optional.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseWeekdayError`] if the string is not the name of a
+    /// weekday in the locale's language.
+    pub fn parse_weekday(&self, s: &str) -> Result<Weekday, ParseWeekdayError> {
+        WEEKDAYS
+            .into_iter()
+            .find(|&wd| {
+                self.matches(s, self.weekday_name(wd))
+                    || self.matches(s, self.weekday_short_name(wd))
+            })
+            .ok_or(ParseWeekdayError)
+    }
+
+    /// [Private] Returns true if `input` equals `name` after folding case &
+    /// diacritics and removing any trailing period from each
+    fn matches(&self, input: &str, name: &str) -> bool {
+        let input = input.strip_suffix('.').unwrap_or(input);
+        let name = name.strip_suffix('.').unwrap_or(name);
+        !input.is_empty() && self.fold(input).eq(self.fold(name))
+    }
+
+    /// [Private] Returns an iterator over the characters of `s` with case and
+    /// diacritics removed
+    fn fold<'a>(&self, s: &'a str) -> impl Iterator<Item = char> + 'a {
+        let latin = matches!(self, Locale::Latin);
+        s.chars()
+            .flat_map(char::to_lowercase)
+            .map(move |c| match fold_diacritic(c) {
+                'j' if latin => 'i',
+                'v' if latin => 'u',
+                c => c,
+            })
+    }
+}
+
+impl fmt::Display for Locale {
+    /// A `Locale` is displayed as its ISO 639-1 language code
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    /// Parses a locale from its ISO 639-1 language code or from the English
+    /// name of its language.  Input is treated case-insensitively.
+    fn from_str(s: &str) -> Result<Locale, ParseLocaleError> {
+        Locale::ALL
+            .into_iter()
+            .find(|loc| s.eq_ignore_ascii_case(loc.code()) || s.eq_ignore_ascii_case(loc.name()))
+            .ok_or(ParseLocaleError)
+    }
+}
+
+/// [Private] The weekdays, in order from Monday
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+/// [Private] Returns the given lowercase letter with any diacritic removed
+const fn fold_diacritic(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ё' => 'е',
+        c => c,
+    }
+}
+
+/// Parses a month from its full, abbreviated, or genitive name in any
+/// supported language, trying each locale in the order of [`Locale::ALL`].
+/// Returns the month along with the locale whose name matched.
+///
+/// # Errors
+///
+/// Returns [`ParseMonthError`] if the string is not the name of a month in
+/// any supported language.
+///
+/// # Example
+///
+/// ```
+/// use julian::{Month, locale::{Locale, parse_month}};
+///
+/// assert_eq!(parse_month("août"), Ok((Month::August, Locale::French)));
+/// assert_eq!(parse_month("Okt"), Ok((Month::October, Locale::German)));
+/// assert_eq!(parse_month("сентября"), Ok((Month::September, Locale::Russian)));
+/// ```
+pub fn parse_month(s: &str) -> Result<(Month, Locale), ParseMonthError> {
+    Locale::ALL
+        .into_iter()
+        .find_map(|loc| loc.parse_month(s).ok().map(|m| (m, loc)))
+        .ok_or(ParseMonthError)
+}
+
+/// Parses a weekday from its full or abbreviated name in any supported
+/// language, trying each locale in the order of [`Locale::ALL`].  Returns the
+/// weekday along with the locale whose name matched.
+///
+/// # Errors
+///
+/// Returns [`ParseWeekdayError`] if the string is not the name of a weekday
+/// in any supported language.
+pub fn parse_weekday(s: &str) -> Result<(Weekday, Locale), ParseWeekdayError> {
+    Locale::ALL
+        .into_iter()
+        .find_map(|loc| loc.parse_weekday(s).ok().map(|wd| (wd, loc)))
+        .ok_or(ParseWeekdayError)
+}
+
+/// A [`Month`] or [`Weekday`] paired with a [`Locale`] in which to display it.
+///
+/// A `Localized` value is displayed as the full name of the month or weekday
+/// in the locale's language by default.  Selecting the alternate form with
+/// `{:#}` instead produces the abbreviated name.
+///
+/// Values of this type are returned by [`Month::localized()`] and
+/// [`Weekday::localized()`].
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Localized<T> {
+    value: T,
+    locale: Locale,
+}
+
+impl<T> Localized<T> {
+    /// [Private] Pair a value with a locale
+    pub(crate) const fn new(value: T, locale: Locale) -> Localized<T> {
+        Localized { value, locale }
+    }
+
+    /// Returns the locale in which the value is displayed
+    pub const fn locale(&self) -> Locale {
+        self.locale
+    }
+}
+
+impl<T: Copy> Localized<T> {
+    /// Returns the month or weekday being displayed
+    pub const fn value(&self) -> T {
+        self.value
+    }
+}
+
+impl fmt::Display for Localized<Month> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.locale.month_short_name(self.value))
+        } else {
+            f.pad(self.locale.month_name(self.value))
+        }
+    }
+}
+
+impl fmt::Display for Localized<Weekday> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.locale.weekday_short_name(self.value))
+        } else {
+            f.pad(self.locale.weekday_name(self.value))
+        }
+    }
+}
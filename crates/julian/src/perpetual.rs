@@ -0,0 +1,242 @@
+//! Year types for perpetual calendars
+//!
+//! The layout of a year's calendar — which weekday each date falls on — is
+//! determined by the weekday of January 1 and by whether the year is a leap
+//! year, and so there are fourteen possible layouts for full-length years of
+//! the Julian and Gregorian calendars.  A [perpetual calendar][pc] prints each
+//! of these fourteen calendars once along with a table of which years use
+//! which one, and a wall calendar for one year can be reused in any later year
+//! of the same type.
+//!
+//! A [`YearType`] identifies the layout of a year in a [`Calendar`].  Years
+//! affected by a calendar reformation have one-off types that also record
+//! which days were skipped; such a type is only shared by the same reformation
+//! year in another calendar with the same reformation.  Because a `YearType`
+//! does not record the calendar it came from, types can be compared across
+//! calendars.
+//!
+//! [pc]: https://en.wikipedia.org/wiki/Perpetual_calendar
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Weekday, perpetual::{YearType, next_same_calendar}};
+//!
+//! let ty = YearType::for_year(Calendar::GREGORIAN, 2023).unwrap();
+//! assert_eq!(ty.weekday(), Weekday::Sunday);
+//! assert!(!ty.is_leap());
+//! assert_eq!(ty.number(), Some(1));
+//!
+//! // A 2023 calendar can be reused in 2034:
+//! assert_eq!(next_same_calendar(Calendar::GREGORIAN, 2023), Some(2034));
+//!
+//! // … and it could have been used in 2018 of the Julian calendar:
+//! assert_eq!(ty.prev_in(Calendar::JULIAN, 2023), Some(2018));
+//! ```
+use crate::{Calendar, Month, Weekday, YearKind, iter::MonthIter};
+
+/// The number of periods of a leap rule after which the weekdays of a
+/// calendar following the rule repeat
+const WEEKDAY_PERIODS: i64 = 7;
+
+/// The layout of a year in a calendar: the weekday of its first day, its
+/// [`YearKind`], and, for years affected by a calendar reformation, the days
+/// that were skipped
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct YearType {
+    weekday: Weekday,
+    kind: YearKind,
+    gap: Option<SkippedDays>,
+}
+
+/// [Private] The first and last month & day skipped by a calendar
+/// reformation within a year
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+struct SkippedDays {
+    start: (Month, u32),
+    end: (Month, u32),
+}
+
+impl YearType {
+    /// Returns the type of the given year in the given calendar.
+    ///
+    /// Returns `None` if the year was skipped entirely by a calendar
+    /// reformation or if the first day of the year is outside the range of
+    /// [`Date`][crate::Date].
+    pub fn for_year(calendar: Calendar, year: i32) -> Option<YearType> {
+        let kind = calendar.year_kind(year);
+        if matches!(kind, YearKind::Skipped) {
+            return None;
+        }
+        let weekday = calendar.at_ordinal_date(year, 1).ok()?.weekday();
+        let gap = if kind.is_reform() {
+            skipped_days(calendar, year)
+        } else {
+            None
+        };
+        Some(YearType { weekday, kind, gap })
+    }
+
+    /// Returns the weekday of the first day of the year.  This is the weekday
+    /// of January 1 unless January 1 was skipped by a calendar reformation.
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the [`YearKind`] of the year
+    pub const fn kind(&self) -> YearKind {
+        self.kind
+    }
+
+    /// Returns true if the year contains February 29
+    pub const fn is_leap(&self) -> bool {
+        self.kind.is_leap()
+    }
+
+    /// Returns true if the year was affected by a calendar reformation
+    pub const fn is_reform(&self) -> bool {
+        self.kind.is_reform()
+    }
+
+    /// Returns the traditional number (from 1 through 14) of the year type in
+    /// a perpetual calendar, or `None` if the year was affected by a calendar
+    /// reformation.
+    ///
+    /// Common years beginning on Sunday through Saturday are numbered 1
+    /// through 7, and leap years beginning on Sunday through Saturday are
+    /// numbered 8 through 14.
+    pub const fn number(&self) -> Option<u32> {
+        let base = self.weekday.number() % 7 + 1;
+        match self.kind {
+            YearKind::Common => Some(base),
+            YearKind::Leap => Some(base + 7),
+            _ => None,
+        }
+    }
+
+    /// Returns the first year after `year` in the given calendar that has
+    /// this type, or `None` if there is no such year
+    ///
+    /// The search examines years one at a time and only gives up after
+    /// covering seven full cycles of the calendar's leap rule, so, for a
+    /// calendar with a custom leap rule with a long cycle, this can take time
+    /// proportional to that cycle length (e.g., tens of thousands of years
+    /// for Herschel's 4000-year rule) when no matching year exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, perpetual::YearType};
+    ///
+    /// let ty = YearType::for_year(Calendar::GREGORIAN, 2024).unwrap();
+    /// assert_eq!(ty.next_in(Calendar::GREGORIAN, 2024), Some(2052));
+    /// assert_eq!(ty.next_in(Calendar::JULIAN, 2024), Some(2036));
+    /// ```
+    pub fn next_in(&self, calendar: Calendar, year: i32) -> Option<i32> {
+        let limit = self.search_limit(calendar, year, true);
+        (year.checked_add(1)?..=limit).find(|&y| self.matches(calendar, y))
+    }
+
+    /// Returns the last year before `year` in the given calendar that has
+    /// this type, or `None` if there is no such year
+    ///
+    /// As with [`YearType::next_in()`], this can be slow for calendars with
+    /// custom leap rules that have long cycles.
+    pub fn prev_in(&self, calendar: Calendar, year: i32) -> Option<i32> {
+        let limit = self.search_limit(calendar, year, false);
+        (limit..=year.checked_sub(1)?)
+            .rev()
+            .find(|&y| self.matches(calendar, y))
+    }
+
+    /// [Private] Returns true if the given year of the given calendar has this
+    /// type
+    fn matches(&self, calendar: Calendar, year: i32) -> bool {
+        calendar.year_kind(year) == self.kind && YearType::for_year(calendar, year) == Some(*self)
+    }
+
+    /// [Private] Returns the farthest year from `year` in the given direction
+    /// that needs to be examined when searching for a year of this type.
+    ///
+    /// The weekdays of a calendar repeat after seven periods of its leap rule,
+    /// so, once any reformation years have been passed, searching further
+    /// than that will not find anything new.
+    fn search_limit(&self, calendar: Calendar, year: i32, forwards: bool) -> i32 {
+        let mut start = i64::from(year);
+        let mut span = WEEKDAY_PERIODS * calendar.leap_rule().period().max(4);
+        if let Some(last_julian) = calendar.last_julian_date() {
+            let reform_years = [
+                i64::from(last_julian.year()),
+                i64::from(last_julian.year()) + 1,
+            ];
+            if forwards {
+                start = start.max(reform_years[1]);
+            } else {
+                start = start.min(reform_years[0]);
+                // Before the reformation, the calendar is Julian:
+                span = WEEKDAY_PERIODS * 4;
+            }
+        }
+        let limit = if forwards {
+            start.saturating_add(span)
+        } else {
+            start.saturating_sub(span)
+        };
+        i32::try_from(limit.clamp(i64::from(i32::MIN), i64::from(i32::MAX)))
+            .expect("clamped value should fit in i32")
+    }
+}
+
+/// [Private] Returns the first and last days skipped by a calendar
+/// reformation within the given year, if any
+fn skipped_days(calendar: Calendar, year: i32) -> Option<SkippedDays> {
+    let mut start = None;
+    let mut end = None;
+    for month in MonthIter::new() {
+        // A month with no shape was skipped entirely.
+        let skipped = calendar
+            .month_shape(year, month)
+            .map_or(Some((1, 31)), |shape| {
+                shape.gap().map(|gap| (*gap.start(), *gap.end()))
+            });
+        if let Some((first, last)) = skipped {
+            start.get_or_insert((month, first));
+            end = Some((month, last));
+        }
+    }
+    Some(SkippedDays {
+        start: start?,
+        end: end?,
+    })
+}
+
+/// Returns the first year after `year` in the given calendar whose layout is
+/// identical to that of `year`, or `None` if there is no such year
+///
+/// # Example
+///
+/// ```
+/// use julian::{Calendar, perpetual::next_same_calendar};
+///
+/// assert_eq!(next_same_calendar(Calendar::GREGORIAN, 2025), Some(2031));
+/// assert_eq!(next_same_calendar(Calendar::GREGORIAN, 2096), Some(2108));
+/// assert_eq!(next_same_calendar(Calendar::REFORM1582, 1582), None);
+/// ```
+pub fn next_same_calendar(calendar: Calendar, year: i32) -> Option<i32> {
+    YearType::for_year(calendar, year)?.next_in(calendar, year)
+}
+
+/// Returns the last year before `year` in the given calendar whose layout is
+/// identical to that of `year`, or `None` if there is no such year
+///
+/// # Example
+///
+/// ```
+/// use julian::{Calendar, perpetual::prev_same_calendar};
+///
+/// assert_eq!(prev_same_calendar(Calendar::GREGORIAN, 2025), Some(2014));
+/// assert_eq!(prev_same_calendar(Calendar::JULIAN, 2025), Some(2014));
+/// ```
+pub fn prev_same_calendar(calendar: Calendar, year: i32) -> Option<i32> {
+    YearType::for_year(calendar, year)?.prev_in(calendar, year)
+}
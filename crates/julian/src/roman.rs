@@ -0,0 +1,588 @@
+//! Roman date notation
+//!
+//! Latin sources from classical antiquity through the early modern period
+//! identify the days of the Julian calendar by counting backwards, inclusively,
+//! to the next of three fixed days of each month: the Kalends (the 1st), the
+//! Nones (the 7th in March, May, July, and October and the 5th in other
+//! months), and the Ides (the 15th in March, May, July, and October and the
+//! 13th in other months).  Thus, October 5 is *ante diem tertium Nonas
+//! Octobres* ("the third day before the Nones of October," abbreviated *a.d.
+//! III Non. Oct.*), the day before a fixed day is *pridie*, and days after the
+//! Ides count down to the Kalends of the following month.
+//!
+//! In a leap year, the extra day is inserted by doubling the sixth day before
+//! the Kalends of March: February 24 becomes *ante diem bis sextum Kalendas
+//! Martias*, and February 25 through 29 are numbered as February 24 through
+//! 28 are in a common year.  This doubled day gives the *bissextile* year its
+//! name.
+//!
+//! A [`RomanDate`] does not include a year; it is converted to a [`Date`] by
+//! supplying a calendar & the year in which the day falls, so that (for
+//! example) *a.d. IV Kal. Ian.* in 1700 is December 29, 1700.  Dates can be
+//! formatted in abbreviated form (the default) or, using the alternate
+//! formatting flag `{:#}`, in full Latin.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, roman::RomanDate};
+//!
+//! let date = Calendar::JULIAN.at_ymd(1582, Month::October, 5).unwrap();
+//! let roman = RomanDate::for_date(&date);
+//! assert_eq!(roman.to_string(), "a.d. III Non. Oct.");
+//! assert_eq!(format!("{roman:#}"), "ante diem tertium Nonas Octobres");
+//!
+//! let leap_day = Calendar::JULIAN.at_ymd(1580, Month::February, 24).unwrap();
+//! assert_eq!(RomanDate::for_date(&leap_day).to_string(), "a.d. bis VI Kal. Mart.");
+//!
+//! let roman = "Idibus Martiis".parse::<RomanDate>().unwrap();
+//! let date = roman.to_date(Calendar::JULIAN, -43).unwrap();
+//! assert_eq!(date.month(), Month::March);
+//! assert_eq!(date.day(), 15);
+//! ```
+use crate::errors::{ParseRomanDateError, RomanDateError};
+use crate::{Calendar, Date, Month, iter::MonthIter};
+use core::fmt;
+use core::str::FromStr;
+
+/// The Roman numerals for the day counts from 3 through 19, indexed by count
+static NUMERALS: [&str; 20] = [
+    "", "", "", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "XIII", "XIV", "XV",
+    "XVI", "XVII", "XVIII", "XIX",
+];
+
+/// The accusative Latin ordinals for the day counts from 3 through 19,
+/// indexed by count
+static ORDINALS: [&str; 20] = [
+    "",
+    "",
+    "",
+    "tertium",
+    "quartum",
+    "quintum",
+    "sextum",
+    "septimum",
+    "octavum",
+    "nonum",
+    "decimum",
+    "undecimum",
+    "duodecimum",
+    "tertium decimum",
+    "quartum decimum",
+    "quintum decimum",
+    "sextum decimum",
+    "septimum decimum",
+    "duodevicesimum",
+    "undevicesimum",
+];
+
+/// The count of the bissextile day before the Kalends of March
+const BISSEXTILE_COUNT: u32 = 6;
+
+/// An enumeration of the three fixed days of a Roman month from which other
+/// days are counted
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Reference {
+    /// The Kalends, the first day of the month
+    Kalends,
+
+    /// The Nones, the 7th of March, May, July, and October and the 5th of
+    /// other months
+    Nones,
+
+    /// The Ides, the 15th of March, May, July, and October and the 13th of
+    /// other months
+    Ides,
+}
+
+impl Reference {
+    /// Returns the English name of the reference day
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Reference::Kalends => "Kalends",
+            Reference::Nones => "Nones",
+            Reference::Ides => "Ides",
+        }
+    }
+
+    /// Returns the day of the month on which the reference day falls in the
+    /// given month
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, roman::Reference};
+    ///
+    /// assert_eq!(Reference::Ides.day_in(Month::March), 15);
+    /// assert_eq!(Reference::Ides.day_in(Month::April), 13);
+    /// ```
+    pub const fn day_in(&self, month: Month) -> u32 {
+        let late = matches!(
+            month,
+            Month::March | Month::May | Month::July | Month::October
+        );
+        match (self, late) {
+            (Reference::Kalends, _) => 1,
+            (Reference::Nones, false) => 5,
+            (Reference::Nones, true) => 7,
+            (Reference::Ides, false) => 13,
+            (Reference::Ides, true) => 15,
+        }
+    }
+
+    /// [Private] Returns the abbreviated Latin name of the reference day
+    const fn abbreviation(self) -> &'static str {
+        match self {
+            Reference::Kalends => "Kal.",
+            Reference::Nones => "Non.",
+            Reference::Ides => "Id.",
+        }
+    }
+
+    /// [Private] Returns the Latin name of the reference day in the
+    /// accusative case, as used after *ante diem* and *pridie*
+    const fn accusative(self) -> &'static str {
+        match self {
+            Reference::Kalends => "Kalendas",
+            Reference::Nones => "Nonas",
+            Reference::Ides => "Idus",
+        }
+    }
+
+    /// [Private] Returns the Latin name of the reference day in the ablative
+    /// case, as used for the reference day itself
+    const fn ablative(self) -> &'static str {
+        match self {
+            Reference::Kalends => "Kalendis",
+            Reference::Nones => "Nonis",
+            Reference::Ides => "Idibus",
+        }
+    }
+
+    /// [Private] Parses a reference day from any of its Latin forms
+    fn from_latin(s: &str) -> Option<Reference> {
+        [Reference::Kalends, Reference::Nones, Reference::Ides]
+            .into_iter()
+            .find(|r| {
+                [r.abbreviation(), r.accusative(), r.ablative()]
+                    .into_iter()
+                    .any(|form| latin_eq(s, form))
+            })
+    }
+}
+
+impl fmt::Display for Reference {
+    /// A `Reference` is displayed as its English name
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// A day of the month identified in the Roman manner, by counting inclusively
+/// backwards to a [`Reference`] day
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct RomanDate {
+    reference: Reference,
+    month: Month,
+    count: u32,
+    bissextile: bool,
+}
+
+impl RomanDate {
+    /// The bissextile day, *ante diem bis sextum Kalendas Martias*, which
+    /// occurs only in leap years
+    pub const BISSEXTILE: RomanDate = RomanDate {
+        reference: Reference::Kalends,
+        month: Month::March,
+        count: BISSEXTILE_COUNT,
+        bissextile: true,
+    };
+
+    /// Construct a `RomanDate` for the day `count` days before the given
+    /// reference day of the given month, counted inclusively, so that a count
+    /// of 1 denotes the reference day itself and a count of 2 denotes the day
+    /// before (*pridie*).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RomanDateError::CountOutOfRange`] if `count` is zero or if
+    /// counting back that many days would pass the preceding reference day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Month, roman::{Reference, RomanDate}};
+    ///
+    /// let roman = RomanDate::new(Reference::Kalends, Month::January, 4).unwrap();
+    /// assert_eq!(roman.to_string(), "a.d. IV Kal. Ian.");
+    /// assert!(RomanDate::new(Reference::Ides, Month::March, 9).is_err());
+    /// ```
+    pub const fn new(
+        reference: Reference,
+        month: Month,
+        count: u32,
+    ) -> Result<RomanDate, RomanDateError> {
+        let max = max_count(reference, month);
+        if count == 0 || count > max {
+            return Err(RomanDateError::CountOutOfRange {
+                reference,
+                month,
+                count,
+                max,
+            });
+        }
+        Ok(RomanDate {
+            reference,
+            month,
+            count,
+            bissextile: false,
+        })
+    }
+
+    /// Returns the Roman form of the given date.
+    ///
+    /// In leap years, February 24 is the bissextile day, and February 25
+    /// through 29 are given the forms that February 24 through 28 have in
+    /// common years.  Whether a year is a leap year is determined by the
+    /// date's calendar.
+    pub const fn for_date(date: &Date) -> RomanDate {
+        let month = date.month();
+        let day = date.day();
+        let nones = Reference::Nones.day_in(month);
+        let ides = Reference::Ides.day_in(month);
+        let (reference, ref_month, count) = if day == 1 {
+            (Reference::Kalends, month, 1)
+        } else if day <= nones {
+            (Reference::Nones, month, nones - day + 1)
+        } else if day <= ides {
+            (Reference::Ides, month, ides - day + 1)
+        } else {
+            let next = match month.succ() {
+                Some(m) => m,
+                None => Month::January,
+            };
+            let mut length = common_month_length(month);
+            if matches!(month, Month::February) && date.calendar().year_kind(date.year()).is_leap()
+            {
+                if day == 24 {
+                    return RomanDate::BISSEXTILE;
+                } else if day > 24 {
+                    length += 1;
+                }
+            }
+            (Reference::Kalends, next, length - day + 2)
+        };
+        RomanDate {
+            reference,
+            month: ref_month,
+            count,
+            bissextile: false,
+        }
+    }
+
+    /// Returns the date in the given calendar on which this Roman day falls
+    /// in the given year.  Days counted back to the Kalends of January fall
+    /// in December of `year`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RomanDateError::NotLeapYear`] if `self` is the bissextile day
+    /// and the year is not a leap year in the calendar.
+    ///
+    /// Returns [`RomanDateError::InvalidDate`] if the date was skipped by a
+    /// calendar reformation or if the date is outside the range of [`Date`].
+    pub const fn to_date(&self, calendar: Calendar, year: i32) -> Result<Date, RomanDateError> {
+        let (month, day) = if self.count == 1 {
+            (self.month, self.reference.day_in(self.month))
+        } else if matches!(self.reference, Reference::Kalends) {
+            let prev = match self.month.pred() {
+                Some(m) => m,
+                None => Month::December,
+            };
+            let mut length = common_month_length(prev);
+            if matches!(prev, Month::February) && calendar.year_kind(year).is_leap() {
+                if self.bissextile {
+                    return match calendar.at_ymd(year, prev, 24) {
+                        Ok(date) => Ok(date),
+                        Err(e) => Err(RomanDateError::InvalidDate(e)),
+                    };
+                } else if self.count < BISSEXTILE_COUNT + 1 {
+                    length += 1;
+                }
+            } else if self.bissextile {
+                return Err(RomanDateError::NotLeapYear { year });
+            }
+            (prev, length + 2 - self.count)
+        } else {
+            (
+                self.month,
+                self.reference.day_in(self.month) + 1 - self.count,
+            )
+        };
+        match calendar.at_ymd(year, month, day) {
+            Ok(date) => Ok(date),
+            Err(e) => Err(RomanDateError::InvalidDate(e)),
+        }
+    }
+
+    /// Returns the reference day counted back to
+    pub const fn reference(&self) -> Reference {
+        self.reference
+    }
+
+    /// Returns the month of the reference day
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the inclusive count of days back to the reference day, where 1
+    /// is the reference day itself and 2 is the day before.  The bissextile
+    /// day has a count of 6.
+    pub const fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns true if this is the bissextile day
+    pub const fn is_bissextile(&self) -> bool {
+        self.bissextile
+    }
+}
+
+impl fmt::Display for RomanDate {
+    /// A `RomanDate` is displayed in abbreviated form by default (e.g.,
+    /// "a.d. III Non. Oct.").  Selecting the alternate form with `{:#}`
+    /// instead produces the full Latin form (e.g., "ante diem tertium Nonas
+    /// Octobres").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let month = LatinMonth(self.month);
+        if f.alternate() {
+            match self.count {
+                1 => write!(f, "{} {}", self.reference.ablative(), month.ablative()),
+                2 => write!(
+                    f,
+                    "pridie {} {}",
+                    self.reference.accusative(),
+                    month.accusative()
+                ),
+                n => write!(
+                    f,
+                    "ante diem {}{} {} {}",
+                    if self.bissextile { "bis " } else { "" },
+                    ORDINALS[n as usize],
+                    self.reference.accusative(),
+                    month.accusative()
+                ),
+            }
+        } else {
+            match self.count {
+                1 => write!(
+                    f,
+                    "{} {}",
+                    self.reference.abbreviation(),
+                    month.abbreviation()
+                ),
+                2 => write!(
+                    f,
+                    "prid. {} {}",
+                    self.reference.abbreviation(),
+                    month.abbreviation()
+                ),
+                n => write!(
+                    f,
+                    "a.d. {}{} {} {}",
+                    if self.bissextile { "bis " } else { "" },
+                    NUMERALS[n as usize],
+                    self.reference.abbreviation(),
+                    month.abbreviation()
+                ),
+            }
+        }
+    }
+}
+
+impl FromStr for RomanDate {
+    type Err = ParseRomanDateError;
+
+    /// Parses a Roman date in either abbreviated form (e.g., "a.d. III Non.
+    /// Oct.") or full Latin form (e.g., "ante diem tertium Nonas Octobres").
+    /// The abbreviated and full forms of the individual words may be mixed,
+    /// and the case endings of the names of the reference day & month are not
+    /// checked.  Input is treated case-insensitively, and "J" is accepted in
+    /// place of "I".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseRomanDateError`] if the string is not a valid Roman date.
+    fn from_str(s: &str) -> Result<RomanDate, ParseRomanDateError> {
+        let mut words = [""; 8];
+        let mut len = 0;
+        for w in s.split_whitespace() {
+            *words.get_mut(len).ok_or(ParseRomanDateError)? = w;
+            len += 1;
+        }
+        let [mid @ .., reference, month] = &words[..len] else {
+            return Err(ParseRomanDateError);
+        };
+        let reference = Reference::from_latin(reference).ok_or(ParseRomanDateError)?;
+        let month = LatinMonth::from_latin(month).ok_or(ParseRomanDateError)?;
+        let (count, bissextile) = match mid {
+            [] => (1, false),
+            [w] if latin_eq(w, "prid.") || latin_eq(w, "pridie") => (2, false),
+            [w, rest @ ..] if latin_eq(w, "a.d.") => parse_count(rest)?,
+            [w1, w2, rest @ ..] if latin_eq(w1, "ante") && latin_eq(w2, "diem") => {
+                parse_count(rest)?
+            }
+            _ => return Err(ParseRomanDateError),
+        };
+        let roman = RomanDate::new(reference, month, count).map_err(|_| ParseRomanDateError)?;
+        if !bissextile {
+            Ok(roman)
+        } else if (reference, month, count) == (Reference::Kalends, Month::March, BISSEXTILE_COUNT)
+        {
+            Ok(RomanDate::BISSEXTILE)
+        } else {
+            Err(ParseRomanDateError)
+        }
+    }
+}
+
+/// [Private] Parses the words after "a.d." or "ante diem" and before the
+/// reference day as an optional "bis" followed by a Roman numeral or a Latin
+/// ordinal, returning the count and whether "bis" was present
+fn parse_count(words: &[&str]) -> Result<(u32, bool), ParseRomanDateError> {
+    let (bissextile, words) = match words {
+        [w, rest @ ..] if latin_eq(w, "bis") => (true, rest),
+        _ => (false, words),
+    };
+    let count = match words {
+        [w] => NUMERALS
+            .iter()
+            .chain(ORDINALS.iter())
+            .position(|&n| !n.is_empty() && latin_eq(w, n))
+            .map(|i| i % NUMERALS.len()),
+        [w1, w2] => ORDINALS.iter().position(|&n| {
+            n.split_once(' ')
+                .is_some_and(|(a, b)| latin_eq(w1, a) && latin_eq(w2, b))
+        }),
+        _ => None,
+    };
+    let count = count.ok_or(ParseRomanDateError)?;
+    let count = u32::try_from(count).map_err(|_| ParseRomanDateError)?;
+    // The day before the reference day is "pridie", not "a.d. II":
+    if count < 3 {
+        return Err(ParseRomanDateError);
+    }
+    Ok((count, bissextile))
+}
+
+/// [Private] Returns the largest valid count back to the given reference day
+/// in the given month
+const fn max_count(reference: Reference, month: Month) -> u32 {
+    match reference {
+        Reference::Kalends => {
+            let prev = match month.pred() {
+                Some(m) => m,
+                None => Month::December,
+            };
+            // Count from the day after the Ides of the previous month:
+            common_month_length(prev) - Reference::Ides.day_in(prev) + 1
+        }
+        Reference::Nones => Reference::Nones.day_in(month) - 1,
+        Reference::Ides => Reference::Ides.day_in(month) - Reference::Nones.day_in(month),
+    }
+}
+
+/// [Private] Returns the length of the given month in a common year
+const fn common_month_length(month: Month) -> u32 {
+    match month {
+        Month::February => 28,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        _ => 31,
+    }
+}
+
+/// [Private] Compares a word of input to a Latin word ASCII-case-insensitively,
+/// treating "J" as "I"
+fn latin_eq(input: &str, latin: &str) -> bool {
+    let normalize = |c: u8| match c.to_ascii_lowercase() {
+        b'j' => b'i',
+        c => c,
+    };
+    input.len() == latin.len()
+        && input
+            .bytes()
+            .zip(latin.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+/// [Private] The Latin forms of a month's name, which is an adjective
+/// agreeing with the name of the reference day
+struct LatinMonth(Month);
+
+impl LatinMonth {
+    /// [Private] Returns the standard abbreviation of the month's Latin name
+    const fn abbreviation(&self) -> &'static str {
+        match self.0 {
+            Month::January => "Ian.",
+            Month::February => "Feb.",
+            Month::March => "Mart.",
+            Month::April => "Apr.",
+            Month::May => "Mai.",
+            Month::June => "Iun.",
+            Month::July => "Iul.",
+            Month::August => "Aug.",
+            Month::September => "Sept.",
+            Month::October => "Oct.",
+            Month::November => "Nov.",
+            Month::December => "Dec.",
+        }
+    }
+
+    /// [Private] Returns the feminine accusative plural of the month's Latin
+    /// name
+    const fn accusative(&self) -> &'static str {
+        match self.0 {
+            Month::January => "Ianuarias",
+            Month::February => "Februarias",
+            Month::March => "Martias",
+            Month::April => "Apriles",
+            Month::May => "Maias",
+            Month::June => "Iunias",
+            Month::July => "Iulias",
+            Month::August => "Augustas",
+            Month::September => "Septembres",
+            Month::October => "Octobres",
+            Month::November => "Novembres",
+            Month::December => "Decembres",
+        }
+    }
+
+    /// [Private] Returns the feminine ablative plural of the month's Latin
+    /// name
+    const fn ablative(&self) -> &'static str {
+        match self.0 {
+            Month::January => "Ianuariis",
+            Month::February => "Februariis",
+            Month::March => "Martiis",
+            Month::April => "Aprilibus",
+            Month::May => "Maiis",
+            Month::June => "Iuniis",
+            Month::July => "Iuliis",
+            Month::August => "Augustis",
+            Month::September => "Septembribus",
+            Month::October => "Octobribus",
+            Month::November => "Novembribus",
+            Month::December => "Decembribus",
+        }
+    }
+
+    /// [Private] Parses a month from any of the Latin forms of its name
+    fn from_latin(s: &str) -> Option<Month> {
+        MonthIter::new().find(|&m| {
+            let lm = LatinMonth(m);
+            [lm.abbreviation(), lm.accusative(), lm.ablative()]
+                .into_iter()
+                .any(|form| latin_eq(s, form))
+        })
+    }
+}
@@ -0,0 +1,546 @@
+//! The Indian national calendar (Saka calendar)
+//!
+//! The [Indian national calendar][saka] is the civil calendar of India.  Its
+//! years are counted in the Saka era, which begins 78 years after the start of
+//! the Common Era, and each year begins on March 22 in the Gregorian calendar
+//! (or March 21 in Gregorian leap years).  A Saka year is a leap year if &
+//! only if the Gregorian year in which it begins is a leap year, in which case
+//! the first month, Chaitra, has 31 days instead of 30.
+//!
+//! As with the rest of this crate, years are numbered astronomically, so the
+//! year before Saka year 1 is Saka year 0, which began on 0078-03-22 in the
+//! proleptic Gregorian calendar.
+//!
+//! [saka]: https://en.wikipedia.org/wiki/Indian_national_calendar
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, saka::{SakaDate, SakaMonth}};
+//!
+//! let date = Calendar::GREGORIAN.at_ymd(2023, Month::April, 30).unwrap();
+//! let saka = SakaDate::from_date(&date);
+//! assert_eq!(saka.year(), 1945);
+//! assert_eq!(saka.month(), SakaMonth::Vaishakha);
+//! assert_eq!(saka.day(), 10);
+//! assert_eq!(saka.to_string(), "1945-02-10");
+//! assert_eq!(saka.to_date(Calendar::GREGORIAN), date);
+//! ```
+use crate::errors::{
+    ArithmeticError, ParseDateError, ParseSakaDateError, ParseSakaMonthError, SakaDateError,
+};
+use crate::{COMMON_YEAR_LENGTH, Calendar, Date, Jdnum, LEAP_YEAR_LENGTH, YearKind, inner};
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+/// The number of years by which the Gregorian year in which a Saka year
+/// begins exceeds the Saka year
+const SAKA_ERA_OFFSET: i32 = 78;
+
+/// The day of the (Gregorian) year on which each Saka year begins.  This is
+/// March 22 in common years and March 21 in leap years.
+const NEW_YEAR_ORDINAL: u32 = 81;
+
+/// Returns the [`YearKind`] for the given Saka year, which is always either
+/// [`YearKind::Common`] or [`YearKind::Leap`].
+///
+/// A Saka year is a leap year if & only if the Gregorian year in which it
+/// begins is a leap year.
+///
+/// # Example
+///
+/// ```
+/// use julian::{YearKind, saka};
+///
+/// assert_eq!(saka::year_kind(1945), YearKind::Common);
+/// assert_eq!(saka::year_kind(1946), YearKind::Leap);
+/// assert_eq!(saka::year_kind(1922), YearKind::Leap);
+/// ```
+pub const fn year_kind(year: i32) -> YearKind {
+    // Reduce the year modulo the Gregorian leap cycle first so that adding
+    // the offset doesn't overflow for extreme years
+    let gyear = year.rem_euclid(inner::GREGORIAN_CYCLE_YEARS) + SAKA_ERA_OFFSET;
+    if inner::is_gregorian_leap_year(gyear) {
+        YearKind::Leap
+    } else {
+        YearKind::Common
+    }
+}
+
+/// Returns the number of days in the given Saka year
+///
+/// # Example
+///
+/// ```
+/// use julian::saka;
+///
+/// assert_eq!(saka::year_length(1945), 365);
+/// assert_eq!(saka::year_length(1946), 366);
+/// ```
+#[allow(clippy::cast_sign_loss)]
+pub const fn year_length(year: i32) -> u32 {
+    if year_kind(year).is_leap() {
+        LEAP_YEAR_LENGTH as u32
+    } else {
+        COMMON_YEAR_LENGTH as u32
+    }
+}
+
+/// A date in the Indian national calendar.
+///
+/// Instances of `SakaDate` can be constructed with [`SakaDate::at_ymd()`],
+/// [`SakaDate::at_ordinal_date()`], [`SakaDate::at_jdn()`], or by converting
+/// from a [`Date`] in any calendar.
+///
+/// `SakaDate` instances are ordered by Julian day number.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct SakaDate {
+    year: i32,
+    ordinal: u32,
+    month: SakaMonth,
+    day: u32,
+    jdn: Jdnum,
+}
+
+impl SakaDate {
+    /// Returns the Saka date with the given year, month, and day of month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::saka::{SakaDate, SakaMonth};
+    ///
+    /// let date = SakaDate::at_ymd(1945, SakaMonth::Chaitra, 1).unwrap();
+    /// assert_eq!(date.julian_day_number(), 2460026);
+    ///
+    /// assert!(SakaDate::at_ymd(1945, SakaMonth::Chaitra, 31).is_err());
+    /// assert!(SakaDate::at_ymd(1946, SakaMonth::Chaitra, 31).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SakaDateError::DayOutOfRange`] if `day` is zero or greater
+    /// than the length of the given month in the given year.
+    ///
+    /// Returns [`SakaDateError::Arithmetic`] if numeric overflow/underflow
+    /// occurs while calculating the date's Julian day number.
+    pub const fn at_ymd(year: i32, month: SakaMonth, day: u32) -> Result<SakaDate, SakaDateError> {
+        let max_day = month.length(year_kind(year));
+        if day < 1 || day > max_day {
+            return Err(SakaDateError::DayOutOfRange {
+                year,
+                month,
+                day,
+                max_day,
+            });
+        }
+        let ordinal = month.days_before(year_kind(year)) + day;
+        match jdn_for(year, ordinal) {
+            Ok(jdn) => Ok(SakaDate {
+                year,
+                ordinal,
+                month,
+                day,
+                jdn,
+            }),
+            Err(ArithmeticError) => Err(SakaDateError::Arithmetic),
+        }
+    }
+
+    /// Returns the Saka date with the given year and day-of-year (starting
+    /// counting from 1 at Chaitra 1).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SakaDateError::OrdinalOutOfRange`] if `ordinal` is zero or
+    /// greater than the length of the year.
+    ///
+    /// Returns [`SakaDateError::Arithmetic`] if numeric overflow/underflow
+    /// occurs while calculating the date's Julian day number.
+    pub const fn at_ordinal_date(year: i32, ordinal: u32) -> Result<SakaDate, SakaDateError> {
+        let max_ordinal = year_length(year);
+        if ordinal < 1 || ordinal > max_ordinal {
+            return Err(SakaDateError::OrdinalOutOfRange {
+                year,
+                ordinal,
+                max_ordinal,
+            });
+        }
+        let (month, day) = ordinal2md(year, ordinal);
+        match jdn_for(year, ordinal) {
+            Ok(jdn) => Ok(SakaDate {
+                year,
+                ordinal,
+                month,
+                day,
+                jdn,
+            }),
+            Err(ArithmeticError) => Err(SakaDateError::Arithmetic),
+        }
+    }
+
+    /// Returns the Saka date with the given Julian day number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::saka::{SakaDate, SakaMonth};
+    ///
+    /// let date = SakaDate::at_jdn(2451545);
+    /// assert_eq!(date.year(), 1921);
+    /// assert_eq!(date.month(), SakaMonth::Pausha);
+    /// assert_eq!(date.day(), 11);
+    /// ```
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn at_jdn(jdn: Jdnum) -> SakaDate {
+        let (gyear, gordinal) = inner::jdn2gregorian(jdn);
+        let (year, ordinal) = if gordinal >= NEW_YEAR_ORDINAL {
+            (gyear - SAKA_ERA_OFFSET, gordinal - NEW_YEAR_ORDINAL + 1)
+        } else {
+            let year = gyear - SAKA_ERA_OFFSET - 1;
+            (year, year_length(year) - NEW_YEAR_ORDINAL + 1 + gordinal)
+        };
+        let (month, day) = ordinal2md(year, ordinal);
+        SakaDate {
+            year,
+            ordinal,
+            month,
+            day,
+            jdn,
+        }
+    }
+
+    /// Returns the Saka date on the same day as the given [`Date`]
+    pub const fn from_date(date: &Date) -> SakaDate {
+        SakaDate::at_jdn(date.julian_day_number())
+    }
+
+    /// Returns the [`Date`] in the given calendar on the same day as the Saka
+    /// date
+    pub const fn to_date(&self, calendar: Calendar) -> Date {
+        calendar.at_jdn(self.jdn)
+    }
+
+    /// Returns the date's Saka year
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the date's month
+    pub const fn month(&self) -> SakaMonth {
+        self.month
+    }
+
+    /// Returns the date's day of month
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the ordinal number of the day within the year.  Ordinal date 1
+    /// is Chaitra 1.
+    pub const fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// Returns the Julian day number of the date
+    pub const fn julian_day_number(&self) -> Jdnum {
+        self.jdn
+    }
+
+    /// Returns the [`YearKind`] of the date's year
+    pub const fn year_kind(&self) -> YearKind {
+        year_kind(self.year)
+    }
+}
+
+impl PartialOrd for SakaDate {
+    fn partial_cmp(&self, other: &SakaDate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SakaDate {
+    fn cmp(&self, other: &SakaDate) -> Ordering {
+        self.jdn.cmp(&other.jdn)
+    }
+}
+
+impl From<Date> for SakaDate {
+    /// Convert a [`Date`] in any calendar to the Saka date on the same day
+    fn from(date: Date) -> SakaDate {
+        SakaDate::from_date(&date)
+    }
+}
+
+impl fmt::Display for SakaDate {
+    /// A `SakaDate` is displayed in the format `YYYY-MM-DD` (year, month
+    /// number, and day of month) by default.  Selecting the alternate form
+    /// with `{:#}` instead produces a string of the form `YYYY-JJJ` (year and
+    /// day of year).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-", self.year())?;
+        if f.alternate() {
+            write!(f, "{:03}", self.ordinal())?;
+        } else {
+            write!(f, "{:02}-{:02}", self.month().number(), self.day())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SakaDate {
+    type Err = ParseSakaDateError;
+
+    /// Parse a Saka date from a string in either the format `YYYY-MM-DD` or
+    /// `YYYY-JJJ`, as accepted by
+    /// [`Calendar::parse_date()`][crate::Calendar::parse_date].
+    fn from_str(s: &str) -> Result<SakaDate, ParseSakaDateError> {
+        let mut parser = inner::DateParser::new(s);
+        let year = parser.parse_int()?;
+        parser.scan_char('-')?;
+        let field1 = parser.parse_uint()?;
+        if parser.is_empty() {
+            return Ok(SakaDate::at_ordinal_date(year, field1)?);
+        }
+        let month = SakaMonth::try_from_const(field1)
+            .ok_or(ParseDateError::InvalidMonth { value: field1 })?;
+        parser.scan_char('-')?;
+        let day = parser.parse_uint()?;
+        if !parser.is_empty() {
+            return Err(ParseDateError::Trailing.into());
+        }
+        Ok(SakaDate::at_ymd(year, month, day)?)
+    }
+}
+
+/// An enumeration of the twelve months of the Indian national calendar
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SakaMonth {
+    Chaitra = 1,
+    Vaishakha = 2,
+    Jyeshtha = 3,
+    Ashadha = 4,
+    Shravana = 5,
+    Bhadra = 6,
+    Ashvin = 7,
+    Kartika = 8,
+    Agrahayana = 9,
+    Pausha = 10,
+    Magha = 11,
+    Phalguna = 12,
+}
+
+impl SakaMonth {
+    /// Returns the name of the month as conventionally romanized in English.
+    /// This is the same as the month's Rust identifier.
+    pub const fn name(&self) -> &'static str {
+        use SakaMonth::*;
+        match self {
+            Chaitra => "Chaitra",
+            Vaishakha => "Vaishakha",
+            Jyeshtha => "Jyeshtha",
+            Ashadha => "Ashadha",
+            Shravana => "Shravana",
+            Bhadra => "Bhadra",
+            Ashvin => "Ashvin",
+            Kartika => "Kartika",
+            Agrahayana => "Agrahayana",
+            Pausha => "Pausha",
+            Magha => "Magha",
+            Phalguna => "Phalguna",
+        }
+    }
+
+    /// Returns the number of the month, where Chaitra is 1.
+    ///
+    /// These values are also available as the enumeration discriminants and
+    /// can be accessed by casting, e.g., `SakaMonth::Chaitra as u32`.
+    pub const fn number(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the zero-based number of the month, where Chaitra is 0.
+    pub const fn number0(&self) -> u32 {
+        self.number() - 1
+    }
+
+    /// Returns the number of days in the month in a year of the given kind.
+    ///
+    /// Chaitra has 31 days in leap years and 30 otherwise; Vaishakha through
+    /// Bhadra always have 31 days; and the remaining months always have 30
+    /// days.
+    pub const fn length(&self, kind: YearKind) -> u32 {
+        match self.number() {
+            1 if kind.is_leap() => 31,
+            1 => 30,
+            2..=6 => 31,
+            _ => 30,
+        }
+    }
+
+    /// Returns the month before the month in question.  Returns `None` for
+    /// Chaitra.
+    pub const fn pred(&self) -> Option<SakaMonth> {
+        SakaMonth::try_from_const(self.number() - 1)
+    }
+
+    /// Returns the month after the month in question.  Returns `None` for
+    /// Phalguna.
+    pub const fn succ(&self) -> Option<SakaMonth> {
+        SakaMonth::try_from_const(self.number() + 1)
+    }
+
+    /// [Private] Returns the number of days in the year before the start of
+    /// the month
+    const fn days_before(&self, kind: YearKind) -> u32 {
+        let n = self.number0();
+        let leap = kind.is_leap() as u32;
+        if n == 0 {
+            0
+        } else if n <= 6 {
+            30 + leap + 31 * (n - 1)
+        } else {
+            30 + leap + 31 * 5 + 30 * (n - 6)
+        }
+    }
+
+    /// [Private] Like `TryFrom<u32>`, but const and returning an `Option`
+    const fn try_from_const(value: u32) -> Option<SakaMonth> {
+        use SakaMonth::*;
+        match value {
+            1 => Some(Chaitra),
+            2 => Some(Vaishakha),
+            3 => Some(Jyeshtha),
+            4 => Some(Ashadha),
+            5 => Some(Shravana),
+            6 => Some(Bhadra),
+            7 => Some(Ashvin),
+            8 => Some(Kartika),
+            9 => Some(Agrahayana),
+            10 => Some(Pausha),
+            11 => Some(Magha),
+            12 => Some(Phalguna),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SakaMonth {
+    /// A `SakaMonth` is displayed as its name
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for SakaMonth {
+    type Err = ParseSakaMonthError;
+
+    /// Parses a month from its name.  Input is treated case-insensitively.
+    ///
+    /// In addition to the names returned by [`SakaMonth::name()`], the
+    /// following common alternative spellings are accepted: "Vaisakha",
+    /// "Jyaistha", "Asadha", "Sravana", "Bhadrapada", "Asvina", "Kartik",
+    /// "Margashirsha", "Pausa", and "Phalgun".
+    fn from_str(s: &str) -> Result<SakaMonth, ParseSakaMonthError> {
+        use SakaMonth::*;
+        const NAMES: [(&str, SakaMonth); 22] = [
+            ("chaitra", Chaitra),
+            ("vaishakha", Vaishakha),
+            ("vaisakha", Vaishakha),
+            ("jyeshtha", Jyeshtha),
+            ("jyaistha", Jyeshtha),
+            ("ashadha", Ashadha),
+            ("asadha", Ashadha),
+            ("shravana", Shravana),
+            ("sravana", Shravana),
+            ("bhadra", Bhadra),
+            ("bhadrapada", Bhadra),
+            ("ashvin", Ashvin),
+            ("asvina", Ashvin),
+            ("kartika", Kartika),
+            ("kartik", Kartika),
+            ("agrahayana", Agrahayana),
+            ("margashirsha", Agrahayana),
+            ("pausha", Pausha),
+            ("pausa", Pausha),
+            ("magha", Magha),
+            ("phalguna", Phalguna),
+            ("phalgun", Phalguna),
+        ];
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name))
+            .map(|&(_, month)| month)
+            .ok_or(ParseSakaMonthError)
+    }
+}
+
+macro_rules! impl_saka_month_try_from {
+    ($($t:ty),* $(,)?) => {
+      $(
+        impl TryFrom<$t> for SakaMonth {
+            type Error = crate::errors::TryIntoMonthError;
+
+            /// Convert a month number to the corresponding month.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`TryIntoMonthError`][crate::errors::TryIntoMonthError]
+            /// if the given number is less than one or greater than twelve.
+            fn try_from(value: $t) -> Result<SakaMonth, crate::errors::TryIntoMonthError> {
+                u32::try_from(value)
+                    .ok()
+                    .and_then(SakaMonth::try_from_const)
+                    .ok_or(crate::errors::TryIntoMonthError)
+            }
+        }
+      )*
+    }
+}
+
+impl_saka_month_try_from!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// [Private] Calculate the month and day of month for a given Saka year and
+/// day of year.  The ordinal must be valid for the year.
+const fn ordinal2md(year: i32, ordinal: u32) -> (SakaMonth, u32) {
+    let kind = year_kind(year);
+    let mut month = SakaMonth::Phalguna;
+    while month.days_before(kind) >= ordinal {
+        month = match month.pred() {
+            Some(m) => m,
+            None => unreachable!(),
+        };
+    }
+    (month, ordinal - month.days_before(kind))
+}
+
+/// [Private] Calculate the Julian day number of the given Saka year and day
+/// of year.
+///
+/// # Errors
+///
+/// Returns [`ArithmeticError`] if numeric overflow/underflow occurs.
+#[allow(clippy::cast_possible_truncation)]
+const fn jdn_for(year: i32, ordinal: u32) -> Result<Jdnum, ArithmeticError> {
+    // The start of a Saka year near either end of the supported range can lie
+    // outside of `Jdnum`'s range even when the date itself does not, so find
+    // the new year in the first Gregorian leap cycle and do the remaining
+    // arithmetic in i64, range-checking only the final result.
+    let gyear = year as i64 + SAKA_ERA_OFFSET as i64;
+    let cycle_year = gyear.rem_euclid(inner::GREGORIAN_CYCLE_YEARS as i64);
+    let cycles = gyear.div_euclid(inner::GREGORIAN_CYCLE_YEARS as i64);
+    // `cycle_year` is less than 400, so the cast is lossless and the
+    // conversion cannot fail:
+    let Some(new_year) = inner::gregorian2jdn(cycle_year as i32, NEW_YEAR_ORDINAL) else {
+        unreachable!();
+    };
+    let jdn = new_year as i64 + cycles * inner::GREGORIAN_CYCLE_DAYS as i64 + ordinal as i64 - 1;
+    if jdn < Jdnum::MIN as i64 || jdn > Jdnum::MAX as i64 {
+        Err(ArithmeticError)
+    } else {
+        Ok(jdn as Jdnum)
+    }
+}
@@ -0,0 +1,565 @@
+//! The Chinese sexagenary cycle of heavenly stems and earthly branches
+//!
+//! The [sexagenary cycle][sexagenary] is a cycle of sixty terms used in
+//! China and elsewhere in East Asia to label days and years.  Each term pairs
+//! one of the ten [heavenly stems][Stem] with one of the twelve [earthly
+//! branches][Branch]; both advance by one with each step of the cycle, so only
+//! stem-branch pairs with stems & branches in the same (odd or even) position
+//! occur.  The first term of the cycle is *jiǎzǐ* (甲子) and the last is
+//! *guǐhài* (癸亥).
+//!
+//! The cycle of days has run uninterrupted since at least the eighth century
+//! BC, and so the day cycle is a simple function of the Julian day number:
+//! JDN 2451551 (2000-01-07 in the Gregorian calendar) was a *jiǎzǐ* day.  The
+//! cycle of years is reckoned such that AD 4 (and thus AD 1984) was a *jiǎzǐ*
+//! year.  Note that a Chinese year does not begin on January 1, and so dates
+//! early in a Gregorian year belong to the preceding year of the cycle; this
+//! module does not attempt to determine the start of the Chinese year.
+//!
+//! [sexagenary]: https://en.wikipedia.org/wiki/Sexagenary_cycle
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, sexagenary::{Branch, Sexagenary, Stem}};
+//!
+//! let date = Calendar::GREGORIAN.at_ymd(1949, Month::October, 1).unwrap();
+//! let day = Sexagenary::for_date(&date);
+//! assert_eq!(day.stem(), Stem::Jia);
+//! assert_eq!(day.branch(), Branch::Zi);
+//! assert_eq!(day.to_string(), "Jiazi");
+//! assert_eq!(format!("{day:#}"), "甲子");
+//!
+//! let year = Sexagenary::for_year(2024);
+//! assert_eq!(year.to_string(), "Jiachen");
+//! ```
+use crate::errors::ParseSexagenaryError;
+use crate::{Date, Jdnum};
+use core::fmt;
+use core::str::FromStr;
+
+/// The number of terms in the sexagenary cycle
+pub const CYCLE_LENGTH: u32 = 60;
+
+/// The amount to add to a Julian day number before taking the remainder
+/// modulo 60 in order to obtain the zero-based index of the day in the cycle
+const DAY_OFFSET: Jdnum = 49;
+
+/// A year in which the cycle of years began anew (at *jiǎzǐ*)
+const YEAR_EPOCH: i32 = 4;
+
+/// An enumeration of the ten heavenly stems
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Stem {
+    Jia = 1,
+    Yi = 2,
+    Bing = 3,
+    Ding = 4,
+    Wu = 5,
+    Ji = 6,
+    Geng = 7,
+    Xin = 8,
+    Ren = 9,
+    Gui = 10,
+}
+
+impl Stem {
+    /// Returns the name of the stem in pinyin without tone marks.  This is the
+    /// same as the stem's Rust identifier.
+    pub const fn name(&self) -> &'static str {
+        use Stem::*;
+        match self {
+            Jia => "Jia",
+            Yi => "Yi",
+            Bing => "Bing",
+            Ding => "Ding",
+            Wu => "Wu",
+            Ji => "Ji",
+            Geng => "Geng",
+            Xin => "Xin",
+            Ren => "Ren",
+            Gui => "Gui",
+        }
+    }
+
+    /// Returns the name of the stem in pinyin with tone marks
+    pub const fn pinyin(&self) -> &'static str {
+        use Stem::*;
+        match self {
+            Jia => "jiǎ",
+            Yi => "yǐ",
+            Bing => "bǐng",
+            Ding => "dīng",
+            Wu => "wù",
+            Ji => "jǐ",
+            Geng => "gēng",
+            Xin => "xīn",
+            Ren => "rén",
+            Gui => "guǐ",
+        }
+    }
+
+    /// Returns the Chinese character for the stem
+    pub const fn character(&self) -> char {
+        use Stem::*;
+        match self {
+            Jia => '甲',
+            Yi => '乙',
+            Bing => '丙',
+            Ding => '丁',
+            Wu => '戊',
+            Ji => '己',
+            Geng => '庚',
+            Xin => '辛',
+            Ren => '壬',
+            Gui => '癸',
+        }
+    }
+
+    /// Returns the number of the stem, where *jiǎ* is 1 and *guǐ* is 10.
+    ///
+    /// These values are also available as the enumeration discriminants and
+    /// can be accessed by casting, e.g., `Stem::Jia as u32`.
+    pub const fn number(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the zero-based number of the stem, where *jiǎ* is 0 and *guǐ*
+    /// is 9.
+    pub const fn number0(&self) -> u32 {
+        self.number() - 1
+    }
+
+    /// [Private] Returns the stem with the given zero-based number, which must
+    /// be less than 10
+    const fn from_number0(n: u32) -> Stem {
+        use Stem::*;
+        match n {
+            0 => Jia,
+            1 => Yi,
+            2 => Bing,
+            3 => Ding,
+            4 => Wu,
+            5 => Ji,
+            6 => Geng,
+            7 => Xin,
+            8 => Ren,
+            9 => Gui,
+            _ => unreachable!(),
+        }
+    }
+
+    /// [Private] If `s` begins with a name of the stem (as accepted by
+    /// `FromStr`), return the rest of the string
+    fn strip_name<'a>(&self, s: &'a str) -> Option<&'a str> {
+        strip_prefix_ignore_ascii_case(s, self.name())
+            .or_else(|| strip_prefix_ignore_ascii_case(s, self.pinyin()))
+            .or_else(|| s.strip_prefix(self.character()))
+    }
+}
+
+impl fmt::Display for Stem {
+    /// A `Stem` is displayed as its name in pinyin without tone marks by
+    /// default.  Selecting the alternate form with `{:#}` instead produces the
+    /// Chinese character for the stem.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut buf = [0; 4];
+            f.pad(self.character().encode_utf8(&mut buf))
+        } else {
+            f.pad(self.name())
+        }
+    }
+}
+
+impl FromStr for Stem {
+    type Err = ParseSexagenaryError;
+
+    /// Parses a stem from its name in pinyin (with or without tone marks) or
+    /// from its Chinese character.  Input is treated case-insensitively.
+    fn from_str(s: &str) -> Result<Stem, ParseSexagenaryError> {
+        (0..10)
+            .map(Stem::from_number0)
+            .find(|stem| stem.strip_name(s) == Some(""))
+            .ok_or(ParseSexagenaryError)
+    }
+}
+
+/// An enumeration of the twelve earthly branches
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Branch {
+    Zi = 1,
+    Chou = 2,
+    Yin = 3,
+    Mao = 4,
+    Chen = 5,
+    Si = 6,
+    Wu = 7,
+    Wei = 8,
+    Shen = 9,
+    You = 10,
+    Xu = 11,
+    Hai = 12,
+}
+
+impl Branch {
+    /// Returns the name of the branch in pinyin without tone marks.  This is
+    /// the same as the branch's Rust identifier.
+    pub const fn name(&self) -> &'static str {
+        use Branch::*;
+        match self {
+            Zi => "Zi",
+            Chou => "Chou",
+            Yin => "Yin",
+            Mao => "Mao",
+            Chen => "Chen",
+            Si => "Si",
+            Wu => "Wu",
+            Wei => "Wei",
+            Shen => "Shen",
+            You => "You",
+            Xu => "Xu",
+            Hai => "Hai",
+        }
+    }
+
+    /// Returns the name of the branch in pinyin with tone marks
+    pub const fn pinyin(&self) -> &'static str {
+        use Branch::*;
+        match self {
+            Zi => "zǐ",
+            Chou => "chǒu",
+            Yin => "yín",
+            Mao => "mǎo",
+            Chen => "chén",
+            Si => "sì",
+            Wu => "wǔ",
+            Wei => "wèi",
+            Shen => "shēn",
+            You => "yǒu",
+            Xu => "xū",
+            Hai => "hài",
+        }
+    }
+
+    /// Returns the Chinese character for the branch
+    pub const fn character(&self) -> char {
+        use Branch::*;
+        match self {
+            Zi => '子',
+            Chou => '丑',
+            Yin => '寅',
+            Mao => '卯',
+            Chen => '辰',
+            Si => '巳',
+            Wu => '午',
+            Wei => '未',
+            Shen => '申',
+            You => '酉',
+            Xu => '戌',
+            Hai => '亥',
+        }
+    }
+
+    /// Returns the number of the branch, where *zǐ* is 1 and *hài* is 12.
+    ///
+    /// These values are also available as the enumeration discriminants and
+    /// can be accessed by casting, e.g., `Branch::Zi as u32`.
+    pub const fn number(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the zero-based number of the branch, where *zǐ* is 0 and *hài*
+    /// is 11.
+    pub const fn number0(&self) -> u32 {
+        self.number() - 1
+    }
+
+    /// [Private] Returns the branch with the given zero-based number, which
+    /// must be less than 12
+    const fn from_number0(n: u32) -> Branch {
+        use Branch::*;
+        match n {
+            0 => Zi,
+            1 => Chou,
+            2 => Yin,
+            3 => Mao,
+            4 => Chen,
+            5 => Si,
+            6 => Wu,
+            7 => Wei,
+            8 => Shen,
+            9 => You,
+            10 => Xu,
+            11 => Hai,
+            _ => unreachable!(),
+        }
+    }
+
+    /// [Private] Returns true iff `s` is a name of the branch (as accepted by
+    /// `FromStr`)
+    fn matches(&self, s: &str) -> bool {
+        s.eq_ignore_ascii_case(self.name())
+            || s.eq_ignore_ascii_case(self.pinyin())
+            || s.strip_prefix(self.character()) == Some("")
+    }
+}
+
+impl fmt::Display for Branch {
+    /// A `Branch` is displayed as its name in pinyin without tone marks by
+    /// default.  Selecting the alternate form with `{:#}` instead produces the
+    /// Chinese character for the branch.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut buf = [0; 4];
+            f.pad(self.character().encode_utf8(&mut buf))
+        } else {
+            f.pad(self.name())
+        }
+    }
+}
+
+impl FromStr for Branch {
+    type Err = ParseSexagenaryError;
+
+    /// Parses a branch from its name in pinyin (with or without tone marks) or
+    /// from its Chinese character.  Input is treated case-insensitively.
+    fn from_str(s: &str) -> Result<Branch, ParseSexagenaryError> {
+        (0..12)
+            .map(Branch::from_number0)
+            .find(|branch| branch.matches(s))
+            .ok_or(ParseSexagenaryError)
+    }
+}
+
+/// A term of the sexagenary cycle, consisting of a heavenly stem and an
+/// earthly branch.
+///
+/// `Sexagenary` values are ordered by their position in the cycle, starting
+/// at *jiǎzǐ*.
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Sexagenary {
+    /// The zero-based position in the cycle; always less than 60
+    index: u32,
+}
+
+impl Sexagenary {
+    /// The first term of the cycle, *jiǎzǐ*
+    pub const JIAZI: Sexagenary = Sexagenary { index: 0 };
+
+    /// Construct the term of the cycle with the given stem & branch.  Returns
+    /// `None` if the stem and branch do not both have odd numbers or both
+    /// have even numbers, as such combinations do not occur in the cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::sexagenary::{Branch, Sexagenary, Stem};
+    ///
+    /// let term = Sexagenary::new(Stem::Bing, Branch::Yin).unwrap();
+    /// assert_eq!(term.number(), 3);
+    /// assert_eq!(Sexagenary::new(Stem::Bing, Branch::Mao), None);
+    /// ```
+    pub const fn new(stem: Stem, branch: Branch) -> Option<Sexagenary> {
+        let s = stem.number0();
+        let b = branch.number0();
+        if s % 2 != b % 2 {
+            return None;
+        }
+        // Step through the six terms with the given stem until we reach the
+        // one with the given branch:
+        let mut index = s;
+        while index % 12 != b {
+            index += 10;
+        }
+        Some(Sexagenary { index })
+    }
+
+    /// Returns the term of the cycle with the given number, where *jiǎzǐ* is
+    /// 1 and *guǐhài* is 60.  Returns `None` if `number` is not in the range
+    /// 1 through 60.
+    pub const fn from_number(number: u32) -> Option<Sexagenary> {
+        if number >= 1 && number <= CYCLE_LENGTH {
+            Some(Sexagenary { index: number - 1 })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the term of the day cycle for the given Julian day number
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::sexagenary::Sexagenary;
+    ///
+    /// assert_eq!(Sexagenary::for_jdn(2451551), Sexagenary::JIAZI);
+    /// assert_eq!(Sexagenary::for_jdn(2451550).to_string(), "Guihai");
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn for_jdn(jdn: Jdnum) -> Sexagenary {
+        // rem_euclid() always returns a nonnegative number less than 60, so
+        // the cast is lossless:
+        let index = ((jdn as i64 + DAY_OFFSET as i64).rem_euclid(CYCLE_LENGTH as i64)) as u32;
+        Sexagenary { index }
+    }
+
+    /// Returns the term of the day cycle for the given date
+    pub const fn for_date(date: &Date) -> Sexagenary {
+        Sexagenary::for_jdn(date.julian_day_number())
+    }
+
+    /// Returns the term of the year cycle for the given year, numbered
+    /// astronomically.
+    ///
+    /// The term applies to the Chinese year that begins in the given year of
+    /// the Common Era; dates before the Chinese New Year belong to the term
+    /// for the preceding year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::sexagenary::{Branch, Sexagenary, Stem};
+    ///
+    /// let year = Sexagenary::for_year(1911);
+    /// assert_eq!(year.stem(), Stem::Xin);
+    /// assert_eq!(year.branch(), Branch::Hai);
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn for_year(year: i32) -> Sexagenary {
+        // rem_euclid() always returns a nonnegative number less than 60, so
+        // the cast is lossless:
+        let index = ((year as i64 - YEAR_EPOCH as i64).rem_euclid(CYCLE_LENGTH as i64)) as u32;
+        Sexagenary { index }
+    }
+
+    /// Returns the heavenly stem of the term
+    pub const fn stem(&self) -> Stem {
+        Stem::from_number0(self.index % 10)
+    }
+
+    /// Returns the earthly branch of the term
+    pub const fn branch(&self) -> Branch {
+        Branch::from_number0(self.index % 12)
+    }
+
+    /// Returns the number of the term, where *jiǎzǐ* is 1 and *guǐhài* is 60
+    pub const fn number(&self) -> u32 {
+        self.index + 1
+    }
+
+    /// Returns the zero-based number of the term, where *jiǎzǐ* is 0 and
+    /// *guǐhài* is 59
+    pub const fn number0(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the next term in the cycle.  The term after *guǐhài* is
+    /// *jiǎzǐ*.
+    pub const fn succ(&self) -> Sexagenary {
+        Sexagenary {
+            index: (self.index + 1) % CYCLE_LENGTH,
+        }
+    }
+
+    /// Returns the previous term in the cycle.  The term before *jiǎzǐ* is
+    /// *guǐhài*.
+    pub const fn pred(&self) -> Sexagenary {
+        Sexagenary {
+            index: (self.index + CYCLE_LENGTH - 1) % CYCLE_LENGTH,
+        }
+    }
+
+    /// Returns the earliest date on or after `date` (in the same calendar)
+    /// whose term in the day cycle is `self`.  Returns `None` if numeric
+    /// overflow occurs while calculating the resulting date's Julian day
+    /// number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, sexagenary::Sexagenary};
+    ///
+    /// let cal = Calendar::reforming(julian::ncal::CHINA).unwrap();
+    /// let date = cal.at_ymd(1912, Month::January, 1).unwrap();
+    /// let term: Sexagenary = "gengzi".parse().unwrap();
+    /// let next = term.next_on_or_after(&date).unwrap();
+    /// assert_eq!(next.to_string(), "1912-01-25");
+    /// ```
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn next_on_or_after(&self, date: &Date) -> Option<Date> {
+        let current = Sexagenary::for_date(date).index;
+        // Both indices are less than 60, so this cannot wrap:
+        let delta = ((self.index + CYCLE_LENGTH - current) % CYCLE_LENGTH) as Jdnum;
+        match date.julian_day_number().checked_add(delta) {
+            Some(jdn) => Some(date.calendar().at_jdn(jdn)),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for Sexagenary {
+    /// A `Sexagenary` is displayed as the names of its stem & branch in
+    /// pinyin without tone marks, written as one word (e.g., "Jiazi").
+    /// Selecting the alternate form with `{:#}` instead produces the Chinese
+    /// characters for the stem & branch (e.g., "甲子").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Long enough for the longest ASCII name (8 bytes) and for two
+        // three-byte characters:
+        let mut buf = [0u8; 8];
+        let len = if f.alternate() {
+            let stem = self.stem().character().encode_utf8(&mut buf).len();
+            stem + self
+                .branch()
+                .character()
+                .encode_utf8(&mut buf[stem..])
+                .len()
+        } else {
+            let stem = self.stem().name().as_bytes();
+            let branch = self.branch().name().as_bytes();
+            buf[..stem.len()].copy_from_slice(stem);
+            buf[stem.len()..stem.len() + branch.len()].copy_from_slice(branch);
+            buf[stem.len()].make_ascii_lowercase();
+            stem.len() + branch.len()
+        };
+        let s = core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)?;
+        f.pad(s)
+    }
+}
+
+impl FromStr for Sexagenary {
+    type Err = ParseSexagenaryError;
+
+    /// Parses a term of the cycle from the names of its stem & branch in
+    /// pinyin (with or without tone marks) or from their Chinese characters.
+    /// The stem & branch may optionally be separated by a hyphen.  Input is
+    /// treated case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseSexagenaryError`] if the string is not a stem followed
+    /// by a branch or if the stem & branch do not occur together in the cycle.
+    fn from_str(s: &str) -> Result<Sexagenary, ParseSexagenaryError> {
+        (0..10)
+            .map(Stem::from_number0)
+            .find_map(|stem| {
+                let rest = stem.strip_name(s)?;
+                let rest = rest.strip_prefix('-').unwrap_or(rest);
+                let branch = rest.parse::<Branch>().ok()?;
+                Some((stem, branch))
+            })
+            .and_then(|(stem, branch)| Sexagenary::new(stem, branch))
+            .ok_or(ParseSexagenaryError)
+    }
+}
+
+/// [Private] If `s` begins with `prefix` (compared ASCII-case-insensitively),
+/// return the rest of `s`
+fn strip_prefix_ignore_ascii_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        s.get(prefix.len()..)
+    } else {
+        None
+    }
+}
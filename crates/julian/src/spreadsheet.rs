@@ -0,0 +1,218 @@
+//! Conversions to & from spreadsheet serial dates
+//!
+//! Spreadsheet applications such as Microsoft Excel and `LibreOffice` Calc store
+//! dates as "serial" numbers counting days from an epoch, with the time of day
+//! (if any) stored as a fraction of a day.  Two systems are in common use,
+//! represented by [`DateSystem`]:
+//!
+//! - The 1900 date system, the default on Windows, in which serial 1 is
+//!   1900-01-01.  For compatibility with Lotus 1-2-3, this system treats 1900
+//!   as a leap year, and so serial 60 is the nonexistent date 1900-02-29.
+//!   Serials before 60 are thus one day off from a straight count of days.
+//!   This crate reports serial 60 as a [`SerialDateError::LotusLeapDay`] error
+//!   and never produces it when converting dates to serials.
+//!
+//! - The 1904 date system, formerly the default on Macintosh, in which serial 0
+//!   is 1904-01-01.
+//!
+//! Serial numbers are counts of days in the proleptic Gregorian calendar, but
+//! as a serial identifies a day independently of any calendar, it can be
+//! converted to a [`Date`] in any [`Calendar`] — such as the Julian calendar
+//! or a reforming calendar, for spreadsheets recording early dates.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, Month, spreadsheet::DateSystem};
+//!
+//! let sys = DateSystem::Excel1900;
+//! let date = sys.serial2date(45000, Calendar::GREGORIAN).unwrap();
+//! assert_eq!(date.to_string(), "2023-03-15");
+//! assert_eq!(sys.date2serial(date), Ok(45000));
+//!
+//! assert!(sys.is_lotus_leap_day(60));
+//! assert!(sys.serial2jdn(60).is_err());
+//!
+//! let (jdn, seconds) = DateSystem::Excel1904.fractional2jdn(43538.75).unwrap();
+//! assert_eq!(jdn, Calendar::GREGORIAN.at_ymd(2023, Month::March, 15).unwrap().julian_day_number());
+//! assert_eq!(seconds, 64800);
+//! ```
+use crate::errors::SerialDateError;
+use crate::{Calendar, Date, Jdnum, SECONDS_IN_DAY};
+
+/// The Julian day number of 1900-03-01, the first day for which serials in the
+/// 1900 date system are a straight count of days
+const LOTUS_CUTOFF_JDN: Jdnum = 2415080;
+
+/// The serial number of the nonexistent 1900-02-29 in the 1900 date system
+const LOTUS_LEAP_DAY: i32 = 60;
+
+/// An enumeration of the date systems used by spreadsheet applications for
+/// serial dates
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DateSystem {
+    /// The 1900 date system, in which serial 1 is 1900-01-01 and serial 60 is
+    /// the nonexistent date 1900-02-29
+    Excel1900,
+
+    /// The 1904 date system, in which serial 0 is 1904-01-01
+    Excel1904,
+}
+
+impl DateSystem {
+    /// Returns the Julian day number of the day denoted by serial 0.  In the
+    /// 1900 date system, this is 1899-12-31 (displayed by spreadsheets as
+    /// 1900-01-00).
+    pub const fn epoch_jdn(&self) -> Jdnum {
+        match self {
+            DateSystem::Excel1900 => 2415020,
+            DateSystem::Excel1904 => 2416481,
+        }
+    }
+
+    /// Returns true if the given serial is the nonexistent date 1900-02-29
+    /// produced by the Lotus 1-2-3 leap year bug, i.e., if the date system is
+    /// the 1900 system and the serial is 60
+    pub const fn is_lotus_leap_day(&self, serial: i32) -> bool {
+        matches!(self, DateSystem::Excel1900) && serial == LOTUS_LEAP_DAY
+    }
+
+    /// Converts a serial date to a Julian day number
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::LotusLeapDay`] if the serial is the
+    /// nonexistent date 1900-02-29 of the 1900 date system.
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if numeric overflow/underflow
+    /// occurs.
+    pub const fn serial2jdn(&self, serial: i32) -> Result<Jdnum, SerialDateError> {
+        if self.is_lotus_leap_day(serial) {
+            return Err(SerialDateError::LotusLeapDay);
+        }
+        let mut epoch = self.epoch_jdn();
+        if matches!(self, DateSystem::Excel1900) && serial > LOTUS_LEAP_DAY {
+            epoch -= 1;
+        }
+        match serial.checked_add(epoch) {
+            Some(jdn) => Ok(jdn),
+            None => Err(SerialDateError::OutOfRange),
+        }
+    }
+
+    /// Converts a Julian day number to a serial date.  In the 1900 date
+    /// system, the returned serial is never 60.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if numeric overflow/underflow
+    /// occurs.
+    pub const fn jdn2serial(&self, jdn: Jdnum) -> Result<i32, SerialDateError> {
+        let mut epoch = self.epoch_jdn();
+        if matches!(self, DateSystem::Excel1900) && jdn >= LOTUS_CUTOFF_JDN {
+            epoch -= 1;
+        }
+        match jdn.checked_sub(epoch) {
+            Some(serial) => Ok(serial),
+            None => Err(SerialDateError::OutOfRange),
+        }
+    }
+
+    /// Converts a serial date to a [`Date`] in the given calendar
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::LotusLeapDay`] if the serial is the
+    /// nonexistent date 1900-02-29 of the 1900 date system.
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if numeric overflow/underflow
+    /// occurs.
+    pub const fn serial2date(
+        &self,
+        serial: i32,
+        calendar: Calendar,
+    ) -> Result<Date, SerialDateError> {
+        match self.serial2jdn(serial) {
+            Ok(jdn) => Ok(calendar.at_jdn(jdn)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Converts a [`Date`] to a serial date
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if numeric overflow/underflow
+    /// occurs.
+    pub const fn date2serial(&self, date: Date) -> Result<i32, SerialDateError> {
+        self.jdn2serial(date.julian_day_number())
+    }
+
+    /// Converts a fractional serial date-time to a Julian day number, along
+    /// with a count of seconds since midnight (rounded to the nearest second)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::LotusLeapDay`] if the serial falls on the
+    /// nonexistent date 1900-02-29 of the 1900 date system.
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if the serial is NaN or
+    /// infinite or if numeric overflow/underflow occurs.
+    // Rounding with `f64::round()` or `f64::mul_add()` requires `std`:
+    #[allow(clippy::suboptimal_flops)]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn fractional2jdn(&self, serial: f64) -> Result<(Jdnum, u32), SerialDateError> {
+        if !(f64::from(i32::MIN) <= serial && serial < f64::from(i32::MAX)) {
+            return Err(SerialDateError::OutOfRange);
+        }
+        // `serial` is in range for `i32`, so these casts don't truncate.  The
+        // integer part is floored by hand, as `f64::floor()` requires `std`.
+        let mut day = serial as i32;
+        if f64::from(day) > serial {
+            day -= 1;
+        }
+        let mut seconds = ((serial - f64::from(day)) * SECONDS_IN_DAY as f64 + 0.5) as u32;
+        if seconds >= SECONDS_IN_DAY as u32 {
+            day += 1;
+            seconds -= SECONDS_IN_DAY as u32;
+        }
+        Ok((self.serial2jdn(day)?, seconds))
+    }
+
+    /// Converts a Julian day number and a count of seconds since midnight to
+    /// a fractional serial date-time
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if numeric overflow/underflow
+    /// occurs.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn jdn2fractional(&self, jdn: Jdnum, seconds: u32) -> Result<f64, SerialDateError> {
+        let serial = self.jdn2serial(jdn)?;
+        Ok(f64::from(serial) + f64::from(seconds) / SECONDS_IN_DAY as f64)
+    }
+
+    /// Converts a fractional serial date-time to a [`Date`] in the given
+    /// calendar, along with a count of seconds since midnight (rounded to the
+    /// nearest second)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialDateError::LotusLeapDay`] if the serial falls on the
+    /// nonexistent date 1900-02-29 of the 1900 date system.
+    ///
+    /// Returns [`SerialDateError::OutOfRange`] if the serial is NaN or
+    /// infinite or if numeric overflow/underflow occurs.
+    pub fn fractional2date(
+        &self,
+        serial: f64,
+        calendar: Calendar,
+    ) -> Result<(Date, u32), SerialDateError> {
+        let (jdn, seconds) = self.fractional2jdn(serial)?;
+        Ok((calendar.at_jdn(jdn), seconds))
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "astro")]
+use crate::astro::{
+    J2000, SolarTime, date2jd, equation_of_time, jd2date, jd2jdn, jdn2jd, julian_centuries,
+    julian_millennia,
+};
+use crate::errors::ArithmeticError;
+use crate::{Calendar, Month, ncal};
+use rstest::rstest;
+
+mod moon;
+mod seasons;
+mod sidereal;
+mod sun;
+mod timescale;
+
+#[rstest]
+#[case(2460066.25, 2460066, 64800.0)]
+#[case(2460066.0, 2460066, 43200.0)]
+#[case(2460065.5, 2460066, 0.0)]
+#[case(0.0, 0, 43200.0)]
+#[case(-0.5, 0, 0.0)]
+#[case(-1.25, -1, 21600.0)]
+fn jd_to_jdn(#[case] jd: f64, #[case] jdn: i32, #[case] seconds: f64) {
+    assert_eq!(jd2jdn(jd), Ok((jdn, seconds)));
+    assert!((jdn2jd(jdn, seconds) - jd).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(f64::NAN)]
+#[case(f64::INFINITY)]
+#[case(f64::NEG_INFINITY)]
+#[case(3e9)]
+#[case(-3e9)]
+fn jd_to_jdn_out_of_range(#[case] jd: f64) {
+    assert_eq!(jd2jdn(jd), Err(ArithmeticError));
+}
+
+#[test]
+fn jd_to_date() {
+    let (date, seconds) = jd2date(2299160.25, Calendar::REFORM1582).unwrap();
+    assert_eq!(date.to_string(), "1582-10-04");
+    assert!((seconds - 64800.0).abs() < 1e-3);
+    assert!((date2jd(date, seconds) - 2299160.25).abs() < 1e-9);
+}
+
+// Reference values from Meeus, example 28.b, and the annual extrema of the
+// equation of time
+#[rstest]
+#[case(2448908.5, 822.6, 1.0)] // 1992-10-13
+#[case(2459986.5, -852.0, 10.0)] // 2023-02-11
+#[case(2460078.5, 219.0, 10.0)] // 2023-05-14
+#[case(2460151.5, -393.0, 10.0)] // 2023-07-26
+#[case(2460251.5, 985.0, 10.0)] // 2023-11-03
+fn eot(#[case] jd: f64, #[case] seconds: f64, #[case] tolerance: f64) {
+    let e = equation_of_time(jd);
+    assert!((e - seconds).abs() < tolerance, "EoT for JD {jd} was {e}");
+}
+
+#[rstest]
+#[case(SolarTime::Mean, 0.0, 0.0)]
+#[case(SolarTime::Mean, -71.0589, -17054.136)]
+#[case(SolarTime::Mean, 116.4, 27936.0)]
+#[case(SolarTime::Mean, 180.0, 43200.0)]
+fn mean_offset(#[case] st: SolarTime, #[case] longitude: f64, #[case] offset: f64) {
+    assert!((st.offset(2460065.5, longitude) - offset).abs() < 1e-6);
+}
+
+#[test]
+fn apparent_offset() {
+    let jd = 2448908.5;
+    let offset = SolarTime::Apparent.offset(jd, 15.0);
+    assert!((offset - 3600.0 - equation_of_time(jd)).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(SolarTime::Mean, 60254.136)]
+#[case(SolarTime::Apparent, 60690.020)]
+fn boston_noon_1700(#[case] st: SolarTime, #[case] ut_seconds: f64) {
+    let cal = Calendar::reforming(ncal::UNITED_STATES).unwrap();
+    let date = cal.at_ymd(1700, Month::March, 10).unwrap();
+    assert_eq!(date.julian_day_number(), 2342052);
+    let jd = st.to_ut(date, 43200.0, -71.0589);
+    let (ut_date, seconds) = jd2date(jd, cal).unwrap();
+    assert_eq!(ut_date, date);
+    assert!((seconds - ut_seconds).abs() < 0.01);
+    let (local_date, local_seconds) = st.from_ut(jd, -71.0589, cal).unwrap();
+    assert_eq!(local_date, date);
+    assert!((local_seconds - 43200.0).abs() < 0.01);
+}
+
+#[test]
+fn from_ut_crosses_day() {
+    // 2023-05-01 02:00 UT is still April 30 in local mean time at 60° W:
+    let (date, seconds) = SolarTime::Mean
+        .from_ut(2460065.5 + 2.0 / 24.0, -60.0, Calendar::GREGORIAN)
+        .unwrap();
+    assert_eq!(date.to_string(), "2023-04-30");
+    assert!((seconds - 79200.0).abs() < 0.01);
+}
+
+#[rstest]
+#[case(J2000, 0.0, 0.0)]
+#[case(2446895.5, -0.127296372348, -0.0127296372348)]
+#[case(2448976.5, -0.070321697467, -0.0070321697467)]
+#[case(2488070.0, 1.0, 0.1)]
+fn centuries_and_millennia(#[case] jd: f64, #[case] centuries: f64, #[case] millennia: f64) {
+    assert!((julian_centuries(jd) - centuries).abs() < 1e-12);
+    assert!((julian_millennia(jd) - millennia).abs() < 1e-12);
+}
@@ -0,0 +1,143 @@
+use crate::astro::moon::{
+    LunarPhase, Phase, SYNODIC_MONTH, elongation, full_moons, illuminated_fraction, new_moons,
+    phase_angle, phases_between,
+};
+use crate::{Calendar, Month};
+use rstest::rstest;
+
+// Meeus, examples 49.a & 49.b
+#[rstest]
+#[case(LunarPhase::new(-283, Phase::NewMoon), 2443192.65118)]
+#[case(LunarPhase::new(544, Phase::LastQuarter), 2467636.49186)]
+fn meeus_examples(#[case] lp: LunarPhase, #[case] jde: f64) {
+    assert!((lp.jde() - jde).abs() < 1e-5, "JDE = {}", lp.jde());
+}
+
+// Instants (UT) published by the US Naval Observatory, rounded to the minute
+#[rstest]
+#[case(Phase::NewMoon, 285, Month::January, 21, 20 * 60 + 53)]
+#[case(Phase::FirstQuarter, 285, Month::January, 28, 15 * 60 + 19)]
+#[case(Phase::FullMoon, 284, Month::January, 6, 23 * 60 + 8)]
+#[case(Phase::LastQuarter, 284, Month::January, 15, 2 * 60 + 10)]
+#[case(Phase::FullMoon, 292, Month::August, 31, 60 + 35)]
+#[case(Phase::NewMoon, 296, Month::December, 12, 23 * 60 + 32)]
+fn usno_2023(
+    #[case] phase: Phase,
+    #[case] lunation: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] minutes: u32,
+) {
+    let lp = LunarPhase::new(lunation, phase);
+    let (date, seconds) = lp.date(Calendar::GREGORIAN).unwrap();
+    assert_eq!(date, Calendar::GREGORIAN.at_ymd(2023, month, day).unwrap());
+    let expected = f64::from(minutes * 60);
+    assert!((seconds - expected).abs() < 60.0, "seconds = {seconds}");
+    assert_eq!(lp.julian_day_number(), Ok(date.julian_day_number()));
+}
+
+#[test]
+fn mean_vs_true() {
+    for lunation in -100..100 {
+        for phase in Phase::iter() {
+            let lp = LunarPhase::new(lunation, phase);
+            assert!((lp.jde() - lp.mean_jde()).abs() < 1.0);
+        }
+    }
+}
+
+#[test]
+fn succ_pred() {
+    let lp = LunarPhase::new(0, Phase::FullMoon);
+    assert_eq!(lp.succ(), Some(LunarPhase::new(1, Phase::FullMoon)));
+    assert_eq!(lp.pred(), Some(LunarPhase::new(-1, Phase::FullMoon)));
+    assert_eq!(LunarPhase::new(i32::MAX, Phase::NewMoon).succ(), None);
+    assert_eq!(LunarPhase::new(i32::MIN, Phase::NewMoon).pred(), None);
+    let gap = lp.succ().unwrap().mean_jde() - lp.mean_jde();
+    assert!((gap - SYNODIC_MONTH).abs() < 1e-6);
+}
+
+#[rstest]
+#[case(Phase::NewMoon)]
+#[case(Phase::FirstQuarter)]
+#[case(Phase::FullMoon)]
+#[case(Phase::LastQuarter)]
+fn following(#[case] phase: Phase) {
+    for i in 0..50 {
+        let jd = f64::from(i).mul_add(7.3, 2459945.5);
+        let lp = LunarPhase::following(jd, phase);
+        assert_eq!(lp.phase(), phase);
+        assert!(lp.jd() >= jd);
+        assert!(lp.pred().unwrap().jd() < jd);
+    }
+    // Starting exactly at an instant of the phase returns that occurrence:
+    let lp = LunarPhase::new(300, phase);
+    assert_eq!(LunarPhase::following(lp.jd(), phase), lp);
+}
+
+#[rstest]
+#[case(-3.0e7)]
+#[case(-1.0e6)]
+#[case(1.0e7)]
+#[case(3.0e7)]
+fn following_far_from_present(#[case] jd: f64) {
+    for phase in Phase::iter() {
+        let lp = LunarPhase::following(jd, phase);
+        assert!(lp.jd() >= jd);
+        assert!(lp.pred().unwrap().jd() < jd);
+    }
+}
+
+#[test]
+fn new_moons_2023() {
+    let cal = Calendar::GREGORIAN;
+    let start = cal.at_ymd(2023, Month::January, 1).unwrap();
+    let end = cal.at_ymd(2023, Month::December, 31).unwrap();
+    let lunations = new_moons(start, end)
+        .map(|lp| lp.lunation())
+        .collect::<Vec<_>>();
+    assert_eq!(lunations, (285..=296).collect::<Vec<_>>());
+    let fulls = full_moons(start, end).collect::<Vec<_>>();
+    assert_eq!(fulls.len(), 13);
+    assert_eq!(fulls.first(), Some(&LunarPhase::new(284, Phase::FullMoon)));
+    assert_eq!(fulls.last(), Some(&LunarPhase::new(296, Phase::FullMoon)));
+}
+
+#[test]
+fn phases_inclusive_of_end_date() {
+    let cal = Calendar::GREGORIAN;
+    let date = cal.at_ymd(2023, Month::January, 21).unwrap();
+    let mut iter = new_moons(date, date);
+    assert_eq!(iter.next(), Some(LunarPhase::new(285, Phase::NewMoon)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    let date = cal.at_ymd(2023, Month::January, 22).unwrap();
+    assert_eq!(phases_between(Phase::NewMoon, date, date).next(), None);
+}
+
+#[test]
+fn julian_calendar_new_moons() {
+    let cal = Calendar::JULIAN;
+    let start = cal.at_ymd(1582, Month::March, 1).unwrap();
+    let end = cal.at_ymd(1582, Month::April, 30).unwrap();
+    let dates = new_moons(start, end)
+        .map(|lp| lp.date(cal).unwrap().0.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(dates, ["1582-03-24", "1582-04-22"]);
+}
+
+#[rstest]
+#[case(Phase::NewMoon, 0.0)]
+#[case(Phase::FirstQuarter, 0.5)]
+#[case(Phase::FullMoon, 1.0)]
+#[case(Phase::LastQuarter, 0.5)]
+fn phase_at_instant(#[case] phase: Phase, #[case] fraction: f64) {
+    for lunation in [-5000, -283, 0, 284, 290, 1000] {
+        let jde = LunarPhase::new(lunation, phase).jde();
+        let e = elongation(jde);
+        let diff = (e - phase.elongation()).rem_euclid(360.0);
+        assert!(diff.min(360.0 - diff) < 1.0, "elongation = {e}");
+        assert!((phase_angle(jde) - (180.0 - phase.elongation()).abs()).abs() < 1.0);
+        assert!((illuminated_fraction(jde) - fraction).abs() < 0.01);
+    }
+}
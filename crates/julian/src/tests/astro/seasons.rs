@@ -0,0 +1,82 @@
+use crate::astro::seasons::Season;
+use crate::{Calendar, Month};
+use rstest::rstest;
+
+#[test]
+fn meeus_example() {
+    let jde = Season::JuneSolstice.jde(1962);
+    assert!((jde - 2437837.39245).abs() < 1e-5, "JDE = {jde}");
+}
+
+// Instants (UT) published by the US Naval Observatory, rounded to the minute
+#[rstest]
+#[case(Season::MarchEquinox, 2023, Month::March, 20, 21 * 60 + 24)]
+#[case(Season::JuneSolstice, 2023, Month::June, 21, 14 * 60 + 58)]
+#[case(Season::SeptemberEquinox, 2023, Month::September, 23, 6 * 60 + 50)]
+#[case(Season::DecemberSolstice, 2023, Month::December, 22, 3 * 60 + 27)]
+#[case(Season::MarchEquinox, 2024, Month::March, 20, 3 * 60 + 6)]
+#[case(Season::JuneSolstice, 2024, Month::June, 20, 20 * 60 + 51)]
+#[case(Season::SeptemberEquinox, 2024, Month::September, 22, 12 * 60 + 44)]
+#[case(Season::DecemberSolstice, 2024, Month::December, 21, 9 * 60 + 20)]
+fn usno(
+    #[case] season: Season,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] minutes: u32,
+) {
+    let (date, seconds) = season.date(year, Calendar::GREGORIAN).unwrap();
+    assert_eq!(date, Calendar::GREGORIAN.at_ymd(year, month, day).unwrap());
+    let expected = f64::from(minutes * 60);
+    assert!((seconds - expected).abs() < 90.0, "seconds = {seconds}");
+}
+
+#[rstest]
+#[case(325, Calendar::JULIAN, Month::March, 20)]
+#[case(1582, Calendar::JULIAN, Month::March, 10)]
+#[case(1583, Calendar::GREGORIAN, Month::March, 21)]
+#[case(2000, Calendar::GREGORIAN, Month::March, 20)]
+fn march_equinox_drift(
+    #[case] year: i32,
+    #[case] calendar: Calendar,
+    #[case] month: Month,
+    #[case] day: u32,
+) {
+    let (date, _) = Season::MarchEquinox.date(year, calendar).unwrap();
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+}
+
+#[rstest]
+#[case(-1000)]
+#[case(0)]
+#[case(999)]
+#[case(1000)]
+#[case(2024)]
+#[case(3000)]
+fn seasons_in_order(#[case] year: i32) {
+    let jdes = Season::iter().map(|s| s.jde(year)).collect::<Vec<_>>();
+    assert_eq!(jdes.len(), 4);
+    for pair in jdes.windows(2) {
+        let gap = pair[1] - pair[0];
+        assert!((88.0..95.0).contains(&gap), "gap = {gap}");
+    }
+}
+
+#[rstest]
+#[case(Season::MarchEquinox)]
+#[case(Season::JuneSolstice)]
+#[case(Season::SeptemberEquinox)]
+#[case(Season::DecemberSolstice)]
+fn table_boundary_continuity(#[case] season: Season) {
+    // The two polynomial tables should agree closely where they meet:
+    let year_length = season.jde(1000) - season.jde(999);
+    assert!((year_length - 365.2422).abs() < 0.01, "{year_length}");
+}
+
+#[test]
+fn ut_before_tt() {
+    let s = Season::MarchEquinox;
+    assert!(s.jd(2024) < s.jde(2024));
+}
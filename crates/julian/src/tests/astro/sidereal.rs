@@ -0,0 +1,85 @@
+use crate::astro::sidereal::{SiderealModel, earth_rotation_angle, equation_of_the_equinoxes};
+use rstest::rstest;
+
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Radians per hour of sidereal time
+const RADIANS_PER_HOUR: f64 = core::f64::consts::PI / 12.0;
+
+// Meeus, examples 12.a & 12.b
+#[rstest]
+#[case(SiderealModel::Iau1982, 2446895.5, 47446.3668)]
+#[case(SiderealModel::Iau1982, 2446896.30625, 30897.0896)]
+#[case(SiderealModel::Iau2006, 2446895.5, 47446.3668)]
+#[case(SiderealModel::Iau2006, 2446896.30625, 30897.0896)]
+fn gmst_meeus(#[case] model: SiderealModel, #[case] jd: f64, #[case] seconds: f64) {
+    let gmst = model.gmst(jd) * SECONDS_PER_HOUR;
+    assert!((gmst - seconds).abs() < 0.01, "GMST = {gmst}");
+}
+
+// Meeus, example 12.a
+#[rstest]
+#[case(SiderealModel::Iau1982)]
+#[case(SiderealModel::Iau2006)]
+fn gast_meeus(#[case] model: SiderealModel) {
+    let gast = model.gast(2446895.5) * SECONDS_PER_HOUR;
+    assert!((gast - 47446.1351).abs() < 0.03, "GAST = {gast}");
+}
+
+// Test cases from the SOFA library's `t_sofa_c.c`
+#[rstest]
+#[case(SiderealModel::Iau1982, 1.754174981860675)]
+#[case(SiderealModel::Iau2006, 1.754174971870091)]
+fn gmst_sofa(#[case] model: SiderealModel, #[case] radians: f64) {
+    let gmst = model.gmst(2453736.5) * RADIANS_PER_HOUR;
+    assert!((gmst - radians).abs() < 1e-9, "GMST = {gmst}");
+}
+
+#[test]
+fn gast_sofa() {
+    // `iauGst06a()`, which uses the full IAU 2000A nutation model:
+    let gast = SiderealModel::Iau2006.gast(2453736.5) * RADIANS_PER_HOUR;
+    assert!((gast - 1.754166138018281).abs() < 1e-7, "GAST = {gast}");
+}
+
+#[test]
+fn era_sofa() {
+    let era = earth_rotation_angle(2454388.5).to_radians();
+    assert!((era - 0.4022837240028158).abs() < 1e-12, "ERA = {era}");
+}
+
+#[test]
+fn equation_of_equinoxes_meeus() {
+    let ee = equation_of_the_equinoxes(2446895.5);
+    assert!((ee - -0.2317).abs() < 0.03, "EE = {ee}");
+}
+
+#[rstest]
+#[case(0.0)]
+#[case(-71.0589)]
+#[case(116.4)]
+#[case(179.9)]
+#[case(-179.9)]
+fn local_sidereal(#[case] longitude: f64) {
+    let model = SiderealModel::default();
+    let jd = 2460066.25;
+    let lmst = model.local_mean(jd, longitude);
+    let last = model.local_apparent(jd, longitude);
+    assert!((0.0..24.0).contains(&lmst));
+    assert!((0.0..24.0).contains(&last));
+    let diff = (lmst - model.gmst(jd) - longitude / 15.0).rem_euclid(24.0);
+    assert!(diff.min(24.0 - diff) < 1e-9);
+    let diff = (last - model.gast(jd) - longitude / 15.0).rem_euclid(24.0);
+    assert!(diff.min(24.0 - diff) < 1e-9);
+}
+
+#[test]
+fn gmst_range() {
+    for model in [SiderealModel::Iau1982, SiderealModel::Iau2006] {
+        for i in 0..100 {
+            let jd = f64::from(i).mul_add(1234.567, 2415020.0);
+            assert!((0.0..24.0).contains(&model.gmst(jd)));
+            assert!((0.0..24.0).contains(&model.gast(jd)));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::astro::jd2date;
+use crate::astro::sun::{Daylight, Horizon, sun_times};
+use crate::{Calendar, Month};
+use rstest::rstest;
+
+/// [Private] Asserts that `jd` falls on the given Gregorian date within a
+/// minute of the given time of day (UT)
+fn assert_time(jd: f64, year: i32, month: Month, day: u32, hour: u32, minute: u32) {
+    let (date, seconds) = jd2date(jd, Calendar::GREGORIAN).unwrap();
+    assert_eq!(date, Calendar::GREGORIAN.at_ymd(year, month, day).unwrap());
+    let expected = f64::from(hour * 3600 + minute * 60);
+    assert!((seconds - expected).abs() < 60.0, "seconds = {seconds}");
+}
+
+// Sunrise & sunset times published by timeanddate.com, converted to UT
+#[rstest]
+#[case(51.4769, -0.0005, (2023, Month::June, 21), (2023, Month::June, 21, 3, 43), (2023, Month::June, 21, 20, 21))]
+#[case(51.4769, -0.0005, (2023, Month::December, 21), (2023, Month::December, 21, 8, 3), (2023, Month::December, 21, 15, 53))]
+#[case(40.7128, -74.006, (2023, Month::March, 20), (2023, Month::March, 20, 11, 0), (2023, Month::March, 20, 23, 8))]
+#[case(-33.8688, 151.2093, (2023, Month::June, 21), (2023, Month::June, 20, 21, 0), (2023, Month::June, 21, 6, 54))]
+#[case(21.3069, -157.8583, (2023, Month::July, 4), (2023, Month::July, 4, 15, 54), (2023, Month::July, 5, 5, 18))]
+fn sunrise_sunset(
+    #[case] latitude: f64,
+    #[case] longitude: f64,
+    #[case] (year, month, day): (i32, Month, u32),
+    #[case] rise: (i32, Month, u32, u32, u32),
+    #[case] set: (i32, Month, u32, u32, u32),
+) {
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    let times = sun_times(date, latitude, longitude, Horizon::Official);
+    assert!(!times.is_polar_day());
+    assert!(!times.is_polar_night());
+    assert_time(
+        times.sunrise().unwrap(),
+        rise.0,
+        rise.1,
+        rise.2,
+        rise.3,
+        rise.4,
+    );
+    assert_time(times.sunset().unwrap(), set.0, set.1, set.2, set.3, set.4);
+    assert!(times.sunrise().unwrap() < times.solar_noon());
+    assert!(times.solar_noon() < times.sunset().unwrap());
+}
+
+#[test]
+fn solar_noon_greenwich() {
+    let date = Calendar::GREGORIAN.at_ymd(2023, Month::June, 21).unwrap();
+    let times = sun_times(date, 51.4769, -0.0005, Horizon::Official);
+    assert_time(times.solar_noon(), 2023, Month::June, 21, 12, 2);
+}
+
+#[test]
+fn twilight_order() {
+    let date = Calendar::GREGORIAN
+        .at_ymd(2023, Month::December, 21)
+        .unwrap();
+    let mut prev: Option<(f64, f64)> = None;
+    for horizon in [
+        Horizon::Official,
+        Horizon::Civil,
+        Horizon::Nautical,
+        Horizon::Astronomical,
+    ] {
+        let times = sun_times(date, 51.4769, -0.0005, horizon);
+        let rise = times.sunrise().unwrap();
+        let set = times.sunset().unwrap();
+        if let Some((prev_rise, prev_set)) = prev {
+            assert!(rise < prev_rise);
+            assert!(set > prev_set);
+        }
+        prev = Some((rise, set));
+    }
+}
+
+#[rstest]
+#[case(51.4769, (2023, Month::June, 21), Horizon::Nautical, false, false)]
+#[case(51.4769, (2023, Month::June, 21), Horizon::Astronomical, true, false)]
+#[case(69.6492, (2023, Month::June, 21), Horizon::Official, true, false)]
+#[case(69.6492, (2023, Month::December, 21), Horizon::Official, false, true)]
+#[case(69.6492, (2023, Month::December, 21), Horizon::Civil, false, false)]
+#[case(-77.8419, (2023, Month::June, 21), Horizon::Astronomical, false, false)]
+#[case(-77.8419, (2023, Month::June, 21), Horizon::Civil, false, true)]
+#[case(-77.8419, (2023, Month::December, 21), Horizon::Astronomical, true, false)]
+fn polar(
+    #[case] latitude: f64,
+    #[case] (year, month, day): (i32, Month, u32),
+    #[case] horizon: Horizon,
+    #[case] polar_day: bool,
+    #[case] polar_night: bool,
+) {
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    let times = sun_times(date, latitude, 18.9553, horizon);
+    assert_eq!(times.is_polar_day(), polar_day);
+    assert_eq!(times.is_polar_night(), polar_night);
+    assert_eq!(times.sunrise().is_some(), !polar_day && !polar_night);
+    if polar_day {
+        assert_eq!(times.daylight(), Daylight::PolarDay);
+        assert!((times.day_length() - 86400.0).abs() < 1e-9);
+    } else if polar_night {
+        assert_eq!(times.daylight(), Daylight::PolarNight);
+        assert!(times.day_length().abs() < 1e-9);
+    }
+}
+
+#[test]
+fn equator_day_length() {
+    let date = Calendar::GREGORIAN.at_ymd(2023, Month::March, 20).unwrap();
+    let times = sun_times(date, 0.0, 0.0, Horizon::Official);
+    // A little over 12 hours, due to refraction & the Sun's semidiameter:
+    let length = times.day_length();
+    assert!((43500.0..43800.0).contains(&length), "length = {length}");
+}
+
+#[test]
+fn julian_calendar_date() {
+    // Sunset in Jerusalem on 1582-10-04 (Julian), i.e., 1582-10-14
+    // (Gregorian):
+    let julian = Calendar::JULIAN.at_ymd(1582, Month::October, 4).unwrap();
+    let gregorian = Calendar::GREGORIAN
+        .at_ymd(1582, Month::October, 14)
+        .unwrap();
+    let a = sun_times(julian, 31.7683, 35.2137, Horizon::Official);
+    let b = sun_times(gregorian, 31.7683, 35.2137, Horizon::Official);
+    assert_eq!(a, b);
+    let (date, _) = jd2date(a.sunset().unwrap(), Calendar::JULIAN).unwrap();
+    assert_eq!(date, julian);
+}
+
+#[test]
+fn horizon_altitudes() {
+    assert!((Horizon::default().altitude() - -0.8333).abs() < 1e-4);
+    assert!((Horizon::Civil.altitude() - -6.0).abs() < 1e-9);
+    assert!((Horizon::Nautical.altitude() - -12.0).abs() < 1e-9);
+    assert!((Horizon::Astronomical.altitude() - -18.0).abs() < 1e-9);
+}
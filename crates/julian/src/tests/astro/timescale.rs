@@ -0,0 +1,137 @@
+use crate::astro::timescale::{
+    LeapSecond, LeapSecondTable, TT_MINUS_TAI, decimal_year, delta_t, tai2tt, tt2tai, tt2ut, ut2tt,
+};
+use crate::errors::LeapSecondTableError;
+use rstest::rstest;
+
+#[rstest]
+#[case(2441317, None)]
+#[case(2441318, Some(10))]
+#[case(2441499, Some(10))]
+#[case(2441500, Some(11))]
+#[case(2451544, Some(32))]
+#[case(2457754, Some(36))]
+#[case(2457755, Some(37))]
+#[case(2460066, Some(37))]
+fn tai_minus_utc(#[case] jdn: i32, #[case] offset: Option<i32>) {
+    assert_eq!(LeapSecondTable::BUILTIN.tai_minus_utc(jdn), offset);
+}
+
+#[rstest]
+#[case(2441318, 0.0)]
+#[case(2451545, 43200.0)]
+#[case(2457754, 86399.5)]
+#[case(2457754, 86400.0)]
+#[case(2457754, 86400.5)]
+#[case(2457755, 0.0)]
+#[case(2457755, 0.5)]
+#[case(2460066, 7421.0)]
+fn utc_tai_roundtrip(#[case] jdn: i32, #[case] seconds: f64) {
+    let table = LeapSecondTable::BUILTIN;
+    let tai = table.utc2tai(jdn, seconds).unwrap();
+    let (jdn2, seconds2) = table.tai2utc(tai).unwrap();
+    assert_eq!(jdn2, jdn);
+    assert!((seconds2 - seconds).abs() < 1e-3, "got {seconds2}");
+}
+
+#[test]
+fn leap_second_is_one_second_long() {
+    let table = LeapSecondTable::BUILTIN;
+    let before = table.utc2tai(2457754, 86399.0).unwrap();
+    let after = table.utc2tai(2457755, 0.0).unwrap();
+    let elapsed = (after - before) * 86400.0;
+    assert!((elapsed - 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn before_table() {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(table.utc2tai(2441317, 86399.0), None);
+    assert_eq!(table.tai2utc(2441317.5), None);
+}
+
+#[test]
+fn custom_table() {
+    let mut entries = LeapSecondTable::BUILTIN.entries().to_vec();
+    entries.push(LeapSecond::new(2462868, 38));
+    let table = LeapSecondTable::new(&entries).unwrap();
+    assert_eq!(table.tai_minus_utc(2462867), Some(37));
+    assert_eq!(table.tai_minus_utc(2462868), Some(38));
+    let tai = table.utc2tai(2462867, 86400.25).unwrap();
+    let (jdn, seconds) = table.tai2utc(tai).unwrap();
+    assert_eq!(jdn, 2462867);
+    assert!((seconds - 86400.25).abs() < 1e-3);
+}
+
+#[test]
+fn default_table() {
+    assert_eq!(LeapSecondTable::default(), LeapSecondTable::BUILTIN);
+}
+
+#[rstest]
+#[case(&[], LeapSecondTableError::Empty)]
+#[case(&[LeapSecond::new(2441500, 11), LeapSecond::new(2441318, 10)], LeapSecondTableError::Unsorted)]
+#[case(&[LeapSecond::new(2441318, 10), LeapSecond::new(2441318, 11)], LeapSecondTableError::Unsorted)]
+fn bad_table(#[case] entries: &[LeapSecond], #[case] err: LeapSecondTableError) {
+    assert_eq!(LeapSecondTable::new(entries), Err(err));
+}
+
+#[test]
+fn tai_tt() {
+    let tt = tai2tt(2451545.0);
+    let diff = (tt - 2451545.0) * 86400.0;
+    assert!((diff - TT_MINUS_TAI).abs() < 1e-4);
+    assert!((tt2tai(tt) - 2451545.0).abs() < 1e-9);
+}
+
+// Reference values from Espenak & Meeus's table of ΔT at selected years
+#[rstest]
+#[case(-1000.0, 25400.0, 50.0)]
+#[case(-500.0, 17190.0, 20.0)]
+#[case(0.0, 10580.0, 5.0)]
+#[case(500.0, 5710.0, 1.0)]
+#[case(1000.0, 1570.0, 5.0)]
+#[case(1500.0, 200.0, 2.0)]
+#[case(1600.0, 120.0, 0.5)]
+#[case(1700.0, 9.0, 0.5)]
+#[case(1800.0, 14.0, 0.5)]
+#[case(1850.0, 7.0, 0.5)]
+#[case(1900.0, -3.0, 0.5)]
+#[case(1950.0, 29.0, 0.5)]
+#[case(1955.0, 31.1, 0.5)]
+#[case(1960.0, 33.2, 0.5)]
+#[case(1965.0, 35.7, 0.5)]
+#[case(1970.0, 40.2, 0.5)]
+#[case(1975.0, 45.5, 0.5)]
+#[case(1980.0, 50.5, 0.5)]
+#[case(1985.0, 54.3, 0.5)]
+#[case(1990.0, 56.9, 0.5)]
+#[case(1995.0, 60.8, 0.5)]
+#[case(2000.0, 63.8, 0.5)]
+#[case(2005.0, 64.7, 0.5)]
+#[case(2050.0, 93.0, 1.0)]
+#[case(2100.0, 203.0, 1.0)]
+#[case(2200.0, 442.0, 1.0)]
+fn delta_t_values(#[case] year: f64, #[case] expected: f64, #[case] tolerance: f64) {
+    let dt = delta_t(year);
+    assert!((dt - expected).abs() < tolerance, "ΔT({year}) = {dt}");
+}
+
+#[rstest]
+#[case(2451545.0, 2000.0)]
+#[case(2451544.5, 1999.998631)]
+#[case(2415020.0, 1900.0)]
+fn decimal_years(#[case] jd: f64, #[case] year: f64) {
+    assert!((decimal_year(jd) - year).abs() < 1e-6);
+}
+
+#[rstest]
+#[case(2086307.5)]
+#[case(2451545.0)]
+#[case(2460065.5)]
+#[case(1000000.5)]
+fn ut_tt_roundtrip(#[case] jd: f64) {
+    let tt = ut2tt(jd);
+    assert!(tt > jd);
+    assert!((tt2ut(tt) - jd).abs() * 86400.0 < 0.1);
+}
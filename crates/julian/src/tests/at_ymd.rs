@@ -1,4 +1,5 @@
-use crate::{Calendar, Month, errors::DateError, ncal};
+use crate::{Calendar, Month, SkippedDateStrategy, errors::DateError, ncal};
+use rstest::rstest;
 
 #[test]
 fn day_0() {
@@ -94,7 +95,9 @@ fn skipped_date() {
         Err(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 10
+            day: 10,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         })
     );
     assert_eq!(
@@ -111,7 +114,9 @@ fn first_skipped_date() {
         Err(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 5
+            day: 5,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         })
     );
     assert_eq!(
@@ -128,7 +133,9 @@ fn last_skipped_date() {
         Err(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 14
+            day: 14,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         })
     );
     assert_eq!(
@@ -146,7 +153,9 @@ fn headless_month_start() {
         Err(DateError::SkippedDate {
             year: 1918,
             month: Month::February,
-            day: 1
+            day: 1,
+            before: (1918, Month::January, 31),
+            after: (1918, Month::February, 14),
         })
     );
     assert_eq!(
@@ -215,7 +224,9 @@ fn tailless_month_end() {
         Err(DateError::SkippedDate {
             year: 1700,
             month: Month::February,
-            day: 19
+            day: 19,
+            before: (1700, Month::February, 18),
+            after: (1700, Month::March, 1),
         })
     );
     assert_eq!(
@@ -243,3 +254,160 @@ fn past_natural_end_of_tailless_month() {
         "day 30 is outside of valid range 1-18 for 1700 February"
     );
 }
+
+#[rstest]
+#[case(
+    ncal::UNITED_KINGDOM,
+    1752,
+    Month::September,
+    5,
+    SkippedDateStrategy::Forward,
+    "1752-09-14"
+)]
+#[case(
+    ncal::UNITED_KINGDOM,
+    1752,
+    Month::September,
+    5,
+    SkippedDateStrategy::Backward,
+    "1752-09-02"
+)]
+#[case(
+    ncal::UNITED_KINGDOM,
+    1752,
+    Month::September,
+    5,
+    SkippedDateStrategy::OldStyle,
+    "1752-09-16"
+)]
+#[case(
+    ncal::UNITED_KINGDOM,
+    1752,
+    Month::September,
+    13,
+    SkippedDateStrategy::OldStyle,
+    "1752-09-24"
+)]
+#[case(
+    ncal::ITALY,
+    1582,
+    Month::October,
+    10,
+    SkippedDateStrategy::Forward,
+    "1582-10-15"
+)]
+#[case(
+    ncal::ITALY,
+    1582,
+    Month::October,
+    10,
+    SkippedDateStrategy::Backward,
+    "1582-10-04"
+)]
+#[case(
+    ncal::ITALY,
+    1582,
+    Month::October,
+    10,
+    SkippedDateStrategy::OldStyle,
+    "1582-10-20"
+)]
+#[case(
+    ncal::RUSSIA,
+    1918,
+    Month::February,
+    1,
+    SkippedDateStrategy::OldStyle,
+    "1918-02-14"
+)]
+#[case(
+    ncal::DENMARK,
+    1700,
+    Month::February,
+    29,
+    SkippedDateStrategy::Forward,
+    "1700-03-01"
+)]
+#[case(
+    ncal::DENMARK,
+    1700,
+    Month::February,
+    29,
+    SkippedDateStrategy::Backward,
+    "1700-02-18"
+)]
+#[case(
+    ncal::DENMARK,
+    1700,
+    Month::February,
+    29,
+    SkippedDateStrategy::OldStyle,
+    "1700-03-11"
+)]
+fn lenient_skipped(
+    #[case] reformation: crate::Jdnum,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] strategy: SkippedDateStrategy,
+    #[case] expected: &str,
+) {
+    let cal = Calendar::reforming(reformation).unwrap();
+    assert!(matches!(
+        cal.at_ymd(year, month, day),
+        Err(DateError::SkippedDate { .. })
+    ));
+    let (date, adjustment) = cal.at_ymd_lenient(year, month, day, strategy).unwrap();
+    assert_eq!(date.to_string(), expected);
+    assert_eq!(adjustment, Some(strategy));
+}
+
+#[rstest]
+fn lenient_not_skipped(
+    #[values(
+        SkippedDateStrategy::Forward,
+        SkippedDateStrategy::Backward,
+        SkippedDateStrategy::OldStyle
+    )]
+    strategy: SkippedDateStrategy,
+) {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    for day in [1, 2, 14, 30] {
+        let r = cal.at_ymd_lenient(1752, Month::September, day, strategy);
+        assert_eq!(
+            r,
+            Ok((cal.at_ymd(1752, Month::September, day).unwrap(), None))
+        );
+    }
+    assert_eq!(
+        cal.at_ymd_lenient(1752, Month::September, 31, strategy),
+        Err(DateError::DayOutOfRange {
+            year: 1752,
+            month: Month::September,
+            day: 31,
+            min_day: 1,
+            max_day: 30,
+        })
+    );
+}
+
+#[test]
+fn lenient_old_style_invalid() {
+    // February 3901 is skipped entirely by this reformation, and it has only
+    // 28 days in the Julian calendar:
+    let cal = Calendar::reforming(3145930).unwrap();
+    let (date, _) = cal
+        .at_ymd_lenient(3901, Month::February, 30, SkippedDateStrategy::Forward)
+        .unwrap();
+    assert_eq!(date.to_string(), "3901-03-01");
+    assert_eq!(
+        cal.at_ymd_lenient(3901, Month::February, 30, SkippedDateStrategy::OldStyle),
+        Err(DateError::DayOutOfRange {
+            year: 3901,
+            month: Month::February,
+            day: 30,
+            min_day: 1,
+            max_day: 28,
+        })
+    );
+}
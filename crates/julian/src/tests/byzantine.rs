@@ -0,0 +1,189 @@
+use crate::{
+    Calendar, Jdnum, Month, REFORM1582_JDN,
+    byzantine::{ByzantineCalendar, Era, anno_mundi_year, indiction},
+    errors::{ByzantineDateError, DateError},
+    ncal,
+};
+use core::cmp::Ordering;
+use rstest::rstest;
+
+#[rstest]
+#[case(1721423, 5509, Month::December, 31, 4)]
+#[case(1721424, 5509, Month::January, 1, 4)]
+#[case(1721424 - 122, 5509, Month::September, 1, 4)]
+#[case(1721424 - 123, 5508, Month::August, 31, 3)]
+#[case(2251915, 6961, Month::May, 29, 1)]
+#[case(2341860, 7207, Month::August, 31, 7)]
+#[case(2341861, 7208, Month::September, 1, 8)]
+#[case(2341983, 7208, Month::January, 1, 8)]
+#[case(2460065, 7531, Month::April, 17, 1)]
+fn byzantine(
+    #[case] jdn: Jdnum,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] ind: u32,
+) {
+    let cal = ByzantineCalendar::BYZANTINE;
+    let date = cal.at_jdn(jdn);
+    assert_eq!(date.era(), Era::AnnoMundi);
+    assert_eq!(date.year(), year);
+    assert_eq!(date.anno_mundi_year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+    assert_eq!(date.indiction(), ind);
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(date.to_date(), Calendar::JULIAN.at_jdn(jdn));
+    assert_eq!(cal.at_ymd(Era::AnnoMundi, year, month, day), Ok(date));
+}
+
+#[rstest]
+#[case(2341861, Era::AnnoMundi, 7208, "7208-09-01 AM")]
+#[case(2341982, Era::AnnoMundi, 7208, "7208-12-31 AM")]
+#[case(2341983, Era::AnnoDomini, 1700, "1700-01-01 AD")]
+#[case(2421638, Era::AnnoDomini, 1918, "1918-01-31 AD")]
+#[case(2421639, Era::AnnoDomini, 1918, "1918-02-14 AD")]
+fn russia(#[case] jdn: Jdnum, #[case] era: Era, #[case] year: i32, #[case] s: &str) {
+    let cal = ByzantineCalendar::RUSSIA;
+    let date = cal.at_jdn(jdn);
+    assert_eq!(date.era(), era);
+    assert_eq!(date.year(), year);
+    assert_eq!(date.to_string(), s);
+    assert_eq!(cal.at_ymd(era, year, date.month(), date.day()), Ok(date));
+}
+
+#[test]
+fn russia_properties() {
+    let cal = ByzantineCalendar::RUSSIA;
+    assert_eq!(cal.calendar(), Calendar::reforming(ncal::RUSSIA).unwrap());
+    assert_eq!(cal.anno_domini_start(), Some(2341983));
+    assert_eq!(cal.era_at(2341982), Era::AnnoMundi);
+    assert_eq!(cal.era_at(2341983), Era::AnnoDomini);
+    assert_eq!(
+        cal.at_jdn(2341983).to_date().julian_day_number(),
+        Calendar::JULIAN
+            .at_ymd(1700, Month::January, 1)
+            .unwrap()
+            .julian_day_number()
+    );
+}
+
+#[test]
+fn russia_anno_domini_indiction() {
+    let date = ByzantineCalendar::RUSSIA
+        .at_ymd(Era::AnnoDomini, 1700, Month::September, 1)
+        .unwrap();
+    assert_eq!(date.year(), 1700);
+    assert_eq!(date.anno_mundi_year(), 7209);
+    assert_eq!(date.indiction(), 9);
+}
+
+#[test]
+fn russia_anno_mundi_after_switch() {
+    let r = ByzantineCalendar::RUSSIA.at_ymd(Era::AnnoMundi, 7208, Month::January, 5);
+    assert_eq!(
+        r,
+        Err(ByzantineDateError::EraMismatch {
+            era: Era::AnnoMundi,
+            year: 7208
+        })
+    );
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "AM year 7208 was not in use on the given date"
+    );
+}
+
+#[test]
+fn russia_anno_domini_before_switch() {
+    let r = ByzantineCalendar::RUSSIA.at_ymd(Era::AnnoDomini, 1699, Month::December, 31);
+    assert_eq!(
+        r,
+        Err(ByzantineDateError::EraMismatch {
+            era: Era::AnnoDomini,
+            year: 1699
+        })
+    );
+}
+
+#[test]
+fn russia_skipped_date() {
+    let r = ByzantineCalendar::RUSSIA.at_ymd(Era::AnnoDomini, 1918, Month::February, 5);
+    assert_eq!(
+        r,
+        Err(ByzantineDateError::InvalidDate(DateError::SkippedDate {
+            year: 1918,
+            month: Month::February,
+            day: 5,
+            before: (1918, Month::January, 31),
+            after: (1918, Month::February, 14),
+        }))
+    );
+}
+
+#[test]
+fn invalid_day() {
+    let r = ByzantineCalendar::BYZANTINE.at_ymd(Era::AnnoMundi, 7207, Month::February, 29);
+    assert_eq!(
+        r,
+        Err(ByzantineDateError::InvalidDate(DateError::DayOutOfRange {
+            year: 1699,
+            month: Month::February,
+            day: 29,
+            min_day: 1,
+            max_day: 28,
+        }))
+    );
+}
+
+#[test]
+fn underflow() {
+    let r = ByzantineCalendar::BYZANTINE.at_ymd(Era::AnnoMundi, i32::MIN, Month::October, 1);
+    assert_eq!(
+        r,
+        Err(ByzantineDateError::InvalidDate(DateError::Arithmetic))
+    );
+}
+
+#[test]
+fn custom_calendar() {
+    let cal = ByzantineCalendar::new(Calendar::REFORM1582, Some(REFORM1582_JDN));
+    assert_eq!(cal.at_jdn(REFORM1582_JDN - 1).to_string(), "7091-10-04 AM");
+    assert_eq!(cal.at_jdn(REFORM1582_JDN).to_string(), "1582-10-15 AD");
+}
+
+#[test]
+fn ord() {
+    let cal = ByzantineCalendar::RUSSIA;
+    assert!(cal.at_jdn(2341982) < cal.at_jdn(2341983));
+}
+
+#[test]
+fn ord_same_day_different_calendar() {
+    // Both calendars use the Julian calendar and number this day *Anno
+    // Mundi*, so only the calendars themselves differ:
+    let later_ad = ByzantineCalendar::new(Calendar::JULIAN, Some(2341983));
+    let date1 = ByzantineCalendar::BYZANTINE.at_jdn(2341982);
+    let date2 = later_ad.at_jdn(2341982);
+    assert_eq!(date1.to_string(), date2.to_string());
+    assert_ne!(date1, date2);
+    assert_ne!(date1.cmp(&date2), Ordering::Equal);
+    assert!(ByzantineCalendar::BYZANTINE < later_ad);
+    assert!(date1 < date2);
+}
+
+#[test]
+fn free_functions() {
+    assert_eq!(anno_mundi_year(-5508, Month::August), Some(0));
+    assert_eq!(anno_mundi_year(-5508, Month::September), Some(1));
+    assert_eq!(anno_mundi_year(i32::MAX, Month::January), None);
+    assert_eq!(indiction(0), 15);
+    assert_eq!(indiction(1), 1);
+    assert_eq!(indiction(-1), 14);
+}
+
+#[test]
+fn era_display() {
+    assert_eq!(Era::AnnoMundi.to_string(), "AM");
+    assert_eq!(format!("{:>4}", Era::AnnoDomini), "  AD");
+}
@@ -0,0 +1,110 @@
+use crate::{
+    Calendar, Month,
+    cycles::{
+        Cycles, golden_number, gregorian_epact, indiction, julian_epact, julian_period_year,
+        julian_period_year_from_cycles, solar_cycle,
+    },
+    errors::CycleError,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(-4712, 1, 1, 1, Some(1))]
+#[case(0, 1, 9, 3, Some(4713))]
+#[case(1, 2, 10, 4, Some(4714))]
+#[case(532, 1, 9, 10, Some(5245))]
+#[case(1582, 6, 23, 10, Some(6295))]
+#[case(1900, 1, 5, 13, Some(6613))]
+#[case(2000, 6, 21, 8, Some(6713))]
+#[case(2024, 11, 17, 2, Some(6737))]
+#[case(3267, 19, 28, 15, Some(7980))]
+#[case(3268, 1, 1, 1, Some(7981))]
+#[case(i32::MAX, 3, 24, 10, None)]
+#[case(i32::MIN, 17, 21, 10, Some(-2147478935))]
+fn year_cycles(
+    #[case] year: i32,
+    #[case] golden: u32,
+    #[case] solar: u32,
+    #[case] ind: u32,
+    #[case] jp: Option<i32>,
+) {
+    assert_eq!(golden_number(year), golden);
+    assert_eq!(solar_cycle(year), solar);
+    assert_eq!(indiction(year), ind);
+    assert_eq!(julian_period_year(year), jp);
+}
+
+#[rstest]
+#[case(532, 0, 6)]
+#[case(1583, 6, 7)]
+#[case(1700, 9, 9)]
+#[case(1900, 0, 29)]
+#[case(2000, 25, 24)]
+#[case(2019, 25, 24)]
+#[case(2024, 20, 19)]
+#[case(2025, 1, 0)]
+#[case(4200, 11, 0)]
+fn epacts(#[case] year: i32, #[case] julian: u32, #[case] gregorian: u32) {
+    assert_eq!(julian_epact(year), julian);
+    assert_eq!(gregorian_epact(year), gregorian);
+    let c = Cycles::for_julian_year(year);
+    assert_eq!(c.epact(), julian);
+    let c = Cycles::for_gregorian_year(year);
+    assert_eq!(c.epact(), gregorian);
+}
+
+#[test]
+fn gregorian_epact_matches_knuth() {
+    // The epact computed as in Knuth's Easter algorithm (TAOCP §1.3.2, ex. 14)
+    for year in 1583..=9999 {
+        let g = golden_number(year);
+        let c = year / 100 + 1;
+        let x = (3 * c) / 4 - 12;
+        let z = (8 * c + 5) / 25 - 5;
+        let knuth = (11 * i32::try_from(g).unwrap() + 20 + z - x).rem_euclid(30);
+        assert_eq!(
+            i32::try_from(gregorian_epact(year)).unwrap(),
+            knuth,
+            "year {year}"
+        );
+    }
+}
+
+#[test]
+fn julian_period_round_trip() {
+    for year in -4712..=3267 {
+        let jp =
+            julian_period_year_from_cycles(solar_cycle(year), golden_number(year), indiction(year));
+        assert_eq!(jp.map(i32::try_from), Ok(Ok(year + 4713)), "year {year}");
+        assert_eq!(
+            Cycles::for_julian_year(year).julian_period_year(),
+            jp.unwrap()
+        );
+    }
+}
+
+#[rstest]
+#[case(0, 1, 1, CycleError::SolarCycle(0))]
+#[case(29, 1, 1, CycleError::SolarCycle(29))]
+#[case(1, 0, 1, CycleError::GoldenNumber(0))]
+#[case(1, 20, 1, CycleError::GoldenNumber(20))]
+#[case(1, 1, 0, CycleError::Indiction(0))]
+#[case(1, 1, 16, CycleError::Indiction(16))]
+fn bad_cycles(#[case] solar: u32, #[case] golden: u32, #[case] ind: u32, #[case] err: CycleError) {
+    assert_eq!(julian_period_year_from_cycles(solar, golden, ind), Err(err));
+}
+
+#[test]
+fn for_date() {
+    let cal = Calendar::REFORM1582;
+    let julian = cal.at_ymd(1582, Month::October, 4).unwrap();
+    let gregorian = cal.at_ymd(1582, Month::October, 15).unwrap();
+    let before = Cycles::for_date(&julian);
+    let after = Cycles::for_date(&gregorian);
+    assert_eq!(before.golden_number(), after.golden_number());
+    assert_eq!(before.solar_cycle(), after.solar_cycle());
+    assert_eq!(before.indiction(), after.indiction());
+    assert_eq!(before.epact(), julian_epact(1582));
+    assert_eq!(after.epact(), gregorian_epact(1582));
+    assert_ne!(before.epact(), after.epact());
+}
@@ -0,0 +1,131 @@
+use crate::{
+    Calendar, Jdnum, Month,
+    daycount::{DayCount, DayNumber},
+    errors::{ParseDayCountError, ParseDayNumberError},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(DayCount::Chronological, 2460001)]
+#[case(DayCount::Modified, 60000)]
+#[case(DayCount::Reduced, 60001)]
+#[case(DayCount::Truncated, 20000)]
+#[case(DayCount::Lilian, 160841)]
+#[case(DayCount::RataDie, 738576)]
+#[case(DayCount::Dublin, 44981)]
+#[case(DayCount::Cnes, 26718)]
+#[case(DayCount::Unix, 19413)]
+fn for_date(#[case] count: DayCount, #[case] value: Jdnum) {
+    let date = Calendar::GREGORIAN
+        .at_ymd(2023, Month::February, 25)
+        .unwrap();
+    let dn = count.for_date(&date).unwrap();
+    assert_eq!(dn.count(), count);
+    assert_eq!(dn.value(), value);
+    assert_eq!(dn.julian_day_number(), 2460001);
+    assert_eq!(dn.to_date(Calendar::GREGORIAN), date);
+    assert_eq!(DayNumber::new(count, value), Some(dn));
+}
+
+#[rstest]
+#[case(DayCount::Modified, 1858, Month::November, 17)]
+#[case(DayCount::Reduced, 1858, Month::November, 16)]
+#[case(DayCount::Truncated, 1968, Month::May, 24)]
+#[case(DayCount::Lilian, 1582, Month::October, 14)]
+#[case(DayCount::RataDie, 0, Month::December, 31)]
+#[case(DayCount::Dublin, 1899, Month::December, 31)]
+#[case(DayCount::Cnes, 1950, Month::January, 1)]
+#[case(DayCount::Unix, 1970, Month::January, 1)]
+fn epochs(#[case] count: DayCount, #[case] year: i32, #[case] month: Month, #[case] day: u32) {
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    assert_eq!(count.epoch_jdn(), date.julian_day_number());
+    assert_eq!(count.for_date(&date).unwrap().value(), 0);
+}
+
+#[test]
+fn convert() {
+    let lilian = DayNumber::new(DayCount::Lilian, 1).unwrap();
+    assert_eq!(
+        lilian.to_date(Calendar::REFORM1582).to_string(),
+        "1582-10-15"
+    );
+    let rd = lilian.convert(DayCount::RataDie).unwrap();
+    assert_eq!(rd.value(), 577736);
+    assert_eq!(rd.convert(DayCount::Lilian), Some(lilian));
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(DayNumber::new(DayCount::Modified, Jdnum::MAX), None);
+    assert!(DayNumber::new(DayCount::Modified, Jdnum::MIN).is_some());
+    assert_eq!(DayCount::Modified.for_jdn(Jdnum::MIN), None);
+    assert_eq!(
+        DayCount::Chronological.for_jdn(Jdnum::MIN).unwrap().value(),
+        Jdnum::MIN
+    );
+    let dn = DayNumber::new(DayCount::Modified, Jdnum::MIN).unwrap();
+    assert_eq!(
+        dn.convert(DayCount::Chronological).unwrap().value(),
+        Jdnum::MIN + 2400001
+    );
+    assert_eq!(dn.convert(DayCount::Cnes), None);
+}
+
+#[rstest]
+#[case(DayCount::Chronological, "cjd")]
+#[case(DayCount::Modified, "mjd")]
+#[case(DayCount::Reduced, "rjd")]
+#[case(DayCount::Truncated, "tjd")]
+#[case(DayCount::Lilian, "lilian")]
+#[case(DayCount::RataDie, "rd")]
+#[case(DayCount::Dublin, "djd")]
+#[case(DayCount::Cnes, "cnes")]
+#[case(DayCount::Unix, "unix")]
+fn prefix(#[case] count: DayCount, #[case] s: &str) {
+    assert_eq!(count.prefix(), s);
+    assert_eq!(count.to_string(), s);
+    assert_eq!(s.parse::<DayCount>(), Ok(count));
+    assert_eq!(s.to_ascii_uppercase().parse::<DayCount>(), Ok(count));
+}
+
+#[rstest]
+#[case("mjd:60000", DayCount::Modified, 60000)]
+#[case("MJD:60000", DayCount::Modified, 60000)]
+#[case("rd:-5", DayCount::RataDie, -5)]
+#[case("unix:+19413", DayCount::Unix, 19413)]
+fn parse_day_number(#[case] s: &str, #[case] count: DayCount, #[case] value: Jdnum) {
+    let dn = s.parse::<DayNumber>().unwrap();
+    assert_eq!(dn.count(), count);
+    assert_eq!(dn.value(), value);
+}
+
+#[test]
+fn display_day_number() {
+    let dn = DayNumber::new(DayCount::RataDie, -5).unwrap();
+    assert_eq!(dn.to_string(), "rd:-5");
+    assert_eq!(dn.to_string().parse::<DayNumber>(), Ok(dn));
+}
+
+#[test]
+fn parse_day_number_errors() {
+    assert_eq!(
+        "60000".parse::<DayNumber>(),
+        Err(ParseDayNumberError::MissingSeparator)
+    );
+    assert_eq!(
+        "xjd:60000".parse::<DayNumber>(),
+        Err(ParseDayNumberError::DayCount(ParseDayCountError))
+    );
+    assert!(matches!(
+        "mjd:".parse::<DayNumber>(),
+        Err(ParseDayNumberError::ParseInt(_))
+    ));
+    assert!(matches!(
+        "mjd: 60000".parse::<DayNumber>(),
+        Err(ParseDayNumberError::ParseInt(_))
+    ));
+    assert_eq!(
+        "mjd:2147483647".parse::<DayNumber>(),
+        Err(ParseDayNumberError::OutOfRange)
+    );
+}
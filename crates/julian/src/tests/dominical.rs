@@ -0,0 +1,161 @@
+use crate::{
+    Calendar, Jdnum, Month, Weekday,
+    dominical::{DominicalLetter, DominicalLetters, dominical_letters, years_with_letter},
+    ncal,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(Calendar::GREGORIAN, 1900, "G")]
+#[case(Calendar::GREGORIAN, 2000, "BA")]
+#[case(Calendar::GREGORIAN, 2023, "A")]
+#[case(Calendar::GREGORIAN, 2024, "GF")]
+#[case(Calendar::GREGORIAN, 2025, "E")]
+#[case(Calendar::GREGORIAN, 0, "BA")]
+#[case(Calendar::GREGORIAN, -1, "C")]
+#[case(Calendar::JULIAN, 1, "B")]
+#[case(Calendar::JULIAN, 1582, "G")]
+#[case(Calendar::JULIAN, 1900, "BA")]
+#[case(Calendar::REFORM1582, 1581, "A")]
+#[case(Calendar::REFORM1582, 1582, "G/C")]
+#[case(Calendar::REFORM1582, 1583, "B")]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), 1752, "ED/A")]
+#[case(Calendar::reforming(ncal::GERMANY).unwrap(), 1700, "G/C")]
+#[case(Calendar::reforming(ncal::SWEDEN).unwrap(), 1753, "C/G")]
+#[case(Calendar::reforming(ncal::BULGARIA).unwrap(), 1916, "CB/A")]
+#[case(Calendar::reforming(ncal::RUSSIA).unwrap(), 1918, "G/F")]
+fn letters(#[case] cal: Calendar, #[case] year: i32, #[case] s: &str) {
+    let letters = dominical_letters(cal, year).unwrap();
+    assert_eq!(letters.to_string(), s);
+    assert_eq!(letters.first().to_string(), &s[..1]);
+    assert_eq!(letters.last().to_string(), &s[(s.len() - 1)..]);
+}
+
+#[test]
+fn transition_parts() {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    let Some(DominicalLetters::Transition(t)) = dominical_letters(cal, 1752) else {
+        panic!("1752 should be a transition year");
+    };
+    assert_eq!(
+        t.letters(),
+        [DominicalLetter::E, DominicalLetter::D, DominicalLetter::A]
+    );
+    assert_eq!(t.before(), [DominicalLetter::E, DominicalLetter::D]);
+    assert_eq!(t.after(), [DominicalLetter::A]);
+    assert_eq!(t.before_gap(), DominicalLetter::D);
+    assert_eq!(t.after_gap(), DominicalLetter::A);
+}
+
+#[test]
+fn gap_before_leap_day() {
+    // Bohemia & Moravia switched in January 1584, a leap year.
+    let cal = Calendar::reforming(ncal::CZECH_REPUBLIC).unwrap();
+    let Some(DominicalLetters::Transition(t)) = dominical_letters(cal, 1584) else {
+        panic!("1584 should be a transition year");
+    };
+    assert_eq!(t.before(), [DominicalLetter::E]);
+    assert_eq!(t.after(), [DominicalLetter::A, DominicalLetter::G]);
+}
+
+#[test]
+fn gap_of_whole_weeks() {
+    // In 2150, the Julian calendar is fourteen days behind the Gregorian, so
+    // a reformation then does not change the letter.
+    let jdn = Calendar::GREGORIAN
+        .at_ymd(2150, Month::June, 1)
+        .unwrap()
+        .julian_day_number();
+    let cal = Calendar::reforming(jdn).unwrap();
+    assert_eq!(
+        dominical_letters(cal, 2150),
+        dominical_letters(Calendar::GREGORIAN, 2150)
+    );
+    assert!(matches!(
+        dominical_letters(cal, 2150),
+        Some(DominicalLetters::Single(_))
+    ));
+}
+
+#[rstest]
+// Julian 2000-12-22 is followed by Gregorian 2001-01-05.
+#[case(
+    2000,
+    Some(DominicalLetters::Double(DominicalLetter::C, DominicalLetter::B))
+)]
+#[case(2001, Some(DominicalLetters::Single(DominicalLetter::G)))]
+fn gap_at_new_year(#[case] year: i32, #[case] letters: Option<DominicalLetters>) {
+    let jdn: Jdnum = Calendar::GREGORIAN
+        .at_ymd(2001, Month::January, 5)
+        .unwrap()
+        .julian_day_number();
+    let cal = Calendar::reforming(jdn).unwrap();
+    assert_eq!(dominical_letters(cal, year), letters);
+}
+
+#[test]
+fn skipped_year() {
+    let cal = Calendar::reforming(19582149).unwrap();
+    assert_eq!(dominical_letters(cal, 48901), None);
+}
+
+#[test]
+fn letter_for_date() {
+    let cal = Calendar::GREGORIAN;
+    for (month, day, letter) in [
+        (Month::January, 1, DominicalLetter::A),
+        (Month::January, 7, DominicalLetter::G),
+        (Month::January, 8, DominicalLetter::A),
+        (Month::February, 28, DominicalLetter::C),
+        (Month::February, 29, DominicalLetter::C),
+        (Month::March, 1, DominicalLetter::D),
+        (Month::December, 31, DominicalLetter::A),
+    ] {
+        let date = cal.at_ymd(2024, month, day).unwrap();
+        assert_eq!(DominicalLetter::for_date(&date), letter, "{month} {day}");
+    }
+}
+
+#[test]
+fn sundays_bear_letter() {
+    for year in 1990..2030 {
+        let letters = dominical_letters(Calendar::GREGORIAN, year).unwrap();
+        for date in Calendar::GREGORIAN
+            .at_ymd(year, Month::January, 1)
+            .unwrap()
+            .and_later()
+            .take_while(|d| d.year() == year)
+            .filter(|d| d.weekday() == Weekday::Sunday)
+            // February 29 is not lettered:
+            .filter(|d| !(d.month() == Month::February && d.day() == 29))
+        {
+            let expected = if date.month() <= Month::February {
+                letters.first()
+            } else {
+                letters.last()
+            };
+            assert_eq!(DominicalLetter::for_date(&date), expected, "{date}");
+        }
+    }
+}
+
+#[test]
+fn years_with_letter_forwards_backwards() {
+    let years = years_with_letter(Calendar::REFORM1582, DominicalLetter::C, 1575..=1600);
+    assert_eq!(
+        years.clone().collect::<Vec<_>>(),
+        [1580, 1582, 1588, 1593, 1599]
+    );
+    assert_eq!(
+        years.rev().collect::<Vec<_>>(),
+        [1599, 1593, 1588, 1582, 1580]
+    );
+}
+
+#[test]
+fn letter_pred_succ() {
+    assert_eq!(DominicalLetter::A.pred(), DominicalLetter::G);
+    assert_eq!(DominicalLetter::G.succ(), DominicalLetter::A);
+    assert_eq!(DominicalLetter::D.pred().succ(), DominicalLetter::D);
+    assert_eq!(format!("{:>3}", DominicalLetter::B), "  B");
+}
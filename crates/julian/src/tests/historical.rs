@@ -0,0 +1,136 @@
+use crate::{
+    Calendar, Jdnum, Month,
+    errors::DateError,
+    historical::{JULIAN_START_YEAR, Reconstruction},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(Reconstruction::Scaliger, 1704987, -45, Month::December, 31, 365)]
+#[case(Reconstruction::Scaliger, 1704988, -44, Month::January, 1, 1)]
+#[case(Reconstruction::Scaliger, 1705426, -43, Month::March, 15, 74)]
+#[case(Reconstruction::Scaliger, 1721424, 0, Month::December, 31, 365)]
+#[case(Reconstruction::Ideler, 1704987, -44, Month::January, 1, 1)]
+#[case(Reconstruction::Ideler, 1705426, -43, Month::March, 15, 74)]
+#[case(Reconstruction::Kepler, 1704988, -44, Month::January, 1, 1)]
+#[case(Reconstruction::Kepler, 1720000, -3, Month::February, 5, 36)]
+#[case(Reconstruction::Bennett, 1704986, -44, Month::January, 1, 1)]
+#[case(Reconstruction::Bennett, 1705426, -43, Month::March, 16, 76)]
+#[case(Reconstruction::Bennett, 1721424, 1, Month::January, 1, 1)]
+fn conversions(
+    #[case] reconstruction: Reconstruction,
+    #[case] jdn: Jdnum,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] ordinal: u32,
+) {
+    let date = reconstruction.at_jdn(jdn);
+    assert_eq!(date.reconstruction(), reconstruction);
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+    assert_eq!(date.ordinal(), ordinal);
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(reconstruction.at_ymd(year, month, day), Ok(date));
+    assert_eq!(reconstruction.at_ordinal_date(year, ordinal), Ok(date));
+}
+
+#[rstest]
+#[case(Reconstruction::Scaliger, 12)]
+#[case(Reconstruction::Ideler, 13)]
+#[case(Reconstruction::Kepler, 12)]
+#[case(Reconstruction::Bennett, 13)]
+fn leap_years(#[case] reconstruction: Reconstruction, #[case] count: usize) {
+    let leaps = reconstruction.leap_years();
+    assert_eq!(leaps.len(), count);
+    for year in JULIAN_START_YEAR..reconstruction.resumption_year() {
+        assert_eq!(reconstruction.is_leap_year(year), leaps.contains(&year));
+    }
+    for pair in leaps.windows(2) {
+        assert_eq!(pair[1] - pair[0], 3);
+    }
+}
+
+#[rstest]
+#[case(Reconstruction::Scaliger)]
+#[case(Reconstruction::Ideler)]
+#[case(Reconstruction::Kepler)]
+#[case(Reconstruction::Bennett)]
+fn after_resumption(#[case] reconstruction: Reconstruction) {
+    let year = reconstruction.resumption_year();
+    for y in year..(year + 8) {
+        for (month, day) in [
+            (Month::January, 1),
+            (Month::February, 28),
+            (Month::December, 31),
+        ] {
+            let date = reconstruction.at_ymd(y, month, day).unwrap();
+            assert!(date.matches_proleptic());
+            assert_eq!(
+                date.to_date(),
+                Calendar::JULIAN.at_ymd(y, month, day).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn proleptic_interpretation() {
+    let date = Reconstruction::Bennett
+        .at_ymd(-43, Month::March, 15)
+        .unwrap();
+    assert_eq!(date.julian_day_number(), 1705425);
+    assert_eq!(date.proleptic_julian_day_number(), Some(1705426));
+    assert!(!date.matches_proleptic());
+    assert_eq!(date.to_date().to_string(), "-043-03-14");
+    let date = Reconstruction::Ideler
+        .at_ymd(-43, Month::March, 15)
+        .unwrap();
+    assert!(date.matches_proleptic());
+}
+
+#[test]
+fn nonproleptic_leap_day() {
+    let date = Reconstruction::Scaliger
+        .at_ymd(-41, Month::February, 29)
+        .unwrap();
+    assert_eq!(date.ordinal(), 60);
+    assert_eq!(date.proleptic_julian_day_number(), None);
+    assert!(!date.matches_proleptic());
+    assert_eq!(date.to_date().to_string(), "-041-03-01");
+}
+
+#[test]
+fn skipped_leap_day() {
+    assert_eq!(
+        Reconstruction::Scaliger.at_ymd(-40, Month::February, 29),
+        Err(DateError::DayOutOfRange {
+            year: -40,
+            month: Month::February,
+            day: 29,
+            min_day: 1,
+            max_day: 28,
+        })
+    );
+    assert_eq!(
+        Reconstruction::Scaliger.at_ordinal_date(-40, 366),
+        Err(DateError::OrdinalOutOfRange {
+            year: -40,
+            ordinal: 366,
+            max_ordinal: 365,
+        })
+    );
+    assert_eq!(Reconstruction::Scaliger.year_length(4), 365);
+    assert_eq!(Reconstruction::Bennett.year_length(4), 366);
+}
+
+#[test]
+fn display() {
+    let date = Reconstruction::Kepler
+        .at_ymd(-9, Month::December, 31)
+        .unwrap();
+    assert_eq!(date.to_string(), "-009-12-31");
+    assert_eq!(format!("{date:#}"), "-009-366");
+    assert_eq!(Reconstruction::Kepler.to_string(), "Kepler");
+}
@@ -0,0 +1,398 @@
+use crate::{
+    Calendar, Jdnum, Month, YearKind,
+    errors::{LeapRuleError, ReformingError},
+    leap::{LeapClause, LeapRule},
+};
+use rstest::rstest;
+
+const CYCLE33: LeapRule = match LeapRule::from_cycle(33, &[1, 5, 9, 13, 17, 22, 26, 30]) {
+    Ok(rule) => rule,
+    Err(_) => panic!(),
+};
+
+#[rstest]
+#[case(&LeapRule::JULIAN, 1900, true)]
+#[case(&LeapRule::JULIAN, 1901, false)]
+#[case(&LeapRule::GREGORIAN, 1900, false)]
+#[case(&LeapRule::GREGORIAN, 2000, true)]
+#[case(&LeapRule::HERSCHEL, 2000, true)]
+#[case(&LeapRule::HERSCHEL, 2100, false)]
+#[case(&LeapRule::HERSCHEL, 4000, false)]
+#[case(&LeapRule::HERSCHEL, -4000, false)]
+#[case(&LeapRule::HERSCHEL, 4400, true)]
+#[case(&LeapRule::RULE128, 1900, true)]
+#[case(&LeapRule::RULE128, 1920, false)]
+#[case(&LeapRule::RULE128, 2048, false)]
+#[case(&LeapRule::RULE128, -128, false)]
+#[case(&LeapRule::RULE128, -124, true)]
+#[case(&CYCLE33, 1980, false)]
+#[case(&CYCLE33, 1981, true)]
+#[case(&CYCLE33, 2002, true)]
+#[case(&CYCLE33, 2003, false)]
+#[case(&CYCLE33, -32, true)]
+fn is_leap_year(#[case] rule: &'static LeapRule, #[case] year: i32, #[case] leap: bool) {
+    assert_eq!(rule.is_leap_year(year), leap);
+    let kind = if leap {
+        YearKind::Leap
+    } else {
+        YearKind::Common
+    };
+    let cal = Calendar::with_leap_rule(rule);
+    assert_eq!(cal.year_kind(year), kind);
+    assert_eq!(cal.year_length(year), if leap { 366 } else { 365 });
+}
+
+#[rstest]
+#[case(&LeapRule::HERSCHEL, 2000, 2451545)]
+#[case(&LeapRule::HERSCHEL, 1, 1721426)]
+#[case(&LeapRule::HERSCHEL, 0, 1721061)]
+#[case(&LeapRule::HERSCHEL, 4000, 3182030)]
+#[case(&LeapRule::HERSCHEL, 4001, 3182395)]
+#[case(&LeapRule::RULE128, 1900, 2415021)]
+#[case(&LeapRule::RULE128, 2000, 2451545)]
+#[case(&LeapRule::RULE128, 2100, 2488069)]
+#[case(&CYCLE33, 2000, 2451545)]
+#[case(&CYCLE33, 2001, 2451910)]
+#[case(&CYCLE33, 2002, 2452275)]
+#[case(&CYCLE33, 2003, 2452641)]
+#[case(&CYCLE33, 1999, 2451180)]
+fn new_year_jdn(#[case] rule: &'static LeapRule, #[case] year: i32, #[case] jdn: Jdnum) {
+    let cal = Calendar::with_leap_rule(rule);
+    let date = cal.at_ymd(year, Month::January, 1).unwrap();
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(cal.at_jdn(jdn), date);
+    let prev = cal.at_jdn(jdn - 1);
+    assert_eq!(prev.year(), year - 1);
+    assert_eq!(prev.month(), Month::December);
+    assert_eq!(prev.day(), 31);
+    assert_eq!(prev.ordinal(), cal.year_length(year - 1));
+}
+
+#[rstest]
+#[case(&LeapRule::HERSCHEL)]
+#[case(&LeapRule::RULE128)]
+#[case(&CYCLE33)]
+fn consecutive_days(#[case] rule: &'static LeapRule) {
+    let cal = Calendar::with_leap_rule(rule);
+    for start in [-1_000_000, 1_700_000, 3_180_000] {
+        let mut date = cal.at_jdn(start);
+        for jdn in start..start + 5000 {
+            assert_eq!(date.julian_day_number(), jdn);
+            assert_eq!(cal.at_jdn(jdn), date);
+            assert_eq!(cal.at_ordinal_date(date.year(), date.ordinal()), Ok(date));
+            assert_eq!(cal.at_ymd(date.year(), date.month(), date.day()), Ok(date));
+            date = date.succ().unwrap();
+        }
+    }
+}
+
+#[rstest]
+#[case(&LeapRule::HERSCHEL)]
+#[case(&LeapRule::RULE128)]
+#[case(&CYCLE33)]
+fn extremes(#[case] rule: &'static LeapRule) {
+    let cal = Calendar::with_leap_rule(rule);
+    for jdn in [Jdnum::MIN, Jdnum::MAX] {
+        let date = cal.at_jdn(jdn);
+        assert_eq!(cal.at_ordinal_date(date.year(), date.ordinal()), Ok(date));
+    }
+    assert_eq!(cal.at_jdn(Jdnum::MAX).succ(), None);
+    assert_eq!(cal.at_jdn(Jdnum::MIN).pred(), None);
+}
+
+#[test]
+fn from_clauses_gregorian() {
+    assert_eq!(
+        LeapRule::from_clauses(&[
+            LeapClause::Include(4),
+            LeapClause::Exclude(100),
+            LeapClause::Include(400),
+        ]),
+        Ok(LeapRule::GREGORIAN)
+    );
+}
+
+#[test]
+fn from_clauses_herschel() {
+    assert_eq!(
+        LeapRule::from_clauses(&[
+            LeapClause::Include(4),
+            LeapClause::Exclude(100),
+            LeapClause::Include(400),
+            LeapClause::Exclude(4000),
+        ]),
+        Ok(LeapRule::HERSCHEL)
+    );
+}
+
+#[test]
+fn from_clauses_julian() {
+    static RULE: LeapRule = match LeapRule::from_clauses(&[LeapClause::Include(4)]) {
+        Ok(rule) => rule,
+        Err(_) => panic!(),
+    };
+    assert_eq!(RULE, LeapRule::JULIAN);
+    let cal = Calendar::with_leap_rule(&RULE);
+    assert_eq!(cal, Calendar::JULIAN);
+    for jdn in [
+        Jdnum::MIN,
+        0,
+        1721424,
+        2299160,
+        2451545,
+        2460000,
+        Jdnum::MAX,
+    ] {
+        let date = cal.at_jdn(jdn);
+        assert_eq!(date, Calendar::JULIAN.at_jdn(jdn));
+        assert_eq!(
+            cal.at_ymd(date.year(), date.month(), date.day())
+                .map(|d| d.julian_day_number()),
+            Ok(jdn)
+        );
+    }
+}
+
+#[test]
+fn from_clauses_empty() {
+    static RULE: LeapRule = match LeapRule::from_clauses(&[]) {
+        Ok(rule) => rule,
+        Err(_) => panic!(),
+    };
+    let cal = Calendar::with_leap_rule(&RULE);
+    assert_eq!(cal.year_length(2000), 365);
+    assert_eq!(cal.year_length(2004), 365);
+    let date = cal.at_ymd(2001, Month::January, 1).unwrap();
+    assert_eq!(date.julian_day_number(), 2451545 + 365);
+}
+
+#[test]
+fn from_clauses_zero_divisor() {
+    assert_eq!(
+        LeapRule::from_clauses(&[LeapClause::Include(4), LeapClause::Exclude(0)]),
+        Err(LeapRuleError::ZeroDivisor)
+    );
+}
+
+#[test]
+fn from_clauses_unnested() {
+    let r = LeapRule::from_clauses(&[LeapClause::Include(4), LeapClause::Exclude(6)]);
+    assert_eq!(
+        r,
+        Err(LeapRuleError::UnnestedDivisor {
+            divisor: 6,
+            previous: 4
+        })
+    );
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "leap rule divisor 6 is not a multiple of preceding divisor 4"
+    );
+}
+
+/// The leap years within the 400-year Gregorian cycle
+const GREGORIAN_CYCLE: [u32; 97] = {
+    let mut years = [0; 97];
+    let mut i = 0;
+    let mut year = 0;
+    while year < 400 {
+        if year % 4 == 0 && (year % 100 != 0 || year == 0) {
+            years[i] = year;
+            i += 1;
+        }
+        year += 1;
+    }
+    years
+};
+
+#[test]
+fn from_cycle_julian() {
+    static RULE: LeapRule = match LeapRule::from_cycle(4, &[0]) {
+        Ok(rule) => rule,
+        Err(_) => panic!(),
+    };
+    assert_eq!(RULE, LeapRule::JULIAN);
+    let cal = Calendar::with_leap_rule(&RULE);
+    assert_eq!(cal, Calendar::JULIAN);
+    let date = cal.at_ymd(2024, Month::March, 1).unwrap();
+    assert_eq!(date.julian_day_number(), 2460384);
+    assert_eq!(LeapRule::from_cycle(8, &[0, 4]), Ok(LeapRule::JULIAN));
+}
+
+#[test]
+fn from_cycle_gregorian() {
+    static RULE: LeapRule = match LeapRule::from_cycle(400, &GREGORIAN_CYCLE) {
+        Ok(rule) => rule,
+        Err(_) => panic!(),
+    };
+    assert_eq!(RULE, LeapRule::GREGORIAN);
+    let cal = Calendar::with_leap_rule(&RULE);
+    assert_eq!(cal, Calendar::GREGORIAN);
+    let date = cal.at_ymd(2024, Month::March, 1).unwrap();
+    assert_eq!(date.julian_day_number(), 2460371);
+}
+
+#[test]
+fn from_cycle_near_julian() {
+    // Same number of leap years as the Julian rule, but in different places:
+    let rule = LeapRule::from_cycle(8, &[1, 5]).unwrap();
+    assert_ne!(rule, LeapRule::JULIAN);
+    assert!(rule.is_leap_year(2001));
+}
+
+#[test]
+fn from_cycle_zero_period() {
+    assert_eq!(LeapRule::from_cycle(0, &[]), Err(LeapRuleError::ZeroPeriod));
+}
+
+#[rstest]
+#[case(&[1, 5, 33], 33)]
+#[case(&[5, 1], 1)]
+#[case(&[1, 1], 1)]
+fn from_cycle_invalid_year(#[case] leap_years: &'static [u32], #[case] year: u32) {
+    assert_eq!(
+        LeapRule::from_cycle(33, leap_years),
+        Err(LeapRuleError::InvalidCycleYear { year, period: 33 })
+    );
+}
+
+#[test]
+fn with_leap_rule_builtin() {
+    assert_eq!(
+        Calendar::with_leap_rule(&LeapRule::JULIAN),
+        Calendar::JULIAN
+    );
+    assert_eq!(
+        Calendar::with_leap_rule(&LeapRule::GREGORIAN),
+        Calendar::GREGORIAN
+    );
+    assert_eq!(Calendar::JULIAN.leap_rule(), LeapRule::JULIAN);
+    assert_eq!(Calendar::GREGORIAN.leap_rule(), LeapRule::GREGORIAN);
+}
+
+#[test]
+fn custom_properties() {
+    let cal = Calendar::with_leap_rule(&LeapRule::HERSCHEL);
+    assert!(cal.is_proleptic());
+    assert!(!cal.is_reforming());
+    assert_eq!(cal.leap_rule(), LeapRule::HERSCHEL);
+    assert_eq!(cal.reformation(), None);
+    let date = cal.at_jdn(2460000);
+    assert!(!date.is_julian());
+    assert!(!date.is_gregorian());
+}
+
+#[test]
+fn reforming_with_gregorian_rule() {
+    assert_eq!(
+        Calendar::reforming_with_rule(2299161, &LeapRule::GREGORIAN),
+        Ok(Calendar::REFORM1582)
+    );
+}
+
+#[test]
+fn reforming_with_herschel() {
+    let cal = Calendar::reforming_with_rule(2460000, &LeapRule::HERSCHEL).unwrap();
+    assert!(cal.is_reforming());
+    assert_eq!(cal.reformation(), Some(2460000));
+    assert_eq!(cal.leap_rule(), LeapRule::HERSCHEL);
+    let last_julian = cal.last_julian_date().unwrap();
+    let first_new = cal.first_gregorian_date().unwrap();
+    assert_eq!(last_julian.to_string(), "2023-02-10");
+    assert_eq!(first_new.to_string(), "2023-02-24");
+    assert_eq!(last_julian.succ(), Some(first_new));
+    assert_eq!(first_new.pred(), Some(last_julian));
+    assert!(last_julian.is_julian());
+    assert!(!first_new.is_julian());
+    assert!(!first_new.is_gregorian());
+    assert_eq!(cal.year_kind(2023), YearKind::ReformCommon);
+    assert_eq!(cal.year_length(2023), 352);
+    assert!(cal.year_kind(3600).is_leap());
+    assert!(!cal.year_kind(4000).is_leap());
+    assert_eq!(cal.at_jdn(3182030).to_string(), "4000-01-01");
+}
+
+#[test]
+fn reforming_with_rule128() {
+    // 1900 is a leap year under both the Julian calendar and the 128-year
+    // rule, so February 29 is kept.
+    let cal = Calendar::reforming_with_rule(2415100, &LeapRule::RULE128).unwrap();
+    let last_julian = cal.last_julian_date().unwrap();
+    let first_new = cal.first_gregorian_date().unwrap();
+    assert_eq!(last_julian.to_string(), "1900-03-07");
+    assert_eq!(first_new.to_string(), "1900-03-20");
+    assert_eq!(last_julian.succ(), Some(first_new));
+    assert_eq!(cal.year_kind(1900), YearKind::ReformLeap);
+    assert_eq!(cal.year_length(1900), 354);
+    let days_in_year = cal
+        .at_ymd(1900, Month::January, 1)
+        .unwrap()
+        .and_later()
+        .take_while(|d| d.year() == 1900)
+        .count();
+    assert_eq!(u32::try_from(days_in_year).unwrap(), cal.year_length(1900));
+}
+
+#[rstest]
+#[case(2299161)]
+#[case(2460000)]
+#[case(1830692)]
+fn reforming_with_julian_rule(#[case] reformation: Jdnum) {
+    assert_eq!(
+        Calendar::reforming_with_rule(reformation, &LeapRule::JULIAN),
+        Err(ReformingError::InvalidReformation)
+    );
+}
+
+#[test]
+fn reforming_with_cycle() {
+    let cal = Calendar::reforming_with_rule(2451545, &CYCLE33).unwrap();
+    assert_eq!(cal.last_julian_date().unwrap().to_string(), "1999-12-18");
+    assert_eq!(
+        cal.first_gregorian_date().unwrap().to_string(),
+        "2000-01-01"
+    );
+    assert_eq!(cal.year_kind(1999), YearKind::ReformCommon);
+    assert_eq!(cal.year_length(1999), 352);
+    assert_eq!(cal.year_kind(2000), YearKind::Common);
+    assert_eq!(cal.year_kind(2002), YearKind::Leap);
+}
+
+#[test]
+fn ordering() {
+    let herschel = Calendar::with_leap_rule(&LeapRule::HERSCHEL);
+    let herschel_reform = Calendar::reforming_with_rule(2460000, &LeapRule::HERSCHEL).unwrap();
+    let herschel_reform_early =
+        Calendar::reforming_with_rule(2299161, &LeapRule::HERSCHEL).unwrap();
+    let cycle = Calendar::with_leap_rule(&CYCLE33);
+    let mut calendars = [
+        cycle,
+        herschel,
+        Calendar::GREGORIAN,
+        herschel_reform,
+        Calendar::REFORM1582,
+        herschel_reform_early,
+        Calendar::JULIAN,
+    ];
+    calendars.sort();
+    assert_eq!(
+        calendars,
+        [
+            Calendar::JULIAN,
+            Calendar::REFORM1582,
+            Calendar::GREGORIAN,
+            herschel_reform_early,
+            herschel_reform,
+            herschel,
+            cycle,
+        ]
+    );
+    assert_ne!(Calendar::REFORM1582, herschel_reform_early);
+}
+
+#[test]
+fn rule_ordering() {
+    assert!(LeapRule::JULIAN < LeapRule::GREGORIAN);
+    assert!(LeapRule::GREGORIAN < LeapRule::HERSCHEL);
+    assert!(LeapRule::HERSCHEL < CYCLE33);
+}
@@ -0,0 +1,237 @@
+use crate::{
+    Month, Weekday,
+    errors::{ParseLocaleError, ParseMonthError, ParseWeekdayError},
+    iter::MonthIter,
+    locale::{Locale, parse_month, parse_weekday},
+};
+use rstest::rstest;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+#[rstest]
+#[case(Locale::English, Month::September, "September", "Sep", "September")]
+#[case(Locale::Latin, Month::January, "Ianuarius", "Ian.", "Ianuarii")]
+#[case(Locale::Latin, Month::October, "October", "Oct.", "Octobris")]
+#[case(Locale::French, Month::December, "décembre", "déc.", "décembre")]
+#[case(Locale::German, Month::March, "März", "März", "März")]
+#[case(Locale::Russian, Month::November, "ноябрь", "нояб.", "ноября")]
+#[case(Locale::Swedish, Month::May, "maj", "maj", "maj")]
+#[case(Locale::Dutch, Month::March, "maart", "mrt.", "maart")]
+#[case(Locale::Italian, Month::June, "giugno", "giu", "giugno")]
+fn month_names(
+    #[case] locale: Locale,
+    #[case] month: Month,
+    #[case] name: &str,
+    #[case] short: &str,
+    #[case] genitive: &str,
+) {
+    assert_eq!(locale.month_name(month), name);
+    assert_eq!(locale.month_short_name(month), short);
+    assert_eq!(locale.month_genitive(month), genitive);
+    assert_eq!(month.localized(locale).to_string(), name);
+    assert_eq!(format!("{:#}", month.localized(locale)), short);
+}
+
+#[rstest]
+#[case(Locale::English, Weekday::Thursday, "Thursday", "Thu")]
+#[case(Locale::Latin, Weekday::Sunday, "dies Solis", "Sol.")]
+#[case(Locale::French, Weekday::Tuesday, "mardi", "mar.")]
+#[case(Locale::German, Weekday::Saturday, "Samstag", "Sa.")]
+#[case(Locale::Russian, Weekday::Monday, "понедельник", "пн")]
+#[case(Locale::Swedish, Weekday::Saturday, "lördag", "lör")]
+#[case(Locale::Dutch, Weekday::Friday, "vrijdag", "vr")]
+#[case(Locale::Italian, Weekday::Friday, "venerdì", "ven")]
+fn weekday_names(
+    #[case] locale: Locale,
+    #[case] weekday: Weekday,
+    #[case] name: &str,
+    #[case] short: &str,
+) {
+    assert_eq!(locale.weekday_name(weekday), name);
+    assert_eq!(locale.weekday_short_name(weekday), short);
+    assert_eq!(weekday.localized(locale).to_string(), name);
+    assert_eq!(format!("{:#}", weekday.localized(locale)), short);
+}
+
+#[test]
+fn localized_padding() {
+    let month = Month::August.localized(Locale::French);
+    assert_eq!(format!("[{month:>6}]"), "[  août]");
+    assert_eq!(format!("[{month:-<6}]"), "[août--]");
+    let wd = Weekday::Monday.localized(Locale::Russian);
+    assert_eq!(format!("[{wd:^#6}]"), "[  пн  ]");
+    assert_eq!(wd.value(), Weekday::Monday);
+    assert_eq!(wd.locale(), Locale::Russian);
+}
+
+#[rstest]
+fn month_round_trip(
+    #[values(
+        Locale::English,
+        Locale::Latin,
+        Locale::French,
+        Locale::German,
+        Locale::Russian,
+        Locale::Swedish,
+        Locale::Dutch,
+        Locale::Italian
+    )]
+    locale: Locale,
+) {
+    for month in MonthIter::new() {
+        for name in [
+            locale.month_name(month),
+            locale.month_short_name(month),
+            locale.month_genitive(month),
+        ] {
+            assert_eq!(locale.parse_month(name), Ok(month), "{name}");
+            assert_eq!(
+                locale.parse_month(&name.to_uppercase()),
+                Ok(month),
+                "{name}"
+            );
+            assert_eq!(
+                locale.parse_month(&name.to_lowercase()),
+                Ok(month),
+                "{name}"
+            );
+            let bare = name.strip_suffix('.').unwrap_or(name);
+            assert_eq!(locale.parse_month(bare), Ok(month), "{name}");
+            // No name means different months in different languages:
+            assert_eq!(parse_month(name).map(|(m, _)| m), Ok(month), "{name}");
+        }
+    }
+}
+
+#[rstest]
+fn weekday_round_trip(
+    #[values(
+        Locale::English,
+        Locale::Latin,
+        Locale::French,
+        Locale::German,
+        Locale::Russian,
+        Locale::Swedish,
+        Locale::Dutch,
+        Locale::Italian
+    )]
+    locale: Locale,
+) {
+    for weekday in WEEKDAYS {
+        for name in [
+            locale.weekday_name(weekday),
+            locale.weekday_short_name(weekday),
+        ] {
+            assert_eq!(locale.parse_weekday(name), Ok(weekday), "{name}");
+            assert_eq!(
+                locale.parse_weekday(&name.to_uppercase()),
+                Ok(weekday),
+                "{name}"
+            );
+            let bare = name.strip_suffix('.').unwrap_or(name);
+            assert_eq!(locale.parse_weekday(bare), Ok(weekday), "{name}");
+            // No name means different weekdays in different languages:
+            assert_eq!(parse_weekday(name).map(|(wd, _)| wd), Ok(weekday), "{name}");
+        }
+    }
+}
+
+#[rstest]
+#[case(Locale::French, "fevrier", Month::February)]
+#[case(Locale::French, "FÉVR.", Month::February)]
+#[case(Locale::French, "aout", Month::August)]
+#[case(Locale::German, "marz", Month::March)]
+#[case(Locale::German, "MÄRZ", Month::March)]
+#[case(Locale::Latin, "Januarius", Month::January)]
+#[case(Locale::Latin, "IVLII", Month::July)]
+#[case(Locale::Latin, "Jun", Month::June)]
+#[case(Locale::Russian, "ЯНВАРЯ", Month::January)]
+#[case(Locale::Russian, "янв", Month::January)]
+fn parse_month_folded(#[case] locale: Locale, #[case] s: &str, #[case] month: Month) {
+    assert_eq!(locale.parse_month(s), Ok(month));
+}
+
+#[rstest]
+#[case(Locale::Swedish, "mandag", Weekday::Monday)]
+#[case(Locale::Swedish, "LÖRDAG", Weekday::Saturday)]
+#[case(Locale::Italian, "lunedi", Weekday::Monday)]
+#[case(Locale::Latin, "Dies Iovis", Weekday::Thursday)]
+#[case(Locale::Latin, "DIES LVNAE", Weekday::Monday)]
+fn parse_weekday_folded(#[case] locale: Locale, #[case] s: &str, #[case] weekday: Weekday) {
+    assert_eq!(locale.parse_weekday(s), Ok(weekday));
+}
+
+#[rstest]
+#[case(Locale::English, "")]
+#[case(Locale::English, ".")]
+#[case(Locale::English, "janvier")]
+#[case(Locale::English, "Ja")]
+#[case(Locale::English, "Jan..")]
+#[case(Locale::French, "janvie")]
+#[case(Locale::German, "Maerz")]
+#[case(Locale::Russian, "yanvar")]
+#[case(Locale::Latin, "Ianuar")]
+fn parse_month_invalid(#[case] locale: Locale, #[case] s: &str) {
+    assert_eq!(locale.parse_month(s), Err(ParseMonthError));
+}
+
+#[rstest]
+#[case(Locale::English, "")]
+#[case(Locale::English, "lundi")]
+#[case(Locale::German, "Mont")]
+#[case(Locale::Latin, "Lunae")]
+#[case(Locale::Dutch, "m")]
+fn parse_weekday_invalid(#[case] locale: Locale, #[case] s: &str) {
+    assert_eq!(locale.parse_weekday(s), Err(ParseWeekdayError));
+}
+
+#[rstest]
+#[case("Mar", Month::March, Locale::English)]
+#[case("Mai.", Month::May, Locale::Latin)]
+#[case("juillet", Month::July, Locale::French)]
+#[case("Dezember", Month::December, Locale::German)]
+#[case("август", Month::August, Locale::Russian)]
+#[case("januari", Month::January, Locale::Swedish)]
+#[case("mei", Month::May, Locale::Dutch)]
+#[case("gennaio", Month::January, Locale::Italian)]
+fn parse_month_any(#[case] s: &str, #[case] month: Month, #[case] locale: Locale) {
+    assert_eq!(parse_month(s), Ok((month, locale)));
+}
+
+#[test]
+fn parse_any_invalid() {
+    assert_eq!(parse_month("Smarch"), Err(ParseMonthError));
+    assert_eq!(parse_weekday("Caturday"), Err(ParseWeekdayError));
+}
+
+#[rstest]
+#[case("en", Locale::English)]
+#[case("LA", Locale::Latin)]
+#[case("fr", Locale::French)]
+#[case("German", Locale::German)]
+#[case("russian", Locale::Russian)]
+#[case("sv", Locale::Swedish)]
+#[case("nl", Locale::Dutch)]
+#[case("IT", Locale::Italian)]
+fn parse_locale(#[case] s: &str, #[case] locale: Locale) {
+    assert_eq!(s.parse::<Locale>(), Ok(locale));
+    assert_eq!(locale.code().parse::<Locale>(), Ok(locale));
+    assert_eq!(locale.to_string(), locale.code());
+}
+
+#[rstest]
+#[case("")]
+#[case("pl")]
+#[case("en-US")]
+#[case("Français")]
+fn parse_locale_invalid(#[case] s: &str) {
+    assert_eq!(s.parse::<Locale>(), Err(ParseLocaleError));
+}
@@ -1,6 +1,7 @@
 use crate::{
-    Calendar, Month,
+    Calendar, Month, Style, StylePolicy,
     errors::{DateError, ParseDateError},
+    ncal,
 };
 use assert_matches::assert_matches;
 use rstest::rstest;
@@ -250,7 +251,9 @@ fn skipped_date() {
         Err(ParseDateError::InvalidDate(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 10
+            day: 10,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         }))
     );
     assert_eq!(
@@ -267,7 +270,9 @@ fn first_skipped_date() {
         Err(ParseDateError::InvalidDate(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 5
+            day: 5,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         }))
     );
     assert_eq!(
@@ -284,7 +289,9 @@ fn last_skipped_date() {
         Err(ParseDateError::InvalidDate(DateError::SkippedDate {
             year: 1582,
             month: Month::October,
-            day: 14
+            day: 14,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
         }))
     );
     assert_eq!(
@@ -460,3 +467,113 @@ fn trailing() {
     assert_eq!(r, Err(ParseDateError::Trailing));
     assert_eq!(r.unwrap_err().to_string(), "trailing characters after date");
 }
+
+#[rstest]
+#[case(Calendar::REFORM1582, "1582-10-04 O.S.", "1582-10-04")]
+#[case(Calendar::REFORM1582, "1582-10-15 N.S.", "1582-10-15")]
+#[case(Calendar::REFORM1582, "1582-10-10 O.S.", "1582-10-20")]
+#[case(Calendar::REFORM1582, "1582-283 O.S.", "1582-10-20")]
+#[case(Calendar::REFORM1582, "1582-10-10 N.S.", "1582-09-30")]
+#[case(Calendar::REFORM1582, "1582-10-10  os", "1582-10-20")]
+#[case(Calendar::REFORM1582, "1582-10-10\tJulian", "1582-10-20")]
+#[case(Calendar::REFORM1582, "1582-10-10 gregorian", "1582-09-30")]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-03 O.S.", "1752-09-14")]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-14 N.S.", "1752-09-14")]
+#[case(Calendar::GREGORIAN, "2023-04-17 Julian", "2023-04-30")]
+#[case(Calendar::GREGORIAN, "2023-04-30 NS", "2023-04-30")]
+#[case(Calendar::JULIAN, "2023-04-30 N.S.", "2023-04-17")]
+fn style_marker(#[case] cal: Calendar, #[case] s: &str, #[case] expected: &str) {
+    let date = cal.parse_date(s).unwrap();
+    assert_eq!(date.to_string(), expected);
+    assert_eq!(
+        cal.parse_date_with_policy(s, StylePolicy::Convert),
+        Ok(date)
+    );
+}
+
+#[rstest]
+#[case(Calendar::REFORM1582, "1582-10-04 O.S.")]
+#[case(Calendar::REFORM1582, "1582-10-15 N.S.")]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-02 O.S.")]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-14 N.S.")]
+#[case(Calendar::GREGORIAN, "2023-04-30 N.S.")]
+#[case(Calendar::JULIAN, "2023-04-30 O.S.")]
+#[case(Calendar::JULIAN, "2023-04-30")]
+fn style_marker_match(#[case] cal: Calendar, #[case] s: &str) {
+    let date = cal.parse_date_with_policy(s, StylePolicy::Reject).unwrap();
+    assert_eq!(Ok(date), cal.parse_date(s));
+}
+
+#[rstest]
+#[case(Calendar::REFORM1582, "1582-10-10 O.S.", Style::Old)]
+#[case(Calendar::REFORM1582, "1582-10-10 N.S.", Style::New)]
+#[case(Calendar::REFORM1582, "1582-283 O.S.", Style::Old)]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-03 O.S.", Style::Old)]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap(), "1752-09-13 N.S.", Style::New)]
+#[case(Calendar::GREGORIAN, "2023-04-17 Julian", Style::Old)]
+#[case(Calendar::JULIAN, "2023-04-30 Gregorian", Style::New)]
+fn style_marker_mismatch(#[case] cal: Calendar, #[case] s: &str, #[case] style: Style) {
+    let r = cal.parse_date_with_policy(s, StylePolicy::Reject);
+    assert_eq!(r, Err(ParseDateError::StyleMismatch { style }));
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        format!("date marked {style} is not in that style in this calendar")
+    );
+}
+
+#[test]
+fn style_marker_invalid_date() {
+    let r = Calendar::JULIAN.parse_date("1900-02-29 N.S.");
+    assert_matches!(
+        r,
+        Err(ParseDateError::InvalidDate(DateError::DayOutOfRange {
+            year: 1900,
+            month: Month::February,
+            day: 29,
+            ..
+        }))
+    );
+}
+
+#[test]
+fn unmarked_skipped_date() {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    assert_eq!(
+        cal.parse_date_with_policy("1752-09-03", StylePolicy::Reject),
+        Err(ParseDateError::InvalidDate(DateError::SkippedDate {
+            year: 1752,
+            month: Month::September,
+            day: 3,
+            before: (1752, Month::September, 2),
+            after: (1752, Month::September, 14),
+        }))
+    );
+}
+
+#[rstest]
+#[case("2023-04-30O.S.")]
+#[case("2023-04-30 X.S.")]
+#[case("2023-04-30 O.S. N.S.")]
+#[case("2023-04-30 Old Style")]
+fn style_marker_trailing(#[case] s: &str) {
+    assert_eq!(
+        Calendar::REFORM1582.parse_date(s),
+        Err(ParseDateError::Trailing)
+    );
+}
+
+#[test]
+fn style_marker_only() {
+    assert_eq!(
+        Calendar::REFORM1582.parse_date(" O.S."),
+        Err(ParseDateError::EmptyInt)
+    );
+}
+
+#[test]
+fn style_display() {
+    assert_eq!(Style::Old.to_string(), "O.S.");
+    assert_eq!(format!("{:>6}", Style::New), "  N.S.");
+    assert_eq!(Style::Old.calendar(), Calendar::JULIAN);
+    assert_eq!(Style::New.calendar(), Calendar::GREGORIAN);
+}
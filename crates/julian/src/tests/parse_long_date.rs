@@ -0,0 +1,232 @@
+use crate::{
+    Calendar, Month, Weekday,
+    errors::{DateError, ParseLongDateError},
+    ncal,
+};
+use assert_matches::assert_matches;
+use rstest::rstest;
+
+#[rstest]
+#[case("April 30, 2023")]
+#[case("April 30th 2023")]
+#[case("Apr. 30 2023")]
+#[case("30 April 2023")]
+#[case("30th of April, 2023")]
+#[case("the 30th of April 2023")]
+#[case("Sunday, April 30, 2023")]
+#[case("Sun 30 Apr 2023")]
+#[case("SUNDAY, THE 30TH OF APRIL, 2023")]
+#[case("30. April 2023")]
+#[case("Sonntag, 30. April 2023")]
+#[case("dimanche 30 avril 2023")]
+#[case("воскресенье, 30 апреля 2023 г.")]
+#[case("söndag 30 april 2023")]
+#[case("zondag 30 april 2023")]
+#[case("domenica 30 aprile 2023")]
+#[case("dies Solis, 30 Aprilis 2023")]
+#[case("30 Aprilis AD 2023")]
+#[case("April 30, 2023 CE")]
+#[case("30/04/2023")]
+#[case("04/30/2023")]
+#[case("30.04.2023")]
+#[case("30-4-2023")]
+#[case("2023-04-30")]
+#[case("2023/4/30")]
+#[case("  April   30 ,2023  ")]
+fn april_30_2023(#[case] s: &str) {
+    let date = Calendar::GREGORIAN.parse_long_date(s).unwrap();
+    assert_eq!(date.year(), 2023);
+    assert_eq!(date.month(), Month::April);
+    assert_eq!(date.day(), 30);
+}
+
+#[rstest]
+#[case("Mar 3, 2023", Month::March, 3)]
+#[case("mar. 7 mars 2023", Month::March, 7)]
+#[case("1er mai 2023", Month::May, 1)]
+#[case("2nd June 2023", Month::June, 2)]
+#[case("Di. 3. Okt. 2023", Month::October, 3)]
+#[case("05/05/2023", Month::May, 5)]
+#[case("13/05/2023", Month::May, 13)]
+#[case("05/13/2023", Month::May, 13)]
+#[case("Monday 03/04/2023", Month::April, 3)]
+#[case("Saturday 03/04/2023", Month::March, 4)]
+fn gregorian_2023(#[case] s: &str, #[case] month: Month, #[case] day: u32) {
+    let date = Calendar::GREGORIAN.parse_long_date(s).unwrap();
+    assert_eq!(date.year(), 2023);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+}
+
+#[rstest]
+#[case("15 March 44 BC", -43)]
+#[case("15 March 44 B.C.", -43)]
+#[case("15 March BCE 44", -43)]
+#[case("15 March 1 BC", 0)]
+#[case("15 March AD 1", 1)]
+#[case("15/03/44 BC", -43)]
+#[case("15 March 0", 0)]
+fn eras(#[case] s: &str, #[case] year: i32) {
+    let date = Calendar::JULIAN.parse_long_date(s).unwrap();
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), Month::March);
+    assert_eq!(date.day(), 15);
+}
+
+#[rstest]
+#[case(Calendar::REFORM1582, "October 4, 1582 O.S.", "1582-10-04")]
+#[case(Calendar::REFORM1582, "October 15, 1582 N.S.", "1582-10-15")]
+#[case(Calendar::REFORM1582, "October 10, 1582 N.S.", "1582-09-30")]
+#[case(Calendar::REFORM1582, "October 10, 1582 O.S.", "1582-10-20")]
+#[case(Calendar::REFORM1582, "October 10, 1582 Old Style", "1582-10-20")]
+#[case(Calendar::REFORM1582, "10 October 1582 (NS)", "1582-09-30")]
+#[case(Calendar::REFORM1582, "10 October 1582 Julian", "1582-10-20")]
+#[case(Calendar::GREGORIAN, "1 January 1700 O.S.", "1700-01-11")]
+#[case(Calendar::JULIAN, "11 January 1700 New Style", "1700-01-01")]
+#[case(Calendar::JULIAN, "1 January 1700 OS", "1700-01-01")]
+fn styles(#[case] cal: Calendar, #[case] s: &str, #[case] date: &str) {
+    assert_eq!(cal.parse_long_date(s).unwrap().to_string(), date);
+}
+
+#[test]
+fn ambiguous() {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    assert_eq!(
+        cal.parse_long_date("03/04/1752"),
+        Err(ParseLongDateError::Ambiguous {
+            day_first: (1752, Month::April, 3),
+            month_first: (1752, Month::March, 4),
+        })
+    );
+    assert_eq!(
+        cal.parse_long_date("03/04/1752").unwrap_err().to_string(),
+        "ambiguous date: could be 1752-04-03 or 1752-03-04"
+    );
+    // Neither reading falls on a Sunday:
+    assert_eq!(
+        cal.parse_long_date("Sunday 03/04/1752"),
+        Err(ParseLongDateError::WeekdayMismatch {
+            weekday: Weekday::Sunday,
+            actual: Weekday::Friday,
+            date: (1752, Month::April, 3),
+        })
+    );
+    let date = cal.parse_long_date("Friday 03/04/1752").unwrap();
+    assert_eq!(date.to_string(), "1752-04-03");
+    let date = cal.parse_long_date("Wednesday 03/04/1752").unwrap();
+    assert_eq!(date.to_string(), "1752-03-04");
+}
+
+#[test]
+fn ambiguous_one_skipped() {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    // 1752-09-03 was skipped, so this can only be March 9:
+    let date = cal.parse_long_date("03/09/1752").unwrap();
+    assert_eq!(date.to_string(), "1752-03-09");
+    // Likewise, 1752-09-05 was skipped, so this can only be May 9:
+    let date = cal.parse_long_date("05/09/1752").unwrap();
+    assert_eq!(date.to_string(), "1752-05-09");
+    // The remaining reading is still checked against the weekday:
+    assert_eq!(
+        cal.parse_long_date("Thursday 05/09/1752"),
+        Err(ParseLongDateError::WeekdayMismatch {
+            weekday: Weekday::Thursday,
+            actual: Weekday::Saturday,
+            date: (1752, Month::May, 9),
+        })
+    );
+}
+
+#[test]
+fn weekday_mismatch() {
+    let cal = Calendar::GREGORIAN;
+    let e = cal.parse_long_date("Monday, April 30, 2023").unwrap_err();
+    assert_eq!(
+        e,
+        ParseLongDateError::WeekdayMismatch {
+            weekday: Weekday::Monday,
+            actual: Weekday::Sunday,
+            date: (2023, Month::April, 30),
+        }
+    );
+    assert_eq!(
+        e.to_string(),
+        "weekday mismatch: 2023-04-30 is a Sunday, not a Monday"
+    );
+}
+
+#[test]
+fn skipped_date() {
+    let cal = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    assert_eq!(
+        cal.parse_long_date("Sept. 5, 1752"),
+        Err(ParseLongDateError::InvalidDate(DateError::SkippedDate {
+            year: 1752,
+            month: Month::September,
+            day: 5,
+            before: (1752, Month::September, 2),
+            after: (1752, Month::September, 14),
+        }))
+    );
+    let date = cal.parse_long_date("Sept. 5, 1752 O.S.").unwrap();
+    assert_eq!(date.to_string(), "1752-09-16");
+}
+
+#[test]
+fn day_out_of_range() {
+    assert_matches!(
+        Calendar::GREGORIAN.parse_long_date("February 30, 2023"),
+        Err(ParseLongDateError::InvalidDate(DateError::DayOutOfRange {
+            year: 2023,
+            month: Month::February,
+            day: 30,
+            ..
+        }))
+    );
+}
+
+#[rstest]
+#[case("")]
+#[case(" , ")]
+#[case("April")]
+#[case("April 2023")]
+#[case("April 30")]
+#[case("30 Smarch 2023")]
+#[case("April the 30th 2023")]
+#[case("April 30, 2023 extra")]
+#[case("April 30, 2023 BC AD")]
+#[case("April 30, 2023 O.S. N.S.")]
+#[case("Sunday Sunday April 30, 2023")]
+#[case("30xx April 2023")]
+#[case("April 30, -2023")]
+#[case("30/04")]
+#[case("30/04/2023/1")]
+#[case("1 2 3 4 5 6 7 8 9 10 11 12 13")]
+fn unrecognized(#[case] s: &str) {
+    assert_eq!(
+        Calendar::GREGORIAN.parse_long_date(s),
+        Err(ParseLongDateError::Unrecognized)
+    );
+}
+
+#[rstest]
+#[case("13/13/2023", 13)]
+#[case("2023-13-01", 13)]
+#[case("00/05/2023", 0)]
+fn invalid_month(#[case] s: &str, #[case] value: u32) {
+    assert_eq!(
+        Calendar::GREGORIAN.parse_long_date(s),
+        Err(ParseLongDateError::InvalidMonth { value })
+    );
+}
+
+#[rstest]
+#[case("1 January 0 BC")]
+#[case("1 January AD 0")]
+#[case("1 January 99999999999")]
+fn invalid_year(#[case] s: &str) {
+    assert_eq!(
+        Calendar::GREGORIAN.parse_long_date(s),
+        Err(ParseLongDateError::InvalidYear)
+    );
+}
@@ -0,0 +1,124 @@
+use crate::{
+    Calendar, Month, Weekday, YearKind, ncal,
+    perpetual::{YearType, next_same_calendar, prev_same_calendar},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(Calendar::GREGORIAN, 2023, Weekday::Sunday, YearKind::Common, Some(1))]
+#[case(Calendar::GREGORIAN, 2024, Weekday::Monday, YearKind::Leap, Some(9))]
+#[case(
+    Calendar::GREGORIAN,
+    2025,
+    Weekday::Wednesday,
+    YearKind::Common,
+    Some(4)
+)]
+#[case(Calendar::GREGORIAN, 2000, Weekday::Saturday, YearKind::Leap, Some(14))]
+#[case(Calendar::GREGORIAN, 1900, Weekday::Monday, YearKind::Common, Some(2))]
+#[case(Calendar::JULIAN, 1900, Weekday::Saturday, YearKind::Leap, Some(14))]
+#[case(
+    Calendar::REFORM1582,
+    1582,
+    Weekday::Monday,
+    YearKind::ReformCommon,
+    None
+)]
+#[case(Calendar::reforming(ncal::RUSSIA).unwrap(), 1918, Weekday::Monday, YearKind::ReformCommon, None)]
+fn year_type(
+    #[case] cal: Calendar,
+    #[case] year: i32,
+    #[case] weekday: Weekday,
+    #[case] kind: YearKind,
+    #[case] number: Option<u32>,
+) {
+    let ty = YearType::for_year(cal, year).unwrap();
+    assert_eq!(ty.weekday(), weekday);
+    assert_eq!(ty.kind(), kind);
+    assert_eq!(ty.number(), number);
+    assert_eq!(ty.is_leap(), kind.is_leap());
+    assert_eq!(ty.is_reform(), kind.is_reform());
+}
+
+#[test]
+fn fourteen_types() {
+    let mut numbers = (2001..2029)
+        .map(|y| {
+            YearType::for_year(Calendar::GREGORIAN, y)
+                .unwrap()
+                .number()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    numbers.sort_unstable();
+    numbers.dedup();
+    assert_eq!(numbers, (1..=14).collect::<Vec<_>>());
+}
+
+#[rstest]
+#[case(Calendar::GREGORIAN, 2023, Some(2017), Some(2034))]
+#[case(Calendar::GREGORIAN, 2024, Some(1996), Some(2052))]
+#[case(Calendar::GREGORIAN, 1900, Some(1894), Some(1906))]
+#[case(Calendar::GREGORIAN, 2096, Some(2068), Some(2108))]
+#[case(Calendar::JULIAN, 2024, Some(1996), Some(2052))]
+#[case(Calendar::JULIAN, 1900, Some(1872), Some(1928))]
+#[case(Calendar::REFORM1582, 1582, None, None)]
+#[case(Calendar::REFORM1582, 1583, Some(1575), Some(1594))]
+#[case(Calendar::REFORM1582, 1580, Some(1552), Some(1588))]
+#[case(Calendar::GREGORIAN, i32::MAX - 1, None, None)]
+fn same_calendar(
+    #[case] cal: Calendar,
+    #[case] year: i32,
+    #[case] prev: Option<i32>,
+    #[case] next: Option<i32>,
+) {
+    assert_eq!(prev_same_calendar(cal, year), prev);
+    assert_eq!(next_same_calendar(cal, year), next);
+}
+
+#[test]
+fn across_calendars() {
+    let ty = YearType::for_year(Calendar::GREGORIAN, 2023).unwrap();
+    assert_eq!(ty.prev_in(Calendar::JULIAN, 2023), Some(2018));
+    assert_eq!(ty.next_in(Calendar::JULIAN, 2023), Some(2029));
+    assert_eq!(
+        YearType::for_year(Calendar::JULIAN, 2029),
+        YearType::for_year(Calendar::GREGORIAN, 2023)
+    );
+}
+
+#[test]
+fn reform_years_across_calendars() {
+    let ty = YearType::for_year(Calendar::REFORM1582, 1582).unwrap();
+    let cal = Calendar::reforming(ncal::SPAIN).unwrap();
+    assert_eq!(ty.next_in(cal, 1500), Some(1582));
+    assert_eq!(ty.prev_in(cal, 1700), Some(1582));
+    assert_eq!(ty.next_in(Calendar::GREGORIAN, 1500), None);
+    assert_eq!(ty.prev_in(Calendar::JULIAN, 1700), None);
+    // A reformation in another year has a different type:
+    let uk = Calendar::reforming(ncal::UNITED_KINGDOM).unwrap();
+    assert_ne!(YearType::for_year(uk, 1752), Some(ty));
+    assert_eq!(ty.next_in(uk, 1500), None);
+}
+
+#[test]
+fn reform_years_differ_by_gap() {
+    // Two reformations in 1582 that both leave the year starting on a Monday
+    // but skip different days:
+    let italy = Calendar::reforming(ncal::ITALY).unwrap();
+    let france = Calendar::reforming(ncal::FRANCE).unwrap();
+    let a = YearType::for_year(italy, 1582).unwrap();
+    let b = YearType::for_year(france, 1582).unwrap();
+    assert_eq!(a.weekday(), b.weekday());
+    assert_eq!(a.kind(), b.kind());
+    assert_ne!(a, b);
+}
+
+#[test]
+fn skipped_year() {
+    let cal = Calendar::reforming(19582149).unwrap();
+    assert_eq!(YearType::for_year(cal, 48901), None);
+    assert_eq!(next_same_calendar(cal, 48901), None);
+    let date = cal.at_ymd(48902, Month::January, 1).unwrap();
+    assert!(YearType::for_year(cal, date.year()).is_some());
+}
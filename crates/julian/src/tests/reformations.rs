@@ -91,7 +91,9 @@ mod gregorian_reform {
             Err(DateError::SkippedDate {
                 year: 1582,
                 month: October,
-                day: 5
+                day: 5,
+                before: (1582, October, 4),
+                after: (1582, October, 15),
             })
         );
         assert_eq!(
@@ -99,7 +101,9 @@ mod gregorian_reform {
             Err(DateError::SkippedDate {
                 year: 1582,
                 month: October,
-                day: 14
+                day: 14,
+                before: (1582, October, 4),
+                after: (1582, October, 15),
             })
         );
         assert_eq!(shape.day_ordinal(15), Some(5));
@@ -464,7 +468,9 @@ mod germany {
             Err(DateError::SkippedDate {
                 year: 1700,
                 month: Month::February,
-                day: 29
+                day: 29,
+                before: (1700, Month::February, 18),
+                after: (1700, Month::March, 1),
             })
         );
     }
@@ -1372,7 +1378,9 @@ mod minreform {
             Err(DateError::SkippedDate {
                 year: 300,
                 month: Month::February,
-                day: 29
+                day: 29,
+                before: (300, Month::February, 28),
+                after: (300, Month::March, 1),
             })
         );
     }
@@ -1682,7 +1690,9 @@ mod jdn2344534 {
             Err(DateError::SkippedDate {
                 year: 1706,
                 month: Month::December,
-                day: 31
+                day: 31,
+                before: (1706, Month::December, 25),
+                after: (1707, Month::January, 6),
             })
         );
     }
@@ -1850,7 +1860,9 @@ fn skipped_month() {
             Err(DateError::SkippedDate {
                 year: 3901,
                 month: Month::February,
-                day
+                day,
+                before: (3901, Month::January, 31),
+                after: (3901, Month::March, 1),
             })
         );
     }
@@ -1914,7 +1926,9 @@ fn skipped_year() {
             Err(DateError::SkippedDate {
                 year: 48901,
                 month,
-                day
+                day,
+                before: (48900, Month::December, 31),
+                after: (48902, Month::January, 1),
             })
         );
     }
@@ -1934,6 +1948,25 @@ fn skipped_year() {
     assert_eq!(first_gregorian.pred(), Some(last_julian));
 }
 
+#[test]
+fn jdn6104039() {
+    // Headless Gregorian leap year that has lost its leap day in a cross-year
+    // reformation
+    let cal = Calendar::reforming(6104039).unwrap();
+    assert_eq!(cal.last_julian_date().unwrap().to_string(), "11999-12-12");
+    assert_eq!(
+        cal.first_gregorian_date().unwrap().to_string(),
+        "12000-03-10"
+    );
+    assert_eq!(cal.year_kind(11999), YearKind::ReformCommon);
+    assert_eq!(cal.year_length(11999), 346);
+    assert_eq!(cal.year_kind(12000), YearKind::ReformCommon);
+    assert_eq!(cal.year_length(12000), 297);
+    let last = cal.at_ymd(12000, Month::December, 31).unwrap();
+    assert_eq!(last.ordinal(), 297);
+    assert_eq!(cal.at_ordinal_date(12000, 297), Ok(last));
+}
+
 #[test]
 fn jdn2460316() {
     // Headless leap year in a cross-year reformation
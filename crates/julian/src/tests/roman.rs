@@ -0,0 +1,295 @@
+use crate::{
+    Calendar, Month,
+    errors::{DateError, RomanDateError},
+    ncal,
+    roman::{Reference, RomanDate},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(1581, Month::January, 1, "Kal. Ian.", "Kalendis Ianuariis")]
+#[case(
+    1581,
+    Month::January,
+    2,
+    "a.d. IV Non. Ian.",
+    "ante diem quartum Nonas Ianuarias"
+)]
+#[case(1581, Month::January, 4, "prid. Non. Ian.", "pridie Nonas Ianuarias")]
+#[case(1581, Month::January, 5, "Non. Ian.", "Nonis Ianuariis")]
+#[case(1581, Month::January, 12, "prid. Id. Ian.", "pridie Idus Ianuarias")]
+#[case(1581, Month::January, 13, "Id. Ian.", "Idibus Ianuariis")]
+#[case(
+    1581,
+    Month::January,
+    14,
+    "a.d. XIX Kal. Feb.",
+    "ante diem undevicesimum Kalendas Februarias"
+)]
+#[case(
+    1581,
+    Month::February,
+    14,
+    "a.d. XVI Kal. Mart.",
+    "ante diem sextum decimum Kalendas Martias"
+)]
+#[case(
+    1581,
+    Month::February,
+    24,
+    "a.d. VI Kal. Mart.",
+    "ante diem sextum Kalendas Martias"
+)]
+#[case(
+    1581,
+    Month::February,
+    28,
+    "prid. Kal. Mart.",
+    "pridie Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    14,
+    "a.d. XVI Kal. Mart.",
+    "ante diem sextum decimum Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    23,
+    "a.d. VII Kal. Mart.",
+    "ante diem septimum Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    24,
+    "a.d. bis VI Kal. Mart.",
+    "ante diem bis sextum Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    25,
+    "a.d. VI Kal. Mart.",
+    "ante diem sextum Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    28,
+    "a.d. III Kal. Mart.",
+    "ante diem tertium Kalendas Martias"
+)]
+#[case(
+    1580,
+    Month::February,
+    29,
+    "prid. Kal. Mart.",
+    "pridie Kalendas Martias"
+)]
+#[case(1580, Month::March, 1, "Kal. Mart.", "Kalendis Martiis")]
+#[case(1580, Month::March, 7, "Non. Mart.", "Nonis Martiis")]
+#[case(
+    1580,
+    Month::March,
+    8,
+    "a.d. VIII Id. Mart.",
+    "ante diem octavum Idus Martias"
+)]
+#[case(1580, Month::March, 15, "Id. Mart.", "Idibus Martiis")]
+#[case(
+    1580,
+    Month::March,
+    16,
+    "a.d. XVII Kal. Apr.",
+    "ante diem septimum decimum Kalendas Apriles"
+)]
+#[case(
+    1580,
+    Month::September,
+    18,
+    "a.d. XIV Kal. Oct.",
+    "ante diem quartum decimum Kalendas Octobres"
+)]
+#[case(
+    1580,
+    Month::October,
+    5,
+    "a.d. III Non. Oct.",
+    "ante diem tertium Nonas Octobres"
+)]
+#[case(
+    1580,
+    Month::December,
+    14,
+    "a.d. XIX Kal. Ian.",
+    "ante diem undevicesimum Kalendas Ianuarias"
+)]
+#[case(
+    1580,
+    Month::December,
+    25,
+    "a.d. VIII Kal. Ian.",
+    "ante diem octavum Kalendas Ianuarias"
+)]
+#[case(
+    1580,
+    Month::December,
+    31,
+    "prid. Kal. Ian.",
+    "pridie Kalendas Ianuarias"
+)]
+fn julian_dates(
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] short: &str,
+    #[case] full: &str,
+) {
+    let date = Calendar::JULIAN.at_ymd(year, month, day).unwrap();
+    let roman = RomanDate::for_date(&date);
+    assert_eq!(roman.to_string(), short);
+    assert_eq!(format!("{roman:#}"), full);
+    assert_eq!(short.parse::<RomanDate>(), Ok(roman));
+    assert_eq!(full.parse::<RomanDate>(), Ok(roman));
+    assert_eq!(roman.to_date(Calendar::JULIAN, year), Ok(date));
+}
+
+#[rstest]
+#[case(Calendar::JULIAN)]
+#[case(Calendar::GREGORIAN)]
+#[case(Calendar::REFORM1582)]
+#[case(Calendar::reforming(ncal::UNITED_KINGDOM).unwrap())]
+#[case(Calendar::reforming(ncal::GERMANY).unwrap())]
+#[case(Calendar::reforming(ncal::RUSSIA).unwrap())]
+fn round_trip(#[case] cal: Calendar) {
+    for year in [1580, 1582, 1700, 1752, 1900, 1918, 2000] {
+        let mut date = cal.at_ymd(year, Month::January, 1).unwrap();
+        while date.year() == year {
+            let roman = RomanDate::for_date(&date);
+            assert_eq!(roman.to_string().parse::<RomanDate>(), Ok(roman), "{date}");
+            assert_eq!(
+                format!("{roman:#}").parse::<RomanDate>(),
+                Ok(roman),
+                "{date}"
+            );
+            assert_eq!(roman.to_date(cal, year), Ok(date), "{date}");
+            date = date.succ().unwrap();
+        }
+    }
+}
+
+#[test]
+fn reform_dates() {
+    let cal = Calendar::REFORM1582;
+    let before = cal.at_ymd(1582, Month::October, 4).unwrap();
+    let after = cal.at_ymd(1582, Month::October, 15).unwrap();
+    assert_eq!(
+        RomanDate::for_date(&before).to_string(),
+        "a.d. IV Non. Oct."
+    );
+    assert_eq!(RomanDate::for_date(&after).to_string(), "Id. Oct.");
+    let skipped = "a.d. VIII Id. Oct.".parse::<RomanDate>().unwrap();
+    assert_eq!(
+        skipped.to_date(cal, 1582),
+        Err(RomanDateError::InvalidDate(DateError::SkippedDate {
+            year: 1582,
+            month: Month::October,
+            day: 8,
+            before: (1582, Month::October, 4),
+            after: (1582, Month::October, 15),
+        }))
+    );
+}
+
+#[rstest]
+#[case(Calendar::JULIAN, 1581)]
+#[case(Calendar::GREGORIAN, 1900)]
+#[case(Calendar::reforming(ncal::GERMANY).unwrap(), 1700)]
+fn bissextile_common_year(#[case] cal: Calendar, #[case] year: i32) {
+    assert_eq!(
+        RomanDate::BISSEXTILE.to_date(cal, year),
+        Err(RomanDateError::NotLeapYear { year })
+    );
+}
+
+#[test]
+fn bissextile_leap_year() {
+    let date = RomanDate::BISSEXTILE
+        .to_date(Calendar::JULIAN, 1900)
+        .unwrap();
+    assert_eq!(date.month(), Month::February);
+    assert_eq!(date.day(), 24);
+    assert!(RomanDate::BISSEXTILE.is_bissextile());
+    assert_eq!(RomanDate::BISSEXTILE.count(), 6);
+    assert_eq!(RomanDate::BISSEXTILE.reference(), Reference::Kalends);
+    assert_eq!(RomanDate::BISSEXTILE.month(), Month::March);
+}
+
+#[rstest]
+#[case(Reference::Kalends, Month::January, 0, 19)]
+#[case(Reference::Kalends, Month::January, 20, 19)]
+#[case(Reference::Kalends, Month::March, 17, 16)]
+#[case(Reference::Kalends, Month::May, 19, 18)]
+#[case(Reference::Nones, Month::March, 7, 6)]
+#[case(Reference::Nones, Month::April, 5, 4)]
+#[case(Reference::Ides, Month::March, 9, 8)]
+#[case(Reference::Ides, Month::April, 9, 8)]
+fn count_out_of_range(
+    #[case] reference: Reference,
+    #[case] month: Month,
+    #[case] count: u32,
+    #[case] max: u32,
+) {
+    assert_eq!(
+        RomanDate::new(reference, month, count),
+        Err(RomanDateError::CountOutOfRange {
+            reference,
+            month,
+            count,
+            max
+        })
+    );
+    assert!(RomanDate::new(reference, month, max).is_ok());
+}
+
+#[rstest]
+#[case("KAL. IAN.", Reference::Kalends, Month::January, 1)]
+#[case("Kalendis Januariis", Reference::Kalends, Month::January, 1)]
+#[case("  a.d.  IV   Kal.  Ian. ", Reference::Kalends, Month::January, 4)]
+#[case("a.d. iv kalendas ian.", Reference::Kalends, Month::January, 4)]
+#[case("pridie Id. Jul.", Reference::Ides, Month::July, 2)]
+#[case("ante diem XIII Kal. Sept.", Reference::Kalends, Month::September, 13)]
+fn parse_lenient(
+    #[case] s: &str,
+    #[case] reference: Reference,
+    #[case] month: Month,
+    #[case] count: u32,
+) {
+    assert_eq!(
+        s.parse::<RomanDate>(),
+        Ok(RomanDate::new(reference, month, count).unwrap())
+    );
+}
+
+#[rstest]
+#[case("")]
+#[case("Kal.")]
+#[case("Ian.")]
+#[case("Kal. Ianuarius")]
+#[case("a.d. II Kal. Ian.")]
+#[case("a.d. XX Kal. Ian.")]
+#[case("a.d. XVII Kal. Mart.")]
+#[case("a.d. bis VII Kal. Mart.")]
+#[case("a.d. bis VI Kal. Apr.")]
+#[case("a.d. bis Kal. Mart.")]
+#[case("ante diem Kal. Ian.")]
+#[case("ante diem tertium tertium Kal. Ian.")]
+#[case("a.d. III Non. Oct. extra")]
+#[case("pridie pridie Kal. Ian.")]
+#[case("a.d. III Kal. Ian. a.d. III Kal. Ian.")]
+fn parse_invalid(#[case] s: &str) {
+    assert!(s.parse::<RomanDate>().is_err());
+}
@@ -0,0 +1,249 @@
+use crate::{
+    Calendar, Jdnum, Month, YearKind,
+    errors::{
+        ParseDateError, ParseSakaDateError, ParseSakaMonthError, SakaDateError, TryIntoMonthError,
+    },
+    saka::{self, SakaDate, SakaMonth},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(1749630, 0, SakaMonth::Chaitra, 1, 1)]
+#[case(2432413, 1869, SakaMonth::Shravana, 24, 147)]
+#[case(2451545, 1921, SakaMonth::Pausha, 11, 286)]
+#[case(2460025, 1944, SakaMonth::Phalguna, 30, 365)]
+#[case(2460026, 1945, SakaMonth::Chaitra, 1, 1)]
+#[case(2460065, 1945, SakaMonth::Vaishakha, 10, 40)]
+#[case(2460390, 1945, SakaMonth::Phalguna, 30, 365)]
+#[case(2460391, 1946, SakaMonth::Chaitra, 1, 1)]
+#[case(2460421, 1946, SakaMonth::Chaitra, 31, 31)]
+#[case(2460422, 1946, SakaMonth::Vaishakha, 1, 32)]
+#[case(2460676, 1946, SakaMonth::Pausha, 10, 286)]
+#[case(2460735, 1946, SakaMonth::Phalguna, 9, 345)]
+#[case(2460756, 1946, SakaMonth::Phalguna, 30, 366)]
+#[case(2460757, 1947, SakaMonth::Chaitra, 1, 1)]
+fn conversions(
+    #[case] jdn: Jdnum,
+    #[case] year: i32,
+    #[case] month: SakaMonth,
+    #[case] day: u32,
+    #[case] ordinal: u32,
+) {
+    let date = SakaDate::at_jdn(jdn);
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+    assert_eq!(date.ordinal(), ordinal);
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(SakaDate::at_ymd(year, month, day), Ok(date));
+    assert_eq!(SakaDate::at_ordinal_date(year, ordinal), Ok(date));
+}
+
+#[test]
+fn from_date() {
+    let date = Calendar::JULIAN.at_ymd(1947, Month::August, 2).unwrap();
+    let saka = SakaDate::from(date);
+    assert_eq!(saka.year(), 1869);
+    assert_eq!(saka.month(), SakaMonth::Shravana);
+    assert_eq!(saka.day(), 24);
+    assert_eq!(saka.to_date(Calendar::JULIAN), date);
+    let gregorian = saka.to_date(Calendar::GREGORIAN);
+    assert_eq!(gregorian.to_string(), "1947-08-15");
+}
+
+#[rstest]
+#[case(1944, YearKind::Common, 365)]
+#[case(1945, YearKind::Common, 365)]
+#[case(1946, YearKind::Leap, 366)]
+#[case(1922, YearKind::Leap, 366)]
+#[case(1822, YearKind::Common, 365)]
+#[case(2322, YearKind::Leap, 366)]
+#[case(-78, YearKind::Leap, 366)]
+fn year_kinds(#[case] year: i32, #[case] kind: YearKind, #[case] length: u32) {
+    assert_eq!(saka::year_kind(year), kind);
+    assert_eq!(saka::year_length(year), length);
+}
+
+#[test]
+fn month_lengths() {
+    let lengths = [30, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30];
+    let mut month = Some(SakaMonth::Chaitra);
+    for (i, &len) in lengths.iter().enumerate() {
+        let m = month.unwrap();
+        assert_eq!(m.number0(), u32::try_from(i).unwrap());
+        assert_eq!(m.length(YearKind::Common), len);
+        if i == 0 {
+            assert_eq!(m.length(YearKind::Leap), 31);
+        } else {
+            assert_eq!(m.length(YearKind::Leap), len);
+        }
+        month = m.succ();
+    }
+    assert_eq!(month, None);
+    assert_eq!(SakaMonth::Chaitra.pred(), None);
+}
+
+#[test]
+fn day_out_of_range() {
+    let r = SakaDate::at_ymd(1945, SakaMonth::Chaitra, 31);
+    assert_eq!(
+        r,
+        Err(SakaDateError::DayOutOfRange {
+            year: 1945,
+            month: SakaMonth::Chaitra,
+            day: 31,
+            max_day: 30,
+        })
+    );
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "day 31 is outside of valid range 1-30 for Saka 1945 Chaitra"
+    );
+}
+
+#[test]
+fn day_zero() {
+    let r = SakaDate::at_ymd(1945, SakaMonth::Magha, 0);
+    assert_eq!(
+        r,
+        Err(SakaDateError::DayOutOfRange {
+            year: 1945,
+            month: SakaMonth::Magha,
+            day: 0,
+            max_day: 30,
+        })
+    );
+}
+
+#[test]
+fn ordinal_out_of_range() {
+    let r = SakaDate::at_ordinal_date(1945, 366);
+    assert_eq!(
+        r,
+        Err(SakaDateError::OrdinalOutOfRange {
+            year: 1945,
+            ordinal: 366,
+            max_ordinal: 365,
+        })
+    );
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "day-of-year ordinal 366 is outside of valid range 1-365 for Saka year 1945"
+    );
+}
+
+#[rstest]
+#[case(Jdnum::MIN)]
+#[case(Jdnum::MIN + 1)]
+#[case(Jdnum::MAX - 1)]
+#[case(Jdnum::MAX)]
+fn extremes(#[case] jdn: Jdnum) {
+    let date = SakaDate::at_jdn(jdn);
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(
+        SakaDate::at_ymd(date.year(), date.month(), date.day()),
+        Ok(date)
+    );
+    assert_eq!(
+        SakaDate::at_ordinal_date(date.year(), date.ordinal()),
+        Ok(date)
+    );
+}
+
+#[test]
+fn overflow() {
+    let r = SakaDate::at_ymd(i32::MAX, SakaMonth::Chaitra, 1);
+    assert_eq!(r, Err(SakaDateError::Arithmetic));
+}
+
+#[test]
+fn display() {
+    let date = SakaDate::at_ymd(1945, SakaMonth::Vaishakha, 10).unwrap();
+    assert_eq!(date.to_string(), "1945-02-10");
+    assert_eq!(format!("{date:#}"), "1945-040");
+}
+
+#[rstest]
+#[case("1945-02-10", 1945, SakaMonth::Vaishakha, 10)]
+#[case("1945-040", 1945, SakaMonth::Vaishakha, 10)]
+#[case("1946-01-31", 1946, SakaMonth::Chaitra, 31)]
+#[case("-0012-12-30", -12, SakaMonth::Phalguna, 30)]
+fn parse(#[case] s: &str, #[case] year: i32, #[case] month: SakaMonth, #[case] day: u32) {
+    let date = s.parse::<SakaDate>().unwrap();
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+}
+
+#[test]
+fn parse_invalid_date() {
+    let r = "1945-01-31".parse::<SakaDate>();
+    assert_eq!(
+        r,
+        Err(ParseSakaDateError::InvalidDate(
+            SakaDateError::DayOutOfRange {
+                year: 1945,
+                month: SakaMonth::Chaitra,
+                day: 31,
+                max_day: 30,
+            }
+        ))
+    );
+}
+
+#[test]
+fn parse_invalid_month() {
+    let r = "1945-13-01".parse::<SakaDate>();
+    assert_eq!(
+        r,
+        Err(ParseSakaDateError::InvalidFormat(
+            ParseDateError::InvalidMonth { value: 13 }
+        ))
+    );
+}
+
+#[test]
+fn parse_trailing() {
+    let r = "1945-01-01 ".parse::<SakaDate>();
+    assert_eq!(
+        r,
+        Err(ParseSakaDateError::InvalidFormat(ParseDateError::Trailing))
+    );
+}
+
+#[test]
+fn ord() {
+    let d1 = SakaDate::at_ymd(1945, SakaMonth::Phalguna, 30).unwrap();
+    let d2 = SakaDate::at_ymd(1946, SakaMonth::Chaitra, 1).unwrap();
+    assert!(d1 < d2);
+}
+
+#[rstest]
+#[case("Chaitra", SakaMonth::Chaitra)]
+#[case("vaisakha", SakaMonth::Vaishakha)]
+#[case("JYESHTHA", SakaMonth::Jyeshtha)]
+#[case("Bhadrapada", SakaMonth::Bhadra)]
+#[case("Margashirsha", SakaMonth::Agrahayana)]
+#[case("phalguna", SakaMonth::Phalguna)]
+fn parse_month(#[case] s: &str, #[case] month: SakaMonth) {
+    assert_eq!(s.parse::<SakaMonth>(), Ok(month));
+}
+
+#[test]
+fn parse_month_error() {
+    assert_eq!("Chait".parse::<SakaMonth>(), Err(ParseSakaMonthError));
+}
+
+#[test]
+fn month_display() {
+    assert_eq!(SakaMonth::Ashvin.to_string(), "Ashvin");
+    assert_eq!(format!("{:>10}", SakaMonth::Magha), "     Magha");
+}
+
+#[test]
+fn month_try_from() {
+    assert_eq!(SakaMonth::try_from(9u8), Ok(SakaMonth::Agrahayana));
+    assert_eq!(SakaMonth::try_from(12i64), Ok(SakaMonth::Phalguna));
+    assert_eq!(SakaMonth::try_from(0u32), Err(TryIntoMonthError));
+    assert_eq!(SakaMonth::try_from(-1i32), Err(TryIntoMonthError));
+}
@@ -0,0 +1,182 @@
+use crate::{
+    Calendar, Jdnum, Month,
+    errors::ParseSexagenaryError,
+    ncal,
+    sexagenary::{Branch, Sexagenary, Stem},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(2451551, Stem::Jia, Branch::Zi, 1)]
+#[case(2451550, Stem::Gui, Branch::Hai, 60)]
+#[case(2451545, Stem::Wu, Branch::Wu, 55)]
+#[case(2433191, Stem::Jia, Branch::Zi, 1)]
+#[case(2419403, Stem::Bing, Branch::Zi, 13)]
+#[case(0, Stem::Gui, Branch::Chou, 50)]
+#[case(-1, Stem::Ren, Branch::Zi, 49)]
+#[case(Jdnum::MAX, Stem::Geng, Branch::Shen, 57)]
+#[case(Jdnum::MIN, Stem::Yi, Branch::Si, 42)]
+fn for_jdn(#[case] jdn: Jdnum, #[case] stem: Stem, #[case] branch: Branch, #[case] number: u32) {
+    let term = Sexagenary::for_jdn(jdn);
+    assert_eq!(term.stem(), stem);
+    assert_eq!(term.branch(), branch);
+    assert_eq!(term.number(), number);
+    assert_eq!(Sexagenary::new(stem, branch), Some(term));
+    assert_eq!(Sexagenary::from_number(number), Some(term));
+}
+
+#[rstest]
+#[case(4, "Jiazi")]
+#[case(1900, "Gengzi")]
+#[case(1911, "Xinhai")]
+#[case(1984, "Jiazi")]
+#[case(2024, "Jiachen")]
+#[case(2043, "Guihai")]
+#[case(-2696, "Jiazi")]
+#[case(0, "Gengshen")]
+fn for_year(#[case] year: i32, #[case] name: &str) {
+    assert_eq!(Sexagenary::for_year(year).to_string(), name);
+}
+
+#[test]
+fn for_date() {
+    let cal = Calendar::reforming(ncal::CHINA).unwrap();
+    let date = cal.at_ymd(1912, Month::January, 1).unwrap();
+    assert_eq!(date.julian_day_number(), 2419403);
+    assert_eq!(Sexagenary::for_date(&date), Sexagenary::for_jdn(2419403));
+}
+
+#[test]
+fn cycle() {
+    let mut term = Sexagenary::JIAZI;
+    for i in 0..60 {
+        assert_eq!(term.number0(), i);
+        assert_eq!(term.stem().number0(), i % 10);
+        assert_eq!(term.branch().number0(), i % 12);
+        assert_eq!(term.succ().pred(), term);
+        term = term.succ();
+    }
+    assert_eq!(term, Sexagenary::JIAZI);
+    assert_eq!(Sexagenary::JIAZI.pred().to_string(), "Guihai");
+}
+
+#[test]
+fn new_mismatched_parity() {
+    assert_eq!(Sexagenary::new(Stem::Jia, Branch::Chou), None);
+    assert_eq!(Sexagenary::new(Stem::Yi, Branch::Zi), None);
+}
+
+#[rstest]
+#[case(0)]
+#[case(61)]
+fn from_number_out_of_range(#[case] number: u32) {
+    assert_eq!(Sexagenary::from_number(number), None);
+}
+
+#[rstest]
+#[case(1912, Month::January, 1, "Gengzi", "1912-01-25")]
+#[case(1912, Month::January, 1, "Bingzi", "1912-01-01")]
+#[case(1912, Month::January, 1, "Yihai", "1912-02-29")]
+fn next_on_or_after(
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] term: &str,
+    #[case] expected: &str,
+) {
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    let term = term.parse::<Sexagenary>().unwrap();
+    let next = term.next_on_or_after(&date).unwrap();
+    assert_eq!(next.to_string(), expected);
+    assert_eq!(Sexagenary::for_date(&next), term);
+}
+
+#[test]
+fn next_on_or_after_reforming() {
+    let cal = Calendar::REFORM1582;
+    let date = cal.at_ymd(1582, Month::October, 1).unwrap();
+    let term = Sexagenary::for_jdn(date.julian_day_number() + 5);
+    let next = term.next_on_or_after(&date).unwrap();
+    assert_eq!(next.to_string(), "1582-10-16");
+}
+
+#[test]
+fn next_on_or_after_overflow() {
+    let date = Calendar::GREGORIAN.at_jdn(Jdnum::MAX);
+    assert_eq!(Sexagenary::JIAZI.next_on_or_after(&date), None);
+}
+
+#[test]
+fn display() {
+    let term = Sexagenary::new(Stem::Geng, Branch::Chen).unwrap();
+    assert_eq!(term.to_string(), "Gengchen");
+    assert_eq!(format!("{term:#}"), "庚辰");
+    assert_eq!(format!("{term:>10}"), "  Gengchen");
+    assert_eq!(format!("{term:>#4}"), "  庚辰");
+    assert_eq!(format!("{term:.4}"), "Geng");
+    assert_eq!(Stem::Ding.to_string(), "Ding");
+    assert_eq!(format!("{:#}", Stem::Ding), "丁");
+    assert_eq!(Branch::Chou.to_string(), "Chou");
+    assert_eq!(format!("{:#}", Branch::Chou), "丑");
+}
+
+#[rstest]
+#[case("jiazi", 1)]
+#[case("Jia-zi", 1)]
+#[case("JIAZI", 1)]
+#[case("jiǎzǐ", 1)]
+#[case("甲子", 1)]
+#[case("jiyou", 46)]
+#[case("Jǐyǒu", 46)]
+#[case("己酉", 46)]
+#[case("guihai", 60)]
+#[case("wuwu", 55)]
+#[case("wùwǔ", 55)]
+fn parse(#[case] s: &str, #[case] number: u32) {
+    assert_eq!(
+        s.parse::<Sexagenary>(),
+        Ok(Sexagenary::from_number(number).unwrap())
+    );
+}
+
+#[rstest]
+#[case("")]
+#[case("jia")]
+#[case("jiachou")]
+#[case("jia zi")]
+#[case("jiazi ")]
+#[case("甲丑")]
+#[case("zijia")]
+fn parse_error(#[case] s: &str) {
+    assert_eq!(s.parse::<Sexagenary>(), Err(ParseSexagenaryError));
+}
+
+#[rstest]
+#[case("Jia", Stem::Jia)]
+#[case("ji", Stem::Ji)]
+#[case("jǐ", Stem::Ji)]
+#[case("癸", Stem::Gui)]
+fn parse_stem(#[case] s: &str, #[case] stem: Stem) {
+    assert_eq!(s.parse::<Stem>(), Ok(stem));
+}
+
+#[rstest]
+#[case("zi", Branch::Zi)]
+#[case("SHEN", Branch::Shen)]
+#[case("xū", Branch::Xu)]
+#[case("亥", Branch::Hai)]
+fn parse_branch(#[case] s: &str, #[case] branch: Branch) {
+    assert_eq!(s.parse::<Branch>(), Ok(branch));
+}
+
+#[test]
+fn parse_stem_branch_error() {
+    assert_eq!("zi".parse::<Stem>(), Err(ParseSexagenaryError));
+    assert_eq!("jia".parse::<Branch>(), Err(ParseSexagenaryError));
+}
+
+#[test]
+fn ord() {
+    assert!(Sexagenary::JIAZI < Sexagenary::from_number(2).unwrap());
+    assert!(Sexagenary::from_number(59).unwrap() < Sexagenary::from_number(60).unwrap());
+}
@@ -0,0 +1,114 @@
+use crate::{Calendar, Jdnum, Month, errors::SerialDateError, spreadsheet::DateSystem};
+use rstest::rstest;
+
+#[rstest]
+#[case(DateSystem::Excel1900, 0, 1899, Month::December, 31)]
+#[case(DateSystem::Excel1900, 1, 1900, Month::January, 1)]
+#[case(DateSystem::Excel1900, 59, 1900, Month::February, 28)]
+#[case(DateSystem::Excel1900, 61, 1900, Month::March, 1)]
+#[case(DateSystem::Excel1900, 367, 1901, Month::January, 1)]
+#[case(DateSystem::Excel1900, 36526, 2000, Month::January, 1)]
+#[case(DateSystem::Excel1900, 45000, 2023, Month::March, 15)]
+#[case(DateSystem::Excel1900, 2958465, 9999, Month::December, 31)]
+#[case(DateSystem::Excel1900, -1, 1899, Month::December, 30)]
+#[case(DateSystem::Excel1904, 0, 1904, Month::January, 1)]
+#[case(DateSystem::Excel1904, 1, 1904, Month::January, 2)]
+#[case(DateSystem::Excel1904, 35064, 2000, Month::January, 1)]
+#[case(DateSystem::Excel1904, 43538, 2023, Month::March, 15)]
+fn serial_dates(
+    #[case] system: DateSystem,
+    #[case] serial: i32,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+) {
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    assert_eq!(system.serial2jdn(serial), Ok(date.julian_day_number()));
+    assert_eq!(system.serial2date(serial, Calendar::GREGORIAN), Ok(date));
+    assert_eq!(system.jdn2serial(date.julian_day_number()), Ok(serial));
+    assert_eq!(system.date2serial(date), Ok(serial));
+    assert!(!system.is_lotus_leap_day(serial));
+}
+
+#[test]
+fn lotus_leap_day() {
+    let sys = DateSystem::Excel1900;
+    assert!(sys.is_lotus_leap_day(60));
+    assert!(!DateSystem::Excel1904.is_lotus_leap_day(60));
+    assert_eq!(sys.serial2jdn(60), Err(SerialDateError::LotusLeapDay));
+    assert_eq!(
+        sys.serial2date(60, Calendar::GREGORIAN),
+        Err(SerialDateError::LotusLeapDay)
+    );
+    assert_eq!(sys.fractional2jdn(60.5), Err(SerialDateError::LotusLeapDay));
+    assert_eq!(DateSystem::Excel1904.serial2jdn(60), Ok(2416541));
+}
+
+#[test]
+fn other_calendars() {
+    let sys = DateSystem::Excel1900;
+    let date = sys.serial2date(1, Calendar::JULIAN).unwrap();
+    assert_eq!(date.to_string(), "1899-12-20");
+    let date = sys.serial2date(-115859, Calendar::REFORM1582).unwrap();
+    assert_eq!(date.to_string(), "1582-10-15");
+    let date = sys.serial2date(-115860, Calendar::REFORM1582).unwrap();
+    assert_eq!(date.to_string(), "1582-10-04");
+    assert_eq!(sys.date2serial(date), Ok(-115860));
+}
+
+#[rstest]
+#[case(DateSystem::Excel1900, 45000.0, 2460019, 0)]
+#[case(DateSystem::Excel1900, 45000.5, 2460019, 43200)]
+#[case(DateSystem::Excel1900, 45000.999999, 2460020, 0)]
+#[case(DateSystem::Excel1900, 59.25, 2415079, 21600)]
+#[case(DateSystem::Excel1900, 61.75, 2415080, 64800)]
+#[case(DateSystem::Excel1900, -0.25, 2415019, 64800)]
+#[case(DateSystem::Excel1904, 43538.75, 2460019, 64800)]
+fn fractional(
+    #[case] system: DateSystem,
+    #[case] serial: f64,
+    #[case] jdn: Jdnum,
+    #[case] seconds: u32,
+) {
+    assert_eq!(system.fractional2jdn(serial), Ok((jdn, seconds)));
+    let (date, secs) = system.fractional2date(serial, Calendar::GREGORIAN).unwrap();
+    assert_eq!(date.julian_day_number(), jdn);
+    assert_eq!(secs, seconds);
+}
+
+#[test]
+fn jdn2fractional() {
+    assert_eq!(
+        DateSystem::Excel1900.jdn2fractional(2460019, 43200),
+        Ok(45000.5)
+    );
+    assert_eq!(
+        DateSystem::Excel1904.jdn2fractional(2416481, 21600),
+        Ok(0.25)
+    );
+}
+
+#[rstest]
+#[case(f64::NAN)]
+#[case(f64::INFINITY)]
+#[case(f64::NEG_INFINITY)]
+#[case(1e12)]
+#[case(-1e12)]
+fn fractional_out_of_range(#[case] serial: f64) {
+    assert_eq!(
+        DateSystem::Excel1900.fractional2jdn(serial),
+        Err(SerialDateError::OutOfRange)
+    );
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(
+        DateSystem::Excel1904.serial2jdn(i32::MAX),
+        Err(SerialDateError::OutOfRange)
+    );
+    assert_eq!(
+        DateSystem::Excel1904.jdn2serial(Jdnum::MIN),
+        Err(SerialDateError::OutOfRange)
+    );
+}
@@ -0,0 +1,140 @@
+use crate::{
+    Calendar, Jdnum, Month,
+    errors::ArithmeticError,
+    timestamp::{CocoaTimestamp, FileTime, GpsTime, HfsTimestamp, NtpTimestamp},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(0, 0, 2415021, 0)]
+#[case(0, 3891801600, 2460065, 0)]
+#[case(0, 3891808221, 2460065, 6621)]
+#[case(0, u32::MAX, 2464731, 23295)]
+#[case(1, 0, 2464731, 23296)]
+#[case(-1, u32::MAX, 2415020, 86399)]
+fn ntp(#[case] era: i32, #[case] seconds: u32, #[case] jdn: Jdnum, #[case] secs: u32) {
+    let ts = NtpTimestamp::new(era, u64::from(seconds) << 32);
+    assert_eq!(ts.era(), era);
+    assert_eq!(ts.seconds(), seconds);
+    assert_eq!(ts.fraction(), 0);
+    assert_eq!(ts.to_jdn(), Ok((jdn, secs)));
+    assert_eq!(NtpTimestamp::from_jdn(jdn, secs), ts);
+}
+
+#[test]
+fn ntp_fraction() {
+    let ts = NtpTimestamp::new(0, (3891801600 << 32) | 0x8000_0000);
+    assert_eq!(ts.fraction(), 0x8000_0000);
+    assert_eq!(ts.timestamp(), (3891801600 << 32) | 0x8000_0000);
+    let (date, secs) = ts.to_date(Calendar::GREGORIAN).unwrap();
+    assert_eq!(date.to_string(), "2023-04-30");
+    assert_eq!(secs, 0);
+}
+
+#[rstest]
+#[case(1000, 2464731, 1)]
+#[case(1000, 2415021, 0)]
+#[case(u32::MAX, 2464731, 0)]
+#[case(u32::MAX, 2415021, -1)]
+#[case(2_000_000_000, 2440588, 0)]
+#[case(2_000_000_000, 2464731, 1)]
+fn ntp_nearest(#[case] seconds: u32, #[case] reference: Jdnum, #[case] era: i32) {
+    let ts = NtpTimestamp::nearest(u64::from(seconds) << 32, reference);
+    assert_eq!(ts.era(), era);
+    assert_eq!(ts.seconds(), seconds);
+}
+
+#[rstest]
+#[case(0, 0, 1980, Month::January, 6, 0)]
+#[case(2260, 345600, 2023, Month::May, 4, 0)]
+#[case(2260, 351221, 2023, Month::May, 4, 5621)]
+fn gps(
+    #[case] week: u32,
+    #[case] tow: u32,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] secs: u32,
+) {
+    let gps = GpsTime::new(week, tow).unwrap();
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    assert_eq!(gps.to_date(Calendar::GREGORIAN), Ok((date, secs)));
+    assert_eq!(GpsTime::from_date(&date, secs), Ok(gps));
+}
+
+#[test]
+fn gps_errors() {
+    assert_eq!(GpsTime::new(0, 604800), None);
+    assert!(GpsTime::new(0, 604799).is_some());
+    assert_eq!(GpsTime::from_jdn(2444244, 86399), Err(ArithmeticError));
+    assert_eq!(
+        GpsTime::new(u32::MAX, 0).unwrap().to_jdn(),
+        Err(ArithmeticError)
+    );
+}
+
+#[rstest]
+#[case(0, 2305814, 0)]
+#[case(116444736000000000, 2440588, 0)]
+#[case(133272930219999999, 2460065, 6621)]
+#[case(u64::MAX, 23656212, 20170)]
+fn filetime(#[case] ticks: u64, #[case] jdn: Jdnum, #[case] secs: u32) {
+    assert_eq!(FileTime(ticks).to_jdn(), (jdn, secs));
+}
+
+#[test]
+fn filetime_from_jdn() {
+    assert_eq!(
+        FileTime::from_jdn(2440588, 0),
+        Ok(FileTime(116444736000000000))
+    );
+    assert_eq!(FileTime::from_jdn(2305813, 86399), Err(ArithmeticError));
+    assert_eq!(FileTime::from_jdn(23656213, 0), Err(ArithmeticError));
+    let date = Calendar::REFORM1582
+        .at_ymd(1601, Month::January, 1)
+        .unwrap();
+    assert_eq!(FileTime::from_date(&date, 1), Ok(FileTime(10_000_000)));
+}
+
+#[rstest]
+#[case(0, 2416481, 0)]
+#[case(2082844800, 2440588, 0)]
+#[case(u32::MAX, 2466191, 23295)]
+fn hfs(#[case] ts: u32, #[case] jdn: Jdnum, #[case] secs: u32) {
+    assert_eq!(HfsTimestamp(ts).to_jdn(), (jdn, secs));
+    assert_eq!(HfsTimestamp::from_jdn(jdn, secs), Ok(HfsTimestamp(ts)));
+}
+
+#[test]
+fn hfs_errors() {
+    assert_eq!(HfsTimestamp::from_jdn(2416480, 86399), Err(ArithmeticError));
+    assert_eq!(HfsTimestamp::from_jdn(2466191, 23296), Err(ArithmeticError));
+}
+
+#[rstest]
+#[case(0.0, 2451911, 0)]
+#[case(-0.5, 2451910, 86399)]
+#[case(704678400.0, 2460067, 0)]
+#[case(704678400.75, 2460067, 0)]
+#[case(-978307200.0, 2440588, 0)]
+fn cocoa(#[case] ts: f64, #[case] jdn: Jdnum, #[case] secs: u32) {
+    assert_eq!(CocoaTimestamp(ts).to_jdn(), Ok((jdn, secs)));
+}
+
+#[rstest]
+#[case(f64::NAN)]
+#[case(f64::INFINITY)]
+#[case(f64::NEG_INFINITY)]
+#[case(1e17)]
+fn cocoa_errors(#[case] ts: f64) {
+    assert_eq!(CocoaTimestamp(ts).to_jdn(), Err(ArithmeticError));
+}
+
+#[test]
+fn cocoa_from_date() {
+    let date = Calendar::GREGORIAN.at_ymd(2023, Month::May, 2).unwrap();
+    assert_eq!(
+        CocoaTimestamp::from_date(&date, 30),
+        CocoaTimestamp(704678430.0)
+    );
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "tz")]
+use crate::errors::{ParseTzStringError, ParseTzifError, TzError};
+use crate::tz::{LocalTimeResult, TimeZone};
+use crate::{Calendar, Month};
+use rstest::rstest;
+
+static NEW_YORK: &[u8] = include_bytes!("data/tzif/America_New_York");
+static LORD_HOWE: &[u8] = include_bytes!("data/tzif/Australia_Lord_Howe");
+static DUBLIN: &[u8] = include_bytes!("data/tzif/Europe_Dublin");
+static MOSCOW: &[u8] = include_bytes!("data/tzif/Europe_Moscow");
+static UTC: &[u8] = include_bytes!("data/tzif/UTC");
+
+#[rstest]
+#[case(MOSCOW, 1341100800, "2012-07-01", 14400, 14400, false, "MSK")]
+#[case(MOSCOW, 1682906621, "2023-05-01", 18221, 10800, false, "MSK")]
+#[case(NEW_YORK, -5000000000, "1811-07-23", 36638, -17762, false, "LMT")]
+#[case(NEW_YORK, 1673740800, "2023-01-14", 68400, -18000, false, "EST")]
+#[case(NEW_YORK, 1682906621, "2023-04-30", 79421, -14400, true, "EDT")]
+#[case(NEW_YORK, 4118400000, "2100-07-04", 43200, -14400, true, "EDT")]
+#[case(LORD_HOWE, 1673740800, "2023-01-15", 39600, 39600, true, "+11")]
+#[case(LORD_HOWE, 1688169600, "2023-07-01", 37800, 37800, false, "+1030")]
+#[case(DUBLIN, 1673740800, "2023-01-15", 0, 0, true, "GMT")]
+#[case(DUBLIN, 1688169600, "2023-07-01", 3600, 3600, false, "IST")]
+#[case(UTC, 1688169600, "2023-07-01", 0, 0, false, "UTC")]
+fn at_unix_time(
+    #[case] data: &[u8],
+    #[case] unix_time: i64,
+    #[case] date: &str,
+    #[case] seconds: u32,
+    #[case] offset: i32,
+    #[case] is_dst: bool,
+    #[case] abbrev: &str,
+) {
+    let tz = TimeZone::from_tzif(data).unwrap();
+    let (d, secs, ltt) = tz.at_unix_time(Calendar::GREGORIAN, unix_time).unwrap();
+    assert_eq!(d.to_string(), date);
+    assert_eq!(secs, seconds);
+    assert_eq!(ltt.offset().seconds(), offset);
+    assert_eq!(ltt.is_dst(), is_dst);
+    assert_eq!(ltt.abbreviation(), abbrev);
+}
+
+#[test]
+fn utc() {
+    let tz = TimeZone::utc();
+    let ltt = tz.lookup(1688169600).clone();
+    assert_eq!(ltt.offset().seconds(), 0);
+    assert!(!ltt.is_dst());
+    assert_eq!(ltt.abbreviation(), "UTC");
+    let tz = TimeZone::from_tzif(UTC).unwrap();
+    assert_eq!(tz.lookup(1688169600), &ltt);
+}
+
+#[rstest]
+#[case(
+    NEW_YORK,
+    2023,
+    Month::July,
+    4,
+    43200,
+    LocalTimeResult::Unique(1688486400)
+)]
+#[case(NEW_YORK, 2023, Month::March, 12, 9000, LocalTimeResult::Skipped { transition: 1678604400 })]
+#[case(NEW_YORK, 2023, Month::November, 5, 5400, LocalTimeResult::Ambiguous { earlier: 1699162200, later: 1699165800 })]
+#[case(NEW_YORK, 2100, Month::March, 14, 9000, LocalTimeResult::Skipped { transition: 4108690800 })]
+#[case(NEW_YORK, 2100, Month::November, 7, 5400, LocalTimeResult::Ambiguous { earlier: 4129248600, later: 4129252200 })]
+#[case(LORD_HOWE, 2023, Month::October, 1, 7800, LocalTimeResult::Skipped { transition: 1696087800 })]
+#[case(
+    LORD_HOWE,
+    2023,
+    Month::April,
+    2,
+    4800,
+    LocalTimeResult::Unique(1680358800)
+)]
+fn resolve_local(
+    #[case] data: &[u8],
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] seconds: u32,
+    #[case] result: LocalTimeResult,
+) {
+    let tz = TimeZone::from_tzif(data).unwrap();
+    let date = Calendar::GREGORIAN.at_ymd(year, month, day).unwrap();
+    assert_eq!(tz.resolve_local(date, seconds), result);
+}
+
+#[rstest]
+#[case(b"", ParseTzifError::Truncated)]
+#[case(b"TZif2", ParseTzifError::Truncated)]
+#[case(b"XXXX", ParseTzifError::BadMagic)]
+fn bad_tzif(#[case] data: &[u8], #[case] err: ParseTzifError) {
+    assert_eq!(TimeZone::from_tzif(data), Err(err));
+}
+
+#[test]
+fn truncated_tzif() {
+    let data = &NEW_YORK[..NEW_YORK.len() / 2];
+    assert_eq!(TimeZone::from_tzif(data), Err(ParseTzifError::Truncated));
+}
+
+#[rstest]
+#[case("")]
+#[case("/etc/localtime")]
+#[case("../etc/passwd")]
+#[case("America/../../etc/passwd")]
+fn invalid_name(#[case] name: &str) {
+    let r = TimeZone::named(name);
+    assert!(matches!(r, Err(TzError::InvalidName(ref s)) if s == name));
+}
+
+#[rstest]
+#[case("EST5EDT,M3.2.0,M11.1.0")]
+#[case("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0")]
+#[case("IST-1GMT0,M10.5.0,M3.5.0/1")]
+#[case("MSK-3")]
+#[case("EST5EDT,J60,300")]
+fn good_tz_string(#[case] s: &str) {
+    assert!(TimeZone::from_tz_string(s).is_ok());
+}
+
+#[rstest]
+#[case("")]
+#[case("EST")]
+#[case("<+03")]
+#[case("EST5EDT,M3.2.0")]
+#[case("EST5EDT,M13.2.0,M11.1.0")]
+fn bad_tz_string(#[case] s: &str) {
+    assert_eq!(TimeZone::from_tz_string(s), Err(ParseTzStringError));
+}
@@ -1,4 +1,7 @@
-use crate::{Calendar, Jdnum, Month, errors::ArithmeticError, jdn2unix, unix2jdn};
+use crate::{
+    Calendar, Jdnum, Month, errors::ArithmeticError, jdn2unix, jdn2unix_millis, jdn2unix_nanos,
+    unix_millis2jdn, unix_nanos2jdn, unix2jdn,
+};
 use rstest::rstest;
 
 #[rstest]
@@ -88,3 +91,84 @@ fn jdn_to_unix_time(#[case] jdn: Jdnum, #[case] ts: i64) {
     let t = jdn2unix(jdn);
     assert_eq!(t, ts);
 }
+
+#[rstest]
+#[case(-185753453990400000, -2147483648, 0)]
+#[case(-86400001, 2440586, 86399999000000)]
+#[case(-1, 2440587, 86399999000000)]
+#[case(0, 2440588, 0)]
+#[case(1, 2440588, 1000000)]
+#[case(1682906621123, 2460066, 7421123000000)]
+#[case(185331720383999999, 2147483647, 86399999000000)]
+fn unix_millis(#[case] ts: i64, #[case] jdn: Jdnum, #[case] nanos: u64) {
+    assert_eq!(unix_millis2jdn(ts), Ok((jdn, nanos)));
+    assert_eq!(jdn2unix_millis(jdn, nanos), ts);
+}
+
+#[rstest]
+#[case(-185753453990400001)]
+#[case(185331720384000000)]
+#[case(i64::MIN)]
+#[case(i64::MAX)]
+fn unix_millis_out_of_range(#[case] ts: i64) {
+    assert_eq!(unix_millis2jdn(ts), Err(ArithmeticError));
+}
+
+#[test]
+fn jdn2unix_millis_truncates() {
+    assert_eq!(jdn2unix_millis(2440588, 1999999), 1);
+    assert_eq!(jdn2unix_millis(2440587, 86399999999999), -1);
+}
+
+#[rstest]
+#[case(-185753453990400000000000, -2147483648, 0)]
+#[case(-1, 2440587, 86399999999999)]
+#[case(0, 2440588, 0)]
+#[case(1, 2440588, 1)]
+#[case(1682906621123456789, 2460066, 7421123456789)]
+#[case(185331720383999999999999, 2147483647, 86399999999999)]
+fn unix_nanos(#[case] ts: i128, #[case] jdn: Jdnum, #[case] nanos: u64) {
+    assert_eq!(unix_nanos2jdn(ts), Ok((jdn, nanos)));
+    assert_eq!(jdn2unix_nanos(jdn, nanos), ts);
+}
+
+#[rstest]
+#[case(-185753453990400000000001)]
+#[case(185331720384000000000000)]
+#[case(i128::MIN)]
+#[case(i128::MAX)]
+fn unix_nanos_out_of_range(#[case] ts: i128) {
+    assert_eq!(unix_nanos2jdn(ts), Err(ArithmeticError));
+}
+
+#[test]
+fn cal_at_unix_nanos() {
+    let (date, nanos) = Calendar::REFORM1582
+        .at_unix_nanos(-12219292800000000001)
+        .unwrap();
+    assert_eq!(date.year(), 1582);
+    assert_eq!(date.month(), Month::October);
+    assert_eq!(date.day(), 4);
+    assert_eq!(nanos, 86399999999999);
+    let (date, nanos) = Calendar::REFORM1582
+        .at_unix_millis(-12219292800000)
+        .unwrap();
+    assert_eq!(date.day(), 15);
+    assert_eq!(nanos, 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn system_time_nanos() {
+    use crate::system2jdn_nanos;
+    use std::time::{Duration, UNIX_EPOCH};
+    let t = UNIX_EPOCH + Duration::new(1682906621, 123456789);
+    assert_eq!(system2jdn_nanos(t), Ok((2460066, 7421123456789)));
+    let t = UNIX_EPOCH - Duration::new(0, 1);
+    assert_eq!(system2jdn_nanos(t), Ok((2440587, 86399999999999)));
+    let (date, nanos) = Calendar::GREGORIAN
+        .at_system_time_nanos(UNIX_EPOCH + Duration::from_millis(1500))
+        .unwrap();
+    assert_eq!(date.to_string(), "1970-01-01");
+    assert_eq!(nanos, 1500000000);
+}
@@ -0,0 +1,88 @@
+use crate::{Calendar, Month, UtcOffset, errors::ArithmeticError, errors::UtcOffsetError};
+use rstest::rstest;
+
+#[rstest]
+#[case(0, "+00:00")]
+#[case(3600, "+01:00")]
+#[case(-18000, "-05:00")]
+#[case(19800, "+05:30")]
+#[case(-34200, "-09:30")]
+#[case(-1, "-00:00:01")]
+#[case(86399, "+23:59:59")]
+#[case(-86399, "-23:59:59")]
+fn display(#[case] seconds: i32, #[case] s: &str) {
+    let offset = UtcOffset::new(seconds).unwrap();
+    assert_eq!(offset.seconds(), seconds);
+    assert_eq!(offset.to_string(), s);
+}
+
+#[rstest]
+#[case(86400)]
+#[case(-86400)]
+#[case(i32::MAX)]
+#[case(i32::MIN)]
+fn out_of_range(#[case] seconds: i32) {
+    let r = UtcOffset::new(seconds);
+    assert_eq!(r, Err(UtcOffsetError));
+    assert_eq!(
+        r.unwrap_err().to_string(),
+        "UTC offset out of range; must be from -86399 through 86399 seconds"
+    );
+}
+
+#[test]
+fn utc() {
+    assert_eq!(UtcOffset::UTC.seconds(), 0);
+    assert_eq!(UtcOffset::default(), UtcOffset::UTC);
+}
+
+#[rstest]
+#[case(1682906621, 0, 2023, Month::May, 1, 7421)]
+#[case(1682906621, -25200, 2023, Month::April, 30, 68621)]
+#[case(1682906621, 32400, 2023, Month::May, 1, 39821)]
+#[case(0, -1, 1969, Month::December, 31, 86399)]
+#[case(86399, 1, 1970, Month::January, 2, 0)]
+#[case(-185753453990400, 86399, -5884202, Month::March, 16, 86399)]
+#[case(185331720383999, -86399, 5874898, Month::June, 3, 0)]
+fn at_unix_time_with_offset(
+    #[case] ts: i64,
+    #[case] offset: i32,
+    #[case] year: i32,
+    #[case] month: Month,
+    #[case] day: u32,
+    #[case] seconds: u32,
+) {
+    let offset = UtcOffset::new(offset).unwrap();
+    let (date, s) = Calendar::REFORM1582
+        .at_unix_time_with_offset(ts, offset)
+        .unwrap();
+    assert_eq!(date.year(), year);
+    assert_eq!(date.month(), month);
+    assert_eq!(date.day(), day);
+    assert_eq!(s, seconds);
+}
+
+#[rstest]
+#[case(-185753453990400, -1)]
+#[case(185331720383999, 1)]
+#[case(i64::MAX, 1)]
+#[case(i64::MIN, -1)]
+fn at_unix_time_with_offset_overflow(#[case] ts: i64, #[case] offset: i32) {
+    let offset = UtcOffset::new(offset).unwrap();
+    assert_eq!(
+        Calendar::REFORM1582.at_unix_time_with_offset(ts, offset),
+        Err(ArithmeticError)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn at_system_time_with_offset() {
+    use std::time::{Duration, UNIX_EPOCH};
+    let offset = UtcOffset::new(-3600).unwrap();
+    let (date, s) = Calendar::GREGORIAN
+        .at_system_time_with_offset(UNIX_EPOCH + Duration::from_secs(1800), offset)
+        .unwrap();
+    assert_eq!(date.to_string(), "1969-12-31");
+    assert_eq!(s, 84600);
+}
@@ -0,0 +1,462 @@
+//! Conversions to & from timestamps with epochs other than the Unix epoch
+//!
+//! Each timestamp format is represented by its own type, which can be
+//! converted to & from a Julian day number plus a count of seconds since
+//! midnight (like [`unix2jdn()`][crate::unix2jdn]) or a [`Date`] in a given
+//! [`Calendar`].  Sub-second precision is discarded when converting to a day
+//! & seconds pair.
+//!
+//! None of these conversions account for leap seconds; in particular, GPS time
+//! runs ahead of UTC by the number of leap seconds inserted since 1980, which
+//! this module does not correct for.
+//!
+//! # Example
+//!
+//! ```
+//! use julian::{Calendar, timestamp::{FileTime, NtpTimestamp}};
+//!
+//! let ft = FileTime(133272864000000000);
+//! let (date, seconds) = ft.to_date(Calendar::GREGORIAN);
+//! assert_eq!(date.to_string(), "2023-04-30");
+//! assert_eq!(seconds, 0);
+//!
+//! let ntp = NtpTimestamp::from_date(&date, 0);
+//! assert_eq!(ntp.era(), 0);
+//! assert_eq!(ntp.seconds(), 3891801600);
+//! ```
+use crate::errors::ArithmeticError;
+use crate::{Calendar, Date, Jdnum, SECONDS_IN_DAY};
+
+/// The Julian day number of the NTP prime epoch (1900-01-01)
+pub const NTP_EPOCH_JDN: Jdnum = 2415021;
+
+/// The Julian day number of the GPS epoch (1980-01-06)
+pub const GPS_EPOCH_JDN: Jdnum = 2444245;
+
+/// The Julian day number of the Windows `FILETIME` epoch (1601-01-01)
+pub const FILETIME_EPOCH_JDN: Jdnum = 2305814;
+
+/// The Julian day number of the Mac HFS/HFS+ epoch (1904-01-01)
+pub const HFS_EPOCH_JDN: Jdnum = 2416481;
+
+/// The Julian day number of the Cocoa/Core Foundation reference date
+/// (2001-01-01)
+pub const COCOA_EPOCH_JDN: Jdnum = 2451911;
+
+/// The number of seconds in an NTP era
+const NTP_ERA_SECONDS: i64 = 1 << 32;
+
+/// The number of seconds in a week
+const SECONDS_IN_WEEK: i64 = 7 * SECONDS_IN_DAY;
+
+/// The number of `FILETIME` ticks in a second
+const FILETIME_TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// An [NTP][] timestamp, consisting of an era number, a count of seconds
+/// within the era, and a binary fraction of a second.
+///
+/// Era 0 began at the NTP prime epoch, 1900-01-01 00:00:00 UTC; era 1 begins
+/// 2<sup>32</sup> seconds later, in 2036.  The on-the-wire 64-bit timestamp
+/// format does not include the era, so callers must supply it or have it
+/// inferred from a nearby reference date via
+/// [`NtpTimestamp::nearest()`].
+///
+/// [NTP]: https://en.wikipedia.org/wiki/Network_Time_Protocol
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NtpTimestamp {
+    era: i32,
+    seconds: u32,
+    fraction: u32,
+}
+
+impl NtpTimestamp {
+    /// Construct an NTP timestamp from an era number and a 64-bit 32.32
+    /// fixed-point timestamp
+    pub const fn new(era: i32, timestamp: u64) -> NtpTimestamp {
+        #[allow(clippy::cast_possible_truncation)]
+        NtpTimestamp {
+            era,
+            seconds: (timestamp >> 32) as u32,
+            fraction: timestamp as u32,
+        }
+    }
+
+    /// Construct an NTP timestamp from a 64-bit 32.32 fixed-point timestamp,
+    /// choosing the era that places the timestamp closest to midnight UTC on
+    /// the given reference Julian day number
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::timestamp::NtpTimestamp;
+    ///
+    /// // A timestamp shortly after the 2036 era rollover:
+    /// let ts = NtpTimestamp::nearest(1000 << 32, 2464731);
+    /// assert_eq!(ts.era(), 1);
+    /// assert_eq!(ts.to_jdn(), Ok((2464731, 24296)));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn nearest(timestamp: u64, reference: Jdnum) -> NtpTimestamp {
+        let reference = ((reference as i64) - (NTP_EPOCH_JDN as i64)) * SECONDS_IN_DAY;
+        let seconds = ((timestamp >> 32) as u32) as i64;
+        let mut era = reference.div_euclid(NTP_ERA_SECONDS);
+        let offset = reference.rem_euclid(NTP_ERA_SECONDS);
+        if seconds - offset > NTP_ERA_SECONDS / 2 {
+            era -= 1;
+        } else if offset - seconds > NTP_ERA_SECONDS / 2 {
+            era += 1;
+        }
+        // The era of any Julian day number fits in an `i32`.
+        NtpTimestamp::new(era as i32, timestamp)
+    }
+
+    /// Returns the timestamp's era number
+    pub const fn era(&self) -> i32 {
+        self.era
+    }
+
+    /// Returns the number of whole seconds since the start of the era
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Returns the fractional part of the timestamp in units of
+    /// 2<sup>-32</sup> seconds
+    pub const fn fraction(&self) -> u32 {
+        self.fraction
+    }
+
+    /// Returns the 64-bit 32.32 fixed-point timestamp without the era
+    pub const fn timestamp(&self) -> u64 {
+        ((self.seconds as u64) << 32) | (self.fraction as u64)
+    }
+
+    /// Converts the timestamp to a Julian day number, along with a count of
+    /// seconds since midnight UTC
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+    /// conversion.
+    pub const fn to_jdn(&self) -> Result<(Jdnum, u32), ArithmeticError> {
+        let secs = (self.era as i64) * NTP_ERA_SECONDS + (self.seconds as i64);
+        secs2jdn(NTP_EPOCH_JDN, secs)
+    }
+
+    /// Converts the timestamp to a [`Date`] in the given calendar, along with
+    /// a count of seconds since midnight UTC
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+    /// conversion.
+    pub const fn to_date(&self, calendar: Calendar) -> Result<(Date, u32), ArithmeticError> {
+        match self.to_jdn() {
+            Ok((jdn, secs)) => Ok((calendar.at_jdn(jdn), secs)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the NTP timestamp for the given number of seconds after
+    /// midnight UTC on the day with the given Julian day number
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn from_jdn(jdn: Jdnum, seconds: u32) -> NtpTimestamp {
+        let secs = jdn2secs(NTP_EPOCH_JDN, jdn, seconds);
+        // The era of any Julian day number fits in an `i32`, and the
+        // remainder is always in range for a `u32`.
+        NtpTimestamp {
+            era: secs.div_euclid(NTP_ERA_SECONDS) as i32,
+            seconds: secs.rem_euclid(NTP_ERA_SECONDS) as u32,
+            fraction: 0,
+        }
+    }
+
+    /// Returns the NTP timestamp for the given number of seconds after
+    /// midnight UTC on the given date
+    pub const fn from_date(date: &Date, seconds: u32) -> NtpTimestamp {
+        NtpTimestamp::from_jdn(date.julian_day_number(), seconds)
+    }
+}
+
+/// A GPS time, consisting of a week number counted from the GPS epoch
+/// (1980-01-06) and a number of seconds into the week.
+///
+/// GPS time does not include leap seconds and so runs ahead of UTC; the
+/// conversions provided here do not correct for this.  The week number is the
+/// full week count, not a 10- or 13-bit broadcast value subject to rollover.
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GpsTime {
+    week: u32,
+    seconds: u32,
+}
+
+impl GpsTime {
+    /// Construct a GPS time from a week number and a time of week in seconds.
+    /// Returns `None` if `seconds` is not less than 604800 (the number of
+    /// seconds in a week).
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn new(week: u32, seconds: u32) -> Option<GpsTime> {
+        if seconds < SECONDS_IN_WEEK as u32 {
+            Some(GpsTime { week, seconds })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the week number
+    pub const fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// Returns the number of seconds since the start of the week
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Converts the GPS time to a Julian day number, along with a count of
+    /// seconds since midnight GPS time
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+    /// conversion.
+    pub const fn to_jdn(&self) -> Result<(Jdnum, u32), ArithmeticError> {
+        let secs = (self.week as i64) * SECONDS_IN_WEEK + (self.seconds as i64);
+        secs2jdn(GPS_EPOCH_JDN, secs)
+    }
+
+    /// Converts the GPS time to a [`Date`] in the given calendar, along with
+    /// a count of seconds since midnight GPS time
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs during
+    /// conversion.
+    pub const fn to_date(&self, calendar: Calendar) -> Result<(Date, u32), ArithmeticError> {
+        match self.to_jdn() {
+            Ok((jdn, secs)) => Ok((calendar.at_jdn(jdn), secs)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the GPS time for the given number of seconds after midnight
+    /// GPS time on the day with the given Julian day number
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before the GPS epoch.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn from_jdn(jdn: Jdnum, seconds: u32) -> Result<GpsTime, ArithmeticError> {
+        let secs = jdn2secs(GPS_EPOCH_JDN, jdn, seconds);
+        if secs < 0 {
+            return Err(ArithmeticError);
+        }
+        // The week number of any Julian day number fits in a `u32`.
+        Ok(GpsTime {
+            week: (secs / SECONDS_IN_WEEK) as u32,
+            seconds: (secs % SECONDS_IN_WEEK) as u32,
+        })
+    }
+
+    /// Returns the GPS time for the given number of seconds after midnight
+    /// GPS time on the given date
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before the GPS epoch.
+    pub const fn from_date(date: &Date, seconds: u32) -> Result<GpsTime, ArithmeticError> {
+        GpsTime::from_jdn(date.julian_day_number(), seconds)
+    }
+}
+
+/// A Windows [`FILETIME`][ft] value: a count of 100-nanosecond intervals since
+/// 1601-01-01 00:00:00 UTC
+///
+/// [ft]: https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FileTime(pub u64);
+
+impl FileTime {
+    /// Converts the `FILETIME` to a Julian day number, along with a count of
+    /// seconds since midnight UTC.  As every `FILETIME` falls within the range
+    /// of [`Jdnum`], this conversion cannot fail.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn to_jdn(&self) -> (Jdnum, u32) {
+        let secs = self.0 / FILETIME_TICKS_PER_SECOND;
+        let days = secs / (SECONDS_IN_DAY as u64);
+        // `u64::MAX` ticks is less than 2^25 days, so these casts are lossless.
+        (
+            FILETIME_EPOCH_JDN + (days as Jdnum),
+            (secs % (SECONDS_IN_DAY as u64)) as u32,
+        )
+    }
+
+    /// Converts the `FILETIME` to a [`Date`] in the given calendar, along
+    /// with a count of seconds since midnight UTC
+    pub const fn to_date(&self, calendar: Calendar) -> (Date, u32) {
+        let (jdn, secs) = self.to_jdn();
+        (calendar.at_jdn(jdn), secs)
+    }
+
+    /// Returns the `FILETIME` for the given number of seconds after midnight
+    /// UTC on the day with the given Julian day number
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before the `FILETIME` epoch
+    /// or too late to be represented.
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn from_jdn(jdn: Jdnum, seconds: u32) -> Result<FileTime, ArithmeticError> {
+        let secs = jdn2secs(FILETIME_EPOCH_JDN, jdn, seconds);
+        if secs < 0 {
+            return Err(ArithmeticError);
+        }
+        match (secs as u64).checked_mul(FILETIME_TICKS_PER_SECOND) {
+            Some(ticks) => Ok(FileTime(ticks)),
+            None => Err(ArithmeticError),
+        }
+    }
+
+    /// Returns the `FILETIME` for the given number of seconds after midnight
+    /// UTC on the given date
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before the `FILETIME` epoch
+    /// or too late to be represented.
+    pub const fn from_date(date: &Date, seconds: u32) -> Result<FileTime, ArithmeticError> {
+        FileTime::from_jdn(date.julian_day_number(), seconds)
+    }
+}
+
+/// A Mac HFS/HFS+ timestamp: an unsigned count of seconds since 1904-01-01
+/// 00:00:00.  HFS+ records times in UTC, while the original HFS used local
+/// time.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct HfsTimestamp(pub u32);
+
+impl HfsTimestamp {
+    /// Converts the timestamp to a Julian day number, along with a count of
+    /// seconds since midnight.  This conversion cannot fail.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn to_jdn(&self) -> (Jdnum, u32) {
+        let days = self.0 / (SECONDS_IN_DAY as u32);
+        (
+            HFS_EPOCH_JDN + (days as Jdnum),
+            self.0 % (SECONDS_IN_DAY as u32),
+        )
+    }
+
+    /// Converts the timestamp to a [`Date`] in the given calendar, along with
+    /// a count of seconds since midnight
+    pub const fn to_date(&self, calendar: Calendar) -> (Date, u32) {
+        let (jdn, secs) = self.to_jdn();
+        (calendar.at_jdn(jdn), secs)
+    }
+
+    /// Returns the HFS timestamp for the given number of seconds after
+    /// midnight on the day with the given Julian day number
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before 1904-01-01 or after
+    /// 2040-02-06 06:28:15.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn from_jdn(jdn: Jdnum, seconds: u32) -> Result<HfsTimestamp, ArithmeticError> {
+        let secs = jdn2secs(HFS_EPOCH_JDN, jdn, seconds);
+        if 0 <= secs && secs <= u32::MAX as i64 {
+            Ok(HfsTimestamp(secs as u32))
+        } else {
+            Err(ArithmeticError)
+        }
+    }
+
+    /// Returns the HFS timestamp for the given number of seconds after
+    /// midnight on the given date
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the time is before 1904-01-01 or after
+    /// 2040-02-06 06:28:15.
+    pub const fn from_date(date: &Date, seconds: u32) -> Result<HfsTimestamp, ArithmeticError> {
+        HfsTimestamp::from_jdn(date.julian_day_number(), seconds)
+    }
+}
+
+/// A Cocoa/Core Foundation absolute time (`NSDate` reference date time): a
+/// floating-point count of seconds since 2001-01-01 00:00:00 UTC
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct CocoaTimestamp(pub f64);
+
+impl CocoaTimestamp {
+    /// Converts the timestamp to a Julian day number, along with a count of
+    /// seconds since midnight UTC.  Fractional seconds are discarded, rounding
+    /// towards negative infinity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the timestamp is NaN or infinite or if
+    /// numeric overflow/underflow occurs during conversion.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_jdn(&self) -> Result<(Jdnum, u32), ArithmeticError> {
+        // Bounds chosen so that the floored value always fits in an `i64`
+        // and is well outside the range of representable dates:
+        if !(-1e18 < self.0 && self.0 < 1e18) {
+            return Err(ArithmeticError);
+        }
+        // `f64::floor()` requires `std`, so floor by hand:
+        let mut secs = self.0 as i64;
+        #[allow(clippy::cast_precision_loss)]
+        if (secs as f64) > self.0 {
+            secs -= 1;
+        }
+        secs2jdn(COCOA_EPOCH_JDN, secs)
+    }
+
+    /// Converts the timestamp to a [`Date`] in the given calendar, along with
+    /// a count of seconds since midnight UTC
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if the timestamp is NaN or infinite or if
+    /// numeric overflow/underflow occurs during conversion.
+    pub fn to_date(&self, calendar: Calendar) -> Result<(Date, u32), ArithmeticError> {
+        let (jdn, secs) = self.to_jdn()?;
+        Ok((calendar.at_jdn(jdn), secs))
+    }
+
+    /// Returns the Cocoa timestamp for the given number of seconds after
+    /// midnight UTC on the day with the given Julian day number
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn from_jdn(jdn: Jdnum, seconds: u32) -> CocoaTimestamp {
+        // Every Julian day number's timestamp is less than 2^53 in magnitude
+        // and thus exactly representable.
+        CocoaTimestamp(jdn2secs(COCOA_EPOCH_JDN, jdn, seconds) as f64)
+    }
+
+    /// Returns the Cocoa timestamp for the given number of seconds after
+    /// midnight UTC on the given date
+    pub const fn from_date(date: &Date, seconds: u32) -> CocoaTimestamp {
+        CocoaTimestamp::from_jdn(date.julian_day_number(), seconds)
+    }
+}
+
+/// [Private] Converts a count of seconds since midnight on the day with
+/// Julian day number `epoch` to a Julian day number and a count of seconds
+/// since midnight
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn secs2jdn(epoch: Jdnum, secs: i64) -> Result<(Jdnum, u32), ArithmeticError> {
+    let jd = secs.div_euclid(SECONDS_IN_DAY) + (epoch as i64);
+    if Jdnum::MIN as i64 <= jd && jd <= Jdnum::MAX as i64 {
+        Ok((jd as Jdnum, secs.rem_euclid(SECONDS_IN_DAY) as u32))
+    } else {
+        Err(ArithmeticError)
+    }
+}
+
+/// [Private] Converts a Julian day number and a count of seconds since
+/// midnight to a count of seconds since midnight on the day with Julian day
+/// number `epoch`
+const fn jdn2secs(epoch: Jdnum, jdn: Jdnum, seconds: u32) -> i64 {
+    ((jdn as i64) - (epoch as i64)) * SECONDS_IN_DAY + (seconds as i64)
+}
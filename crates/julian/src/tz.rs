@@ -0,0 +1,805 @@
+//! Time zones loaded from TZif files
+//!
+//! This module provides a [`TimeZone`] type for determining local dates &
+//! times in a given time zone, as described by a [TZif][] file such as those
+//! installed in `/usr/share/zoneinfo` on most Unix-like systems.  Time zones
+//! can be loaded by name from the system's zoneinfo directory, from a file at
+//! an arbitrary path, from a byte slice, or from a POSIX `TZ` string.
+//!
+//! Times after the last transition listed in a TZif file are handled using the
+//! POSIX `TZ` string in the file's footer, if present.  TZif files that record
+//! leap seconds (i.e., those under `/usr/share/zoneinfo/right/`) are not
+//! supported.
+//!
+//! [TZif]: https://www.rfc-editor.org/rfc/rfc8536
+//!
+//! # Example
+//!
+//! ```no_run
+//! use julian::{Calendar, tz::TimeZone};
+//!
+//! let moscow = TimeZone::named("Europe/Moscow").unwrap();
+//! let (date, seconds, ltt) = moscow
+//!     .at_unix_time(Calendar::GREGORIAN, 1682906621)
+//!     .unwrap();
+//! assert_eq!(date.to_string(), "2023-05-01");
+//! assert_eq!(seconds, 18221);
+//! assert_eq!(ltt.abbreviation(), "MSK");
+//! assert_eq!(ltt.offset().seconds(), 10800);
+//! ```
+use crate::errors::{ArithmeticError, ParseTzStringError, ParseTzifError, TzError};
+use crate::{Calendar, Date, Month, SECONDS_IN_DAY, UtcOffset, Weekday, jdn2unix, unix2jdn};
+use std::borrow::ToOwned;
+use std::path::Path;
+use std::string::String;
+use std::vec::Vec;
+
+/// The directory in which [`TimeZone::named()`] looks for TZif files
+pub const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// The path to the file describing the system's local time zone
+const LOCALTIME_PATH: &str = "/etc/localtime";
+
+/// A type of local time in a time zone, such as standard time or daylight
+/// saving time
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocalTimeType {
+    offset: UtcOffset,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+impl LocalTimeType {
+    /// Returns the offset from UTC of local time
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Returns true if this local time type is considered daylight saving time
+    pub const fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Returns the abbreviation used for the local time type, e.g., "EST" or
+    /// "+03"
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+/// The result of converting a local date & time to a Unix time with
+/// [`TimeZone::resolve_local()`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LocalTimeResult {
+    /// The local time occurs exactly once, at the given Unix time
+    Unique(i64),
+
+    /// The local time occurs twice, due to the clocks being set back (e.g., at
+    /// the end of daylight saving time)
+    Ambiguous {
+        /// The Unix time of the first occurrence of the local time
+        earlier: i64,
+
+        /// The Unix time of the second occurrence of the local time
+        later: i64,
+    },
+
+    /// The local time does not occur, due to the clocks being set forwards
+    /// (e.g., at the start of daylight saving time)
+    Skipped {
+        /// The Unix time at which the clocks were set forwards
+        transition: i64,
+    },
+}
+
+/// A time zone, consisting of a history of transitions between local time
+/// types plus an optional rule for times after the last transition
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeZone {
+    /// Unix times at which the local time type changes, in ascending order,
+    /// each paired with an index into `types`
+    transitions: Vec<(i64, usize)>,
+
+    /// The local time types used by `transitions`; always nonempty.  Times
+    /// before the first transition use the first type.
+    types: Vec<LocalTimeType>,
+
+    /// The rule governing times after the last transition
+    rule: Option<PosixRule>,
+}
+
+impl TimeZone {
+    /// Returns a time zone that is always UTC
+    pub fn utc() -> TimeZone {
+        TimeZone {
+            transitions: Vec::new(),
+            types: Vec::from([LocalTimeType {
+                offset: UtcOffset::UTC,
+                is_dst: false,
+                abbreviation: "UTC".to_owned(),
+            }]),
+            rule: None,
+        }
+    }
+
+    /// Load the time zone with the given IANA name (e.g., `"Europe/Moscow"`)
+    /// from [`ZONEINFO_DIR`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TzError::InvalidName`] if `name` is empty, is an absolute
+    /// path, or contains a `..` component.
+    ///
+    /// Returns [`TzError::Io`] if the file could not be read.
+    ///
+    /// Returns [`TzError::Tzif`] if the file is not a valid TZif file.
+    pub fn named(name: &str) -> Result<TimeZone, TzError> {
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|c| c == "..") {
+            return Err(TzError::InvalidName(name.to_owned()));
+        }
+        TimeZone::from_path(Path::new(ZONEINFO_DIR).join(name))
+    }
+
+    /// Load the system's local time zone from `/etc/localtime`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TzError::Io`] if the file could not be read.
+    ///
+    /// Returns [`TzError::Tzif`] if the file is not a valid TZif file.
+    pub fn local() -> Result<TimeZone, TzError> {
+        TimeZone::from_path(LOCALTIME_PATH)
+    }
+
+    /// Load a time zone from the TZif file at the given path
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TzError::Io`] if the file could not be read.
+    ///
+    /// Returns [`TzError::Tzif`] if the file is not a valid TZif file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<TimeZone, TzError> {
+        let data = std::fs::read(path)?;
+        Ok(TimeZone::from_tzif(&data)?)
+    }
+
+    /// Parse a time zone from the contents of a TZif file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseTzifError`] if the data is not a valid TZif file.
+    pub fn from_tzif(data: &[u8]) -> Result<TimeZone, ParseTzifError> {
+        let mut reader = Reader(data);
+        let (version, counts) = reader.header()?;
+        if version == 0 {
+            return reader.data_block(&counts, 4, None);
+        }
+        // Skip the version 1 data block, which is superseded by the version
+        // 2+ block:
+        reader.take(counts.block_size(4)?)?;
+        let (_, counts) = reader.header()?;
+        let mut rest = Reader(reader.take(counts.block_size(8)?)?);
+        let footer = reader.footer()?;
+        rest.data_block(&counts, 8, footer)
+    }
+
+    /// Parse a time zone from a POSIX `TZ` string, such as
+    /// `"EST5EDT,M3.2.0,M11.1.0"`.  Only the forms of `TZ` string that can
+    /// occur in the footer of a TZif file are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseTzStringError`] if the string is not a valid `TZ`
+    /// string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, tz::TimeZone};
+    ///
+    /// let tz = TimeZone::from_tz_string("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    /// let (date, seconds, ltt) = tz.at_unix_time(Calendar::GREGORIAN, 1682906621).unwrap();
+    /// assert_eq!(date.to_string(), "2023-04-30");
+    /// assert_eq!(seconds, 79421);
+    /// assert_eq!(ltt.abbreviation(), "EDT");
+    /// ```
+    pub fn from_tz_string(s: &str) -> Result<TimeZone, ParseTzStringError> {
+        let rule = PosixRule::parse(s)?;
+        Ok(TimeZone {
+            transitions: Vec::new(),
+            types: Vec::from([rule.std.clone()]),
+            rule: Some(rule),
+        })
+    }
+
+    /// Returns the local time type in effect at the given Unix time
+    pub fn lookup(&self, unix_time: i64) -> &LocalTimeType {
+        let i = self.transitions.partition_point(|&(t, _)| t <= unix_time);
+        if i == self.transitions.len()
+            && let Some(rule) = self.rule.as_ref()
+        {
+            return rule.lookup(unix_time);
+        }
+        match i.checked_sub(1) {
+            Some(j) => &self.types[self.transitions[j].1],
+            None => &self.types[0],
+        }
+    }
+
+    /// Returns the local date in the given calendar for the given Unix time,
+    /// along with the count of seconds since local midnight and the local time
+    /// type in effect
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.
+    pub fn at_unix_time(
+        &self,
+        calendar: Calendar,
+        unix_time: i64,
+    ) -> Result<(Date, u32, &LocalTimeType), ArithmeticError> {
+        let ltt = self.lookup(unix_time);
+        let (date, secs) = calendar.at_unix_time_with_offset(unix_time, ltt.offset)?;
+        Ok((date, secs, ltt))
+    }
+
+    /// Returns the current local date in the given calendar, along with the
+    /// count of seconds since local midnight and the local time type in
+    /// effect
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError`] if numeric overflow/underflow occurs while
+    /// converting the time.
+    pub fn now(&self, calendar: Calendar) -> Result<(Date, u32, &LocalTimeType), ArithmeticError> {
+        self.at_unix_time(calendar, crate::system2unix(std::time::SystemTime::now())?)
+    }
+
+    /// Determine the Unix time(s) at which the given local date & count of
+    /// seconds since local midnight occur in the time zone.  If the local
+    /// time was skipped or repeated by a transition, this is reported in the
+    /// return value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use julian::{Calendar, Month, tz::{LocalTimeResult, TimeZone}};
+    ///
+    /// let tz = TimeZone::from_tz_string("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    /// let date = Calendar::GREGORIAN.at_ymd(2023, Month::March, 12).unwrap();
+    /// assert_eq!(
+    ///     tz.resolve_local(date, 9000),
+    ///     LocalTimeResult::Skipped { transition: 1678604400 },
+    /// );
+    ///
+    /// let date = Calendar::GREGORIAN.at_ymd(2023, Month::November, 5).unwrap();
+    /// assert_eq!(
+    ///     tz.resolve_local(date, 5400),
+    ///     LocalTimeResult::Ambiguous { earlier: 1699162200, later: 1699165800 },
+    /// );
+    /// ```
+    pub fn resolve_local(&self, date: Date, seconds: u32) -> LocalTimeResult {
+        let local = jdn2unix(date.julian_day_number()) + i64::from(seconds);
+        // All offsets are less than a day in magnitude, so any instant with
+        // the given local time lies within a day of `local`.
+        let lo = local - SECONDS_IN_DAY;
+        let hi = local + SECONDS_IN_DAY;
+        let mut offsets = Vec::from([
+            self.lookup(lo).offset.seconds(),
+            self.lookup(hi).offset.seconds(),
+        ]);
+        let start = self.transitions.partition_point(|&(t, _)| t < lo);
+        for &(t, i) in &self.transitions[start..] {
+            if t > hi {
+                break;
+            }
+            offsets.push(self.types[i].offset.seconds());
+        }
+        if let Some(rule) = self.rule.as_ref() {
+            offsets.push(rule.std.offset.seconds());
+            if let Some(dst) = rule.dst.as_ref() {
+                offsets.push(dst.ltt.offset.seconds());
+            }
+        }
+        let mut candidates = offsets
+            .into_iter()
+            .map(|off| local - i64::from(off))
+            .filter(|&u| self.local_seconds(u) == local)
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates.dedup();
+        match (candidates.first(), candidates.last()) {
+            (Some(&earlier), Some(&later)) if earlier != later => {
+                LocalTimeResult::Ambiguous { earlier, later }
+            }
+            (Some(&u), _) => LocalTimeResult::Unique(u),
+            _ => {
+                // Find the transition at which local time jumps past `local`
+                // by bisection:
+                let (mut lo, mut hi) = (lo, hi);
+                while hi - lo > 1 {
+                    let mid = lo + (hi - lo) / 2;
+                    if self.local_seconds(mid) > local {
+                        hi = mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+                LocalTimeResult::Skipped { transition: hi }
+            }
+        }
+    }
+
+    /// [Private] Returns the given Unix time shifted by the UTC offset in
+    /// effect at that time
+    fn local_seconds(&self, unix_time: i64) -> i64 {
+        unix_time + i64::from(self.lookup(unix_time).offset.seconds())
+    }
+}
+
+/// [Private] A rule for local time given by a POSIX `TZ` string
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PosixRule {
+    std: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+/// [Private] The daylight saving time portion of a POSIX `TZ` string
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct DstRule {
+    ltt: LocalTimeType,
+    start: RuleDate,
+    /// Local (standard) time of day at which DST starts, in seconds
+    start_time: i64,
+    end: RuleDate,
+    /// Local (daylight) time of day at which DST ends, in seconds
+    end_time: i64,
+}
+
+/// [Private] A day of the year in a POSIX `TZ` string rule
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RuleDate {
+    /// `Jn`: day `n` (1 through 365) of the year, never counting February 29
+    Julian1(u32),
+    /// `n`: zero-based day `n` (0 through 365) of the year
+    Julian0(u32),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (1 through 5, where 5
+    /// means "last") of month `m`
+    MonthWeekDay {
+        month: Month,
+        week: u32,
+        weekday: u32,
+    },
+}
+
+impl RuleDate {
+    /// [Private] Returns the Unix time of midnight at the start of the date in
+    /// the given (Gregorian) year, ignoring UTC offsets.  Returns `None` if the
+    /// year is out of range.
+    fn unix_midnight(self, year: i32) -> Option<i64> {
+        let cal = Calendar::GREGORIAN;
+        let jan1 = cal
+            .at_ymd(year, Month::January, 1)
+            .ok()?
+            .julian_day_number();
+        let jdn = match self {
+            RuleDate::Julian1(n) => {
+                let leap = u32::from(n >= 60 && cal.year_kind(year).is_leap());
+                jan1.checked_add_unsigned(n - 1 + leap)?
+            }
+            RuleDate::Julian0(n) => jan1.checked_add_unsigned(n)?,
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = cal.at_ymd(year, month, 1).ok()?.julian_day_number();
+                let first_wd = Weekday::for_jdn(first).number() % 7;
+                let mut day = 1 + (weekday + 7 - first_wd) % 7 + 7 * (week - 1);
+                loop {
+                    match cal.at_ymd(year, month, day) {
+                        Ok(d) => break d.julian_day_number(),
+                        Err(_) if day > 7 => day -= 7,
+                        Err(_) => return None,
+                    }
+                }
+            }
+        };
+        Some(jdn2unix(jdn))
+    }
+}
+
+impl PosixRule {
+    /// [Private] Returns the local time type in effect at the given Unix time
+    fn lookup(&self, unix_time: i64) -> &LocalTimeType {
+        let Some(dst) = self.dst.as_ref() else {
+            return &self.std;
+        };
+        let local = unix_time.saturating_add(i64::from(self.std.offset.seconds()));
+        let Ok((jdn, _)) = unix2jdn(local) else {
+            return &self.std;
+        };
+        let year = Calendar::GREGORIAN.at_jdn(jdn).year();
+        let (Some(start), Some(end)) = (dst.start.unix_midnight(year), dst.end.unix_midnight(year))
+        else {
+            return &self.std;
+        };
+        let start = start + dst.start_time - i64::from(self.std.offset.seconds());
+        let end = end + dst.end_time - i64::from(dst.ltt.offset.seconds());
+        let in_dst = if start <= end {
+            start <= unix_time && unix_time < end
+        } else {
+            unix_time < end || start <= unix_time
+        };
+        if in_dst { &dst.ltt } else { &self.std }
+    }
+
+    /// [Private] Parse a POSIX `TZ` string
+    fn parse(s: &str) -> Result<PosixRule, ParseTzStringError> {
+        let mut p = TzStringParser(s);
+        let std_abbrev = p.abbreviation()?;
+        let std_offset = p.offset()?;
+        let std = LocalTimeType {
+            offset: std_offset,
+            is_dst: false,
+            abbreviation: std_abbrev.to_owned(),
+        };
+        if p.0.is_empty() {
+            return Ok(PosixRule { std, dst: None });
+        }
+        let dst_abbrev = p.abbreviation()?;
+        let dst_offset = if p.0.starts_with(',') || p.0.is_empty() {
+            UtcOffset::new(std_offset.seconds() + 3600).map_err(|_| ParseTzStringError)?
+        } else {
+            p.offset()?
+        };
+        let (start, start_time, end, end_time) = if p.0.is_empty() {
+            // Default to the US rules, as many implementations do:
+            (
+                RuleDate::MonthWeekDay {
+                    month: Month::March,
+                    week: 2,
+                    weekday: 0,
+                },
+                7200,
+                RuleDate::MonthWeekDay {
+                    month: Month::November,
+                    week: 1,
+                    weekday: 0,
+                },
+                7200,
+            )
+        } else {
+            p.expect(',')?;
+            let start = p.rule_date()?;
+            let start_time = p.rule_time()?;
+            p.expect(',')?;
+            let end = p.rule_date()?;
+            let end_time = p.rule_time()?;
+            if !p.0.is_empty() {
+                return Err(ParseTzStringError);
+            }
+            (start, start_time, end, end_time)
+        };
+        Ok(PosixRule {
+            std,
+            dst: Some(DstRule {
+                ltt: LocalTimeType {
+                    offset: dst_offset,
+                    is_dst: true,
+                    abbreviation: dst_abbrev.to_owned(),
+                },
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+}
+
+/// [Private] A simple parser for POSIX `TZ` strings
+struct TzStringParser<'a>(&'a str);
+
+impl<'a> TzStringParser<'a> {
+    fn expect(&mut self, ch: char) -> Result<(), ParseTzStringError> {
+        self.0 = self.0.strip_prefix(ch).ok_or(ParseTzStringError)?;
+        Ok(())
+    }
+
+    /// Parse a time zone abbreviation, either unquoted alphabetic or enclosed
+    /// in angle brackets
+    fn abbreviation(&mut self) -> Result<&'a str, ParseTzStringError> {
+        let (abbrev, rest) = if let Some(s) = self.0.strip_prefix('<') {
+            let (abbrev, rest) = s.split_once('>').ok_or(ParseTzStringError)?;
+            if !abbrev
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-')
+            {
+                return Err(ParseTzStringError);
+            }
+            (abbrev, rest)
+        } else {
+            let i = self
+                .0
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(self.0.len());
+            self.0.split_at(i)
+        };
+        if abbrev.len() < 3 {
+            return Err(ParseTzStringError);
+        }
+        self.0 = rest;
+        Ok(abbrev)
+    }
+
+    /// Parse an optionally-signed `hh[:mm[:ss]]` duration as a number of
+    /// seconds, with hours ranging up to `max_hours`
+    fn hms(&mut self, max_hours: u32) -> Result<i64, ParseTzStringError> {
+        let negative = if let Some(s) = self.0.strip_prefix('-') {
+            self.0 = s;
+            true
+        } else {
+            self.0 = self.0.strip_prefix('+').unwrap_or(self.0);
+            false
+        };
+        let hours = self.number(max_hours)?;
+        let mut secs = i64::from(hours) * 3600;
+        if self.0.starts_with(':') {
+            self.expect(':')?;
+            secs += i64::from(self.number(59)?) * 60;
+            if self.0.starts_with(':') {
+                self.expect(':')?;
+                secs += i64::from(self.number(59)?);
+            }
+        }
+        Ok(if negative { -secs } else { secs })
+    }
+
+    /// Parse a UTC offset given in hours *west* of UTC
+    fn offset(&mut self) -> Result<UtcOffset, ParseTzStringError> {
+        let west = self.hms(24)?;
+        i32::try_from(-west)
+            .ok()
+            .and_then(|secs| UtcOffset::new(secs).ok())
+            .ok_or(ParseTzStringError)
+    }
+
+    /// Parse an unsigned decimal number no greater than `max`
+    fn number(&mut self, max: u32) -> Result<u32, ParseTzStringError> {
+        let i = self
+            .0
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.0.len());
+        let (digits, rest) = self.0.split_at(i);
+        let n = digits.parse::<u32>().map_err(|_| ParseTzStringError)?;
+        if n > max {
+            return Err(ParseTzStringError);
+        }
+        self.0 = rest;
+        Ok(n)
+    }
+
+    fn rule_date(&mut self) -> Result<RuleDate, ParseTzStringError> {
+        if let Some(s) = self.0.strip_prefix('J') {
+            self.0 = s;
+            match self.number(365)? {
+                0 => Err(ParseTzStringError),
+                n => Ok(RuleDate::Julian1(n)),
+            }
+        } else if let Some(s) = self.0.strip_prefix('M') {
+            self.0 = s;
+            let month = Month::try_from(self.number(12)?).map_err(|_| ParseTzStringError)?;
+            self.expect('.')?;
+            let week = self.number(5)?;
+            if week == 0 {
+                return Err(ParseTzStringError);
+            }
+            self.expect('.')?;
+            let weekday = self.number(6)?;
+            Ok(RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            })
+        } else {
+            Ok(RuleDate::Julian0(self.number(365)?))
+        }
+    }
+
+    /// Parse an optional `/time` suffix of a rule date, defaulting to 02:00
+    fn rule_time(&mut self) -> Result<i64, ParseTzStringError> {
+        if self.0.starts_with('/') {
+            self.expect('/')?;
+            self.hms(167)
+        } else {
+            Ok(7200)
+        }
+    }
+}
+
+/// [Private] The counts of items in a TZif data block
+struct Counts {
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Counts {
+    /// [Private] Returns the size in bytes of a data block with these counts
+    /// and the given width of time values
+    fn block_size(&self, time_width: usize) -> Result<usize, ParseTzifError> {
+        [
+            self.timecnt.checked_mul(time_width),
+            Some(self.timecnt),
+            self.typecnt.checked_mul(6),
+            Some(self.charcnt),
+            self.leapcnt.checked_mul(time_width + 4),
+            Some(self.isstdcnt),
+            Some(self.isutcnt),
+        ]
+        .into_iter()
+        .try_fold(0usize, |acc, n| acc.checked_add(n?))
+        .ok_or(ParseTzifError::Truncated)
+    }
+}
+
+/// [Private] A cursor over TZif data
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ParseTzifError> {
+        let (head, rest) = self
+            .0
+            .split_at_checked(n)
+            .ok_or(ParseTzifError::Truncated)?;
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, ParseTzifError> {
+        let (&b, rest) = self.0.split_first().ok_or(ParseTzifError::Truncated)?;
+        self.0 = rest;
+        Ok(b)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ParseTzifError> {
+        let (&head, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or(ParseTzifError::Truncated)?;
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn i32(&mut self) -> Result<i32, ParseTzifError> {
+        self.array().map(i32::from_be_bytes)
+    }
+
+    fn count(&mut self) -> Result<usize, ParseTzifError> {
+        usize::try_from(u32::from_be_bytes(self.array()?)).map_err(|_| ParseTzifError::Truncated)
+    }
+
+    fn time(&mut self, width: usize) -> Result<i64, ParseTzifError> {
+        if width == 4 {
+            self.i32().map(i64::from)
+        } else {
+            self.array().map(i64::from_be_bytes)
+        }
+    }
+
+    /// Parse a TZif header, returning the version (0 for version 1, else the
+    /// version number) and the counts
+    fn header(&mut self) -> Result<(u8, Counts), ParseTzifError> {
+        if self.take(4)? != b"TZif" {
+            return Err(ParseTzifError::BadMagic);
+        }
+        let version = match self.u8()? {
+            0 => 0,
+            v @ b'2'..=b'4' => v - b'0',
+            v => return Err(ParseTzifError::UnsupportedVersion(v)),
+        };
+        self.take(15)?;
+        let counts = Counts {
+            isutcnt: self.count()?,
+            isstdcnt: self.count()?,
+            leapcnt: self.count()?,
+            timecnt: self.count()?,
+            typecnt: self.count()?,
+            charcnt: self.count()?,
+        };
+        Ok((version, counts))
+    }
+
+    /// Parse the newline-enclosed POSIX `TZ` string footer of a version 2+
+    /// TZif file
+    fn footer(&mut self) -> Result<Option<PosixRule>, ParseTzifError> {
+        if self.u8()? != b'\n' {
+            return Err(ParseTzifError::InvalidFooter);
+        }
+        let end = self
+            .0
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or(ParseTzifError::Truncated)?;
+        let s = core::str::from_utf8(self.take(end)?).map_err(|_| ParseTzifError::InvalidFooter)?;
+        if s.is_empty() {
+            Ok(None)
+        } else {
+            PosixRule::parse(s)
+                .map(Some)
+                .map_err(|_| ParseTzifError::InvalidFooter)
+        }
+    }
+
+    /// Parse a TZif data block
+    fn data_block(
+        &mut self,
+        counts: &Counts,
+        time_width: usize,
+        rule: Option<PosixRule>,
+    ) -> Result<TimeZone, ParseTzifError> {
+        if counts.typecnt == 0 || counts.charcnt == 0 {
+            return Err(ParseTzifError::NoLocalTimeTypes);
+        }
+        if counts.leapcnt != 0 {
+            return Err(ParseTzifError::LeapSeconds);
+        }
+        let mut times = Vec::with_capacity(counts.timecnt.min(self.0.len()));
+        for _ in 0..counts.timecnt {
+            let t = self.time(time_width)?;
+            if times.last().is_some_and(|&prev| prev >= t) {
+                return Err(ParseTzifError::UnsortedTransitions);
+            }
+            times.push(t);
+        }
+        let mut transitions = Vec::with_capacity(times.len());
+        for t in times {
+            let i = usize::from(self.u8()?);
+            if i >= counts.typecnt {
+                return Err(ParseTzifError::InvalidTypeIndex);
+            }
+            transitions.push((t, i));
+        }
+        let mut raw_types = Vec::with_capacity(counts.typecnt.min(self.0.len()));
+        for _ in 0..counts.typecnt {
+            let utoff = self.i32()?;
+            let is_dst = match self.u8()? {
+                0 => false,
+                1 => true,
+                _ => return Err(ParseTzifError::InvalidLocalTimeType),
+            };
+            let abbr_index = usize::from(self.u8()?);
+            raw_types.push((utoff, is_dst, abbr_index));
+        }
+        let chars = self.take(counts.charcnt)?;
+        let mut types = Vec::with_capacity(raw_types.len());
+        for (utoff, is_dst, abbr_index) in raw_types {
+            let offset = UtcOffset::new(utoff).map_err(|_| ParseTzifError::InvalidLocalTimeType)?;
+            let abbreviation = chars
+                .get(abbr_index..)
+                .and_then(|cs| cs.split(|&b| b == 0).next().filter(|_| cs.contains(&0)))
+                .and_then(|bs| core::str::from_utf8(bs).ok())
+                .ok_or(ParseTzifError::InvalidLocalTimeType)?
+                .to_owned();
+            types.push(LocalTimeType {
+                offset,
+                is_dst,
+                abbreviation,
+            });
+        }
+        // The standard/wall and UT/local indicators are only needed for
+        // interpreting POSIX-style TZ strings without rules, so skip them.
+        self.take(counts.isstdcnt)?;
+        self.take(counts.isutcnt)?;
+        Ok(TimeZone {
+            transitions,
+            types,
+            rule,
+        })
+    }
+}